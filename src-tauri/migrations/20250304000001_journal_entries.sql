@@ -0,0 +1,25 @@
+-- Journal entries and their debit/credit lines
+CREATE TABLE IF NOT EXISTS journal_entries (
+    id UUID PRIMARY KEY,
+    entry_date DATE NOT NULL,
+    description TEXT NOT NULL,
+    reference VARCHAR(50),
+    is_posted BOOLEAN NOT NULL DEFAULT FALSE,
+    posted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS journal_lines (
+    id UUID PRIMARY KEY,
+    entry_id UUID NOT NULL REFERENCES journal_entries(id) ON DELETE CASCADE,
+    account_id UUID NOT NULL REFERENCES accounts(id),
+    line_number INTEGER NOT NULL,
+    description TEXT,
+    debit DECIMAL(19, 4) NOT NULL DEFAULT 0,
+    credit DECIMAL(19, 4) NOT NULL DEFAULT 0,
+    CHECK (debit >= 0 AND credit >= 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_journal_lines_entry_id ON journal_lines(entry_id);
+CREATE INDEX IF NOT EXISTS idx_journal_lines_account_id ON journal_lines(account_id);
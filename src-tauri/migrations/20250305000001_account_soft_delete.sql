@@ -0,0 +1,4 @@
+-- Accounts are archived rather than removed so journal lines keep their references
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_accounts_not_deleted ON accounts(code) WHERE deleted_at IS NULL;
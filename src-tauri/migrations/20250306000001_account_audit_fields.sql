@@ -0,0 +1,3 @@
+-- Track who created and last modified each account
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS created_by UUID;
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS updated_by UUID;
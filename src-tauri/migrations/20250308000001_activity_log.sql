@@ -0,0 +1,12 @@
+-- Audit trail of user actions
+CREATE TABLE IF NOT EXISTS activity_log (
+    id UUID PRIMARY KEY,
+    action VARCHAR(50) NOT NULL,
+    entity_type VARCHAR(50) NOT NULL,
+    entity_id UUID,
+    description TEXT NOT NULL,
+    user_id UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_activity_log_created_at ON activity_log(created_at DESC);
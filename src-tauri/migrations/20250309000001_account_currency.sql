@@ -0,0 +1,5 @@
+-- ISO 4217 currency of each account
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS currency VARCHAR(3) NOT NULL DEFAULT 'USD';
+
+-- Rate applied when an entry moves money between accounts of different currencies
+ALTER TABLE journal_entries ADD COLUMN IF NOT EXISTS exchange_rate DECIMAL(19, 8);
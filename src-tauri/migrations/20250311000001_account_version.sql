@@ -0,0 +1,2 @@
+-- Row version for optimistic concurrency; bumped on every account update
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,11 @@
+-- Account balance after each posting, so past balances can be read without replaying the ledger
+CREATE TABLE IF NOT EXISTS balance_snapshots (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES accounts(id),
+    entry_id UUID REFERENCES journal_entries(id) ON DELETE SET NULL,
+    balance DECIMAL(19, 4) NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_balance_snapshots_account_recorded
+    ON balance_snapshots(account_id, recorded_at);
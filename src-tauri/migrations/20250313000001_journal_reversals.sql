@@ -0,0 +1,7 @@
+-- Link a reversing entry to the entry it reverses; an entry can be reversed only once
+ALTER TABLE journal_entries
+    ADD COLUMN IF NOT EXISTS reverses_entry_id UUID REFERENCES journal_entries(id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_journal_entries_reverses_entry
+    ON journal_entries(reverses_entry_id)
+    WHERE reverses_entry_id IS NOT NULL;
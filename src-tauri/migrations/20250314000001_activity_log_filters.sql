@@ -0,0 +1,3 @@
+-- Support filtering the activity log by user or entity type, newest first
+CREATE INDEX IF NOT EXISTS idx_activity_log_user_created_at ON activity_log(user_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_activity_log_entity_type_created_at ON activity_log(entity_type, created_at DESC);
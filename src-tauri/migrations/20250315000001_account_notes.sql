@@ -0,0 +1,13 @@
+-- Dated, append-only notes on accounts
+CREATE TABLE IF NOT EXISTS account_notes (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES accounts(id),
+    body TEXT NOT NULL,
+    author_id UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_account_notes_account_created_at ON account_notes(account_id, created_at DESC);
+
+-- Notes are never edited or removed, so a running count stays exact
+ALTER TABLE accounts ADD COLUMN IF NOT EXISTS note_count INTEGER NOT NULL DEFAULT 0;
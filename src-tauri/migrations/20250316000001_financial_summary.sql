@@ -0,0 +1,16 @@
+-- Balance totals per account type for the dashboard, refreshed periodically
+-- and on demand rather than summed on every load
+CREATE MATERIALIZED VIEW IF NOT EXISTS financial_summary AS
+SELECT
+    1 AS id,
+    COALESCE(SUM(balance) FILTER (WHERE account_type = 'ASSET'), 0) AS total_assets,
+    COALESCE(SUM(balance) FILTER (WHERE account_type = 'LIABILITY'), 0) AS total_liabilities,
+    COALESCE(SUM(balance) FILTER (WHERE account_type = 'EQUITY'), 0) AS total_equity,
+    COALESCE(SUM(balance) FILTER (WHERE account_type = 'REVENUE'), 0) AS total_revenue,
+    COALESCE(SUM(balance) FILTER (WHERE account_type = 'EXPENSE'), 0) AS total_expenses,
+    NOW() AS refreshed_at
+FROM accounts
+WHERE deleted_at IS NULL;
+
+-- REFRESH ... CONCURRENTLY needs a unique index
+CREATE UNIQUE INDEX IF NOT EXISTS idx_financial_summary_id ON financial_summary(id);
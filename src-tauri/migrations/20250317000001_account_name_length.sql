@@ -0,0 +1,3 @@
+-- Account names may be up to 200 characters; codes keep their column size so
+-- codes stored before the 20-character limit remain readable
+ALTER TABLE accounts ALTER COLUMN name TYPE VARCHAR(200);
@@ -0,0 +1,104 @@
+-- Subcategories are now checked against a fixed list per category. Bring the
+-- common free-text spellings onto that list; anything unrecognised is kept as
+-- entered and only has to be fixed once the account's subcategory is edited.
+UPDATE accounts SET subcategory = NULL WHERE TRIM(subcategory) = '';
+
+UPDATE accounts a
+SET subcategory = m.canonical
+FROM (VALUES
+    ('CURRENT_ASSET', 'cash', 'Cash'),
+    ('CURRENT_ASSET', 'bank', 'Cash'),
+    ('CURRENT_ASSET', 'checking', 'Cash'),
+    ('CURRENT_ASSET', 'savings', 'Cash'),
+    ('CURRENT_ASSET', 'petty cash', 'Cash'),
+    ('CURRENT_ASSET', 'accounts receivable', 'Accounts Receivable'),
+    ('CURRENT_ASSET', 'receivables', 'Accounts Receivable'),
+    ('CURRENT_ASSET', 'ar', 'Accounts Receivable'),
+    ('CURRENT_ASSET', 'a/r', 'Accounts Receivable'),
+    ('CURRENT_ASSET', 'inventory', 'Inventory'),
+    ('CURRENT_ASSET', 'stock', 'Inventory'),
+    ('CURRENT_ASSET', 'prepaid expenses', 'Prepaid Expenses'),
+    ('CURRENT_ASSET', 'prepaid', 'Prepaid Expenses'),
+    ('CURRENT_ASSET', 'prepayments', 'Prepaid Expenses'),
+    ('CURRENT_ASSET', 'short-term investments', 'Short-Term Investments'),
+    ('CURRENT_ASSET', 'investments', 'Short-Term Investments'),
+    ('CURRENT_ASSET', 'other current assets', 'Other Current Assets'),
+    ('FIXED_ASSET', 'land', 'Land'),
+    ('FIXED_ASSET', 'buildings', 'Buildings'),
+    ('FIXED_ASSET', 'building', 'Buildings'),
+    ('FIXED_ASSET', 'equipment', 'Equipment'),
+    ('FIXED_ASSET', 'machinery', 'Equipment'),
+    ('FIXED_ASSET', 'vehicles', 'Vehicles'),
+    ('FIXED_ASSET', 'vehicle', 'Vehicles'),
+    ('FIXED_ASSET', 'furniture and fixtures', 'Furniture and Fixtures'),
+    ('FIXED_ASSET', 'furniture', 'Furniture and Fixtures'),
+    ('FIXED_ASSET', 'accumulated depreciation', 'Accumulated Depreciation'),
+    ('OTHER_ASSET', 'intangible assets', 'Intangible Assets'),
+    ('OTHER_ASSET', 'intangibles', 'Intangible Assets'),
+    ('OTHER_ASSET', 'goodwill', 'Intangible Assets'),
+    ('OTHER_ASSET', 'long-term investments', 'Long-Term Investments'),
+    ('OTHER_ASSET', 'investments', 'Long-Term Investments'),
+    ('OTHER_ASSET', 'deposits', 'Deposits'),
+    ('CURRENT_LIABILITY', 'accounts payable', 'Accounts Payable'),
+    ('CURRENT_LIABILITY', 'payables', 'Accounts Payable'),
+    ('CURRENT_LIABILITY', 'ap', 'Accounts Payable'),
+    ('CURRENT_LIABILITY', 'a/p', 'Accounts Payable'),
+    ('CURRENT_LIABILITY', 'accrued liabilities', 'Accrued Liabilities'),
+    ('CURRENT_LIABILITY', 'accruals', 'Accrued Liabilities'),
+    ('CURRENT_LIABILITY', 'taxes payable', 'Taxes Payable'),
+    ('CURRENT_LIABILITY', 'sales tax', 'Taxes Payable'),
+    ('CURRENT_LIABILITY', 'vat', 'Taxes Payable'),
+    ('CURRENT_LIABILITY', 'tax', 'Taxes Payable'),
+    ('CURRENT_LIABILITY', 'payroll liabilities', 'Payroll Liabilities'),
+    ('CURRENT_LIABILITY', 'payroll', 'Payroll Liabilities'),
+    ('CURRENT_LIABILITY', 'short-term loans', 'Short-Term Loans'),
+    ('CURRENT_LIABILITY', 'credit card', 'Short-Term Loans'),
+    ('CURRENT_LIABILITY', 'unearned revenue', 'Unearned Revenue'),
+    ('CURRENT_LIABILITY', 'deferred revenue', 'Unearned Revenue'),
+    ('LONG_TERM_LIABILITY', 'long-term loans', 'Long-Term Loans'),
+    ('LONG_TERM_LIABILITY', 'loans', 'Long-Term Loans'),
+    ('LONG_TERM_LIABILITY', 'loan', 'Long-Term Loans'),
+    ('LONG_TERM_LIABILITY', 'mortgages', 'Mortgages'),
+    ('LONG_TERM_LIABILITY', 'mortgage', 'Mortgages'),
+    ('LONG_TERM_LIABILITY', 'bonds payable', 'Bonds Payable'),
+    ('LONG_TERM_LIABILITY', 'bonds', 'Bonds Payable'),
+    ('OTHER_LIABILITY', 'deferred taxes', 'Deferred Taxes'),
+    ('OTHER_LIABILITY', 'deferred tax', 'Deferred Taxes'),
+    ('OTHER_LIABILITY', 'other liabilities', 'Other Liabilities'),
+    ('OWNER_EQUITY', 'capital', 'Capital'),
+    ('OWNER_EQUITY', 'owner capital', 'Capital'),
+    ('OWNER_EQUITY', 'drawings', 'Drawings'),
+    ('OWNER_EQUITY', 'draws', 'Drawings'),
+    ('OWNER_EQUITY', 'common stock', 'Common Stock'),
+    ('OWNER_EQUITY', 'shares', 'Common Stock'),
+    ('RETAINED_EARNINGS', 'retained earnings', 'Retained Earnings'),
+    ('OPERATING_REVENUE', 'sales', 'Sales'),
+    ('OPERATING_REVENUE', 'product sales', 'Sales'),
+    ('OPERATING_REVENUE', 'services', 'Services'),
+    ('OPERATING_REVENUE', 'service', 'Services'),
+    ('OPERATING_REVENUE', 'consulting', 'Services'),
+    ('NON_OPERATING_REVENUE', 'interest income', 'Interest Income'),
+    ('NON_OPERATING_REVENUE', 'interest', 'Interest Income'),
+    ('NON_OPERATING_REVENUE', 'gains', 'Gains'),
+    ('NON_OPERATING_REVENUE', 'other income', 'Other Income'),
+    ('OPERATING_EXPENSE', 'cost of goods sold', 'Cost of Goods Sold'),
+    ('OPERATING_EXPENSE', 'cogs', 'Cost of Goods Sold'),
+    ('OPERATING_EXPENSE', 'cost of sales', 'Cost of Goods Sold'),
+    ('OPERATING_EXPENSE', 'payroll', 'Payroll'),
+    ('OPERATING_EXPENSE', 'salaries', 'Payroll'),
+    ('OPERATING_EXPENSE', 'wages', 'Payroll'),
+    ('OPERATING_EXPENSE', 'rent', 'Rent'),
+    ('OPERATING_EXPENSE', 'utilities', 'Utilities'),
+    ('OPERATING_EXPENSE', 'marketing', 'Marketing'),
+    ('OPERATING_EXPENSE', 'advertising', 'Marketing'),
+    ('OPERATING_EXPENSE', 'office', 'Office'),
+    ('OPERATING_EXPENSE', 'office supplies', 'Office'),
+    ('OPERATING_EXPENSE', 'insurance', 'Insurance'),
+    ('OPERATING_EXPENSE', 'depreciation', 'Depreciation'),
+    ('NON_OPERATING_EXPENSE', 'interest expense', 'Interest Expense'),
+    ('NON_OPERATING_EXPENSE', 'interest', 'Interest Expense'),
+    ('NON_OPERATING_EXPENSE', 'losses', 'Losses'),
+    ('NON_OPERATING_EXPENSE', 'other expenses', 'Other Expenses')
+) AS m(category, alias, canonical)
+WHERE a.category = m.category
+    AND LOWER(TRIM(a.subcategory)) = m.alias;
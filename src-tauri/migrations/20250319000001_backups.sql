@@ -0,0 +1,11 @@
+-- Backup files written to the data directory; the newest one is shown as the last backup
+CREATE TABLE IF NOT EXISTS backups (
+    id UUID PRIMARY KEY,
+    file_path TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    account_count INTEGER NOT NULL,
+    created_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_backups_created_at ON backups(created_at DESC);
@@ -0,0 +1,14 @@
+-- Budgeted amount per revenue or expense account and fiscal period
+CREATE TABLE IF NOT EXISTS budgets (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES accounts(id),
+    period_id UUID NOT NULL REFERENCES fiscal_periods(id),
+    amount DECIMAL(19, 4) NOT NULL CHECK (amount >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_by UUID REFERENCES users(id),
+    updated_by UUID REFERENCES users(id),
+    UNIQUE (account_id, period_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_budgets_period ON budgets(period_id);
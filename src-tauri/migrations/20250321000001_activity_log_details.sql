@@ -0,0 +1,2 @@
+-- Structured details of an activity, e.g. the fields an account update changed
+ALTER TABLE activity_log ADD COLUMN IF NOT EXISTS details JSONB;
@@ -0,0 +1,12 @@
+-- Codes an account was renumbered from, so references to an old code still resolve
+CREATE TABLE IF NOT EXISTS code_history (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES accounts(id) ON DELETE CASCADE,
+    old_code VARCHAR(50) NOT NULL,
+    new_code VARCHAR(50) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    changed_by UUID REFERENCES users(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_code_history_account ON code_history(account_id, changed_at DESC);
+CREATE INDEX IF NOT EXISTS idx_code_history_old_code ON code_history(old_code);
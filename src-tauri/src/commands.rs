@@ -1,10 +1,62 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tauri::Emitter;
 use uuid::Uuid;
 
-use crate::error::{not_found, validation_error, Error, ErrorResponse, Result};
-use crate::models::account::{Account, AccountCategory, AccountType, NewAccount};
+use crate::config::{self, AppConfig, ChartConfig, LogLevel};
+use crate::database::{self, DbHealth};
+use crate::diagnostics::RecordedError;
+use crate::error::{join_field_errors, not_found, validation_error, Error, ErrorResponse, Result};
+use crate::models::account::{
+    normalize_subcategory, validate_code, validate_name, Account, AccountActivityStats,
+    AccountCategory, AccountCursor, AccountDeleteImpact, AccountId, AccountMatchKind,
+    AccountSortField, AccountType, ActivityStatsSortField, BalanceCorrection, NewAccount,
+    SortDirection,
+};
+use crate::models::activity::{
+    ActivityAction, ActivityFilter, ActivityLog, FieldChange, NewActivity,
+};
+use crate::models::backup::BackupRecord;
+use crate::models::budget::Budget;
+use crate::models::code_history::CodeChange;
+use crate::models::currency::{self, Currency, DEFAULT_CURRENCY};
+use crate::models::journal::{
+    JournalEntry, JournalLine, LedgerLine, NewJournalEntry, NewJournalLine,
+};
+use crate::models::note::AccountNote;
+use crate::models::period::{FiscalPeriod, FiscalYear, NewFiscalPeriod};
+use crate::models::summary::FinancialSummary;
+use crate::models::user::{NewUser, User, UserRole};
+use crate::money;
+use crate::repositories::account_store::AccountStore;
 use crate::repositories::accounts::AccountRepository;
+use crate::repositories::activity::ActivityRepository;
+use crate::repositories::backups::BackupRepository;
+use crate::repositories::budgets::BudgetRepository;
+use crate::repositories::code_history::CodeHistoryRepository;
+use crate::repositories::journal::JournalRepository;
+use crate::repositories::notes::NoteRepository;
+use crate::repositories::periods::PeriodRepository;
+use crate::repositories::snapshots::SnapshotRepository;
+use crate::repositories::summary::SummaryRepository;
+use crate::repositories::users::UserRepository;
+use crate::services::auth;
+use crate::services::backup::{self, BackupFile};
+use crate::services::balance_sheet::BalanceSheet;
+use crate::services::budget_variance::BudgetVarianceReport;
+use crate::services::checksum;
+use crate::services::completeness::{self, ChartCompleteness};
+use crate::services::csv;
+use crate::services::iif;
+use crate::services::income_statement::IncomeStatement;
+use crate::services::metrics::{self, ComparisonPeriod};
+use crate::services::numbering;
+use crate::services::seed;
+use crate::services::trial_balance::TrialBalance;
+use crate::validation::{sanitize_optional_text, sanitize_text, FieldErrors};
 use crate::AppState;
 
 // View models for frontend
@@ -20,8 +72,36 @@ pub struct AccountViewModel {
     pub is_active: bool,
     pub parent_id: Option<String>,
     pub balance: String,
+    pub currency: String,
+    pub version: i32,
+    pub note_count: i32,
     pub created_at: String,
     pub updated_at: String,
+    pub created_by: Option<String>,
+    pub updated_by: Option<String>,
+}
+
+// Event emitted to every window after an account is created, updated,
+// deleted or (de)activated
+pub const ACCOUNT_CHANGED_EVENT: &str = "account-changed";
+
+// What happened to the account named in an `account-changed` event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    StatusChanged,
+}
+
+// Payload of `account-changed`; `account` is the row as saved so lists can
+// patch it in place, and is absent for deletions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountChangedEvent {
+    pub account_id: String,
+    pub kind: AccountChangeKind,
+    pub account: Option<AccountViewModel>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,271 +113,5381 @@ pub struct NewAccountDto {
     pub category: String,
     pub subcategory: Option<String>,
     pub parent_id: Option<String>,
+    pub currency: Option<String>,
+    /// Balance to open the account with, on its normal side; only used on create
+    pub opening_balance: Option<String>,
+    /// Date of the opening balance entry, YYYY-MM-DD; defaults to today
+    pub opening_balance_date: Option<String>,
+}
+
+/// Changes to an existing account, saved only if it is still at `version`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdateDto {
+    #[serde(flatten)]
+    pub account: NewAccountDto,
+    /// Version the client loaded
+    pub version: i32,
+}
+
+/// A transfer between two accounts, posted as a two-line journal entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferDto {
+    pub from_account_id: String,
+    pub to_account_id: String,
+    pub amount: String,
+    /// Entry date, YYYY-MM-DD
+    pub date: String,
+    pub description: String,
+    /// Post the entry immediately rather than leaving it as a draft
+    pub post: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalLineViewModel {
+    pub id: String,
+    pub account_id: String,
+    pub line_number: i32,
+    pub description: Option<String>,
+    pub debit: String,
+    pub credit: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntryViewModel {
+    pub id: String,
+    pub entry_date: String,
+    pub description: String,
+    pub reference: Option<String>,
+    pub exchange_rate: Option<String>,
+    pub is_posted: bool,
+    pub posted_at: Option<String>,
+    pub reverses_entry_id: Option<String>,
+    pub lines: Vec<JournalLineViewModel>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<JournalLine> for JournalLineViewModel {
+    fn from(line: JournalLine) -> Self {
+        Self {
+            id: line.id.to_string(),
+            account_id: line.account_id.to_string(),
+            line_number: line.line_number,
+            description: line.description,
+            debit: money::format(line.debit),
+            credit: money::format(line.credit),
+        }
+    }
+}
+
+impl JournalEntryViewModel {
+    fn new(entry: JournalEntry, lines: Vec<JournalLine>) -> Self {
+        Self {
+            id: entry.id.to_string(),
+            entry_date: entry.entry_date.to_string(),
+            description: entry.description,
+            reference: entry.reference,
+            exchange_rate: entry.exchange_rate.map(|rate| rate.to_string()),
+            is_posted: entry.is_posted,
+            posted_at: entry.posted_at.map(|at| at.to_rfc3339()),
+            reverses_entry_id: entry.reverses_entry_id.map(|id| id.to_string()),
+            lines: lines.into_iter().map(JournalLineViewModel::from).collect(),
+            created_at: entry.created_at.to_rfc3339(),
+            updated_at: entry.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+// One row of an account ledger with the balance after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerRowViewModel {
+    pub entry_id: String,
+    pub entry_date: String,
+    pub description: String,
+    pub reference: Option<String>,
+    pub debit: String,
+    pub credit: String,
+    pub running_balance: String,
+}
+
+// Posted activity of a single account over a date range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountLedger {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub opening_balance: String,
+    pub closing_balance: String,
+    pub rows: Vec<LedgerRowViewModel>,
+}
+
+// A printable account statement: account details, the period, balances
+// carried in and out, the period's totals and its ledger rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerExport {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub currency: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub opening_balance: String,
+    pub closing_balance: String,
+    pub total_debits: String,
+    pub total_credits: String,
+    pub rows: Vec<LedgerRowViewModel>,
+    pub generated_at: String,
+}
+
+impl LedgerRowViewModel {
+    fn new(line: LedgerLine, running_balance: Decimal) -> Self {
+        Self {
+            entry_id: line.entry_id.to_string(),
+            entry_date: line.entry_date.to_string(),
+            description: line.description,
+            reference: line.reference,
+            debit: money::format(line.debit),
+            credit: money::format(line.credit),
+            running_balance: money::format(running_balance),
+        }
+    }
+}
+
+// An account changed since a sync point; deleted accounts are still reported
+// so that clients can drop them from their cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModifiedAccount {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    pub is_deleted: bool,
+}
+
+// An account found by search_accounts; `match_kind` is absent for an empty query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSearchResult {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    pub match_kind: Option<AccountMatchKind>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTreeNode {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    // Number of ancestors; root accounts are at depth 0
+    pub depth: u32,
+    pub children: Vec<AccountTreeNode>,
+}
+
+impl AccountTreeNode {
+    /// Builds the nested tree from accounts already ordered by code
+    fn build(accounts: Vec<Account>) -> Vec<Self> {
+        let mut by_parent: HashMap<Option<Uuid>, Vec<Account>> = HashMap::new();
+        for account in accounts {
+            by_parent
+                .entry(account.parent_id)
+                .or_default()
+                .push(account);
+        }
+
+        Self::children_of(None, 0, &mut by_parent)
+    }
+
+    fn children_of(
+        parent_id: Option<Uuid>,
+        depth: u32,
+        by_parent: &mut HashMap<Option<Uuid>, Vec<Account>>,
+    ) -> Vec<Self> {
+        by_parent
+            .remove(&parent_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|account| {
+                let children = Self::children_of(Some(account.id), depth + 1, by_parent);
+                Self {
+                    account: AccountViewModel::from(account),
+                    depth,
+                    children,
+                }
+            })
+            .collect()
+    }
+}
+
+// The accounts of one type with the sum of their balances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountGroup {
+    pub account_type: String,
+    pub accounts: Vec<AccountViewModel>,
+    pub subtotal: String,
+}
+
+impl AccountGroup {
+    /// Groups accounts by type in chart-of-accounts order, keeping the order
+    /// of the accounts within each group. Every type gets a group, even one
+    /// without accounts, so the UI can show all headers.
+    fn group(accounts: Vec<Account>) -> Vec<Self> {
+        let mut by_type: HashMap<AccountType, Vec<Account>> = HashMap::new();
+        for account in accounts {
+            by_type
+                .entry(account.account_type)
+                .or_default()
+                .push(account);
+        }
+
+        AccountType::all()
+            .into_iter()
+            .map(|account_type| {
+                let members = by_type.remove(&account_type).unwrap_or_default();
+                let subtotal: Decimal = members.iter().map(|account| account.balance).sum();
+                Self {
+                    account_type: account_type.to_string(),
+                    accounts: members.into_iter().map(AccountViewModel::from).collect(),
+                    subtotal: money::format(subtotal),
+                }
+            })
+            .collect()
+    }
+}
+
+// What deleting an account would affect; `reasons` explains a refusal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteImpactViewModel {
+    pub children: i64,
+    pub posted_lines: i64,
+    pub unposted_lines: i64,
+    pub notes: i64,
+    pub can_delete: bool,
+    pub reasons: Vec<String>,
+}
+
+impl From<AccountDeleteImpact> for DeleteImpactViewModel {
+    fn from(impact: AccountDeleteImpact) -> Self {
+        let reasons = impact.blocking_reasons();
+        Self {
+            children: impact.children,
+            posted_lines: impact.posted_lines,
+            unposted_lines: impact.unposted_lines,
+            notes: impact.notes,
+            can_delete: reasons.is_empty(),
+            reasons,
+        }
+    }
+}
+
+// How much an account is used; accounts never posted to and without a
+// balance are flagged as candidates for archiving
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountActivityStatsViewModel {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub balance: String,
+    pub line_count: i64,
+    pub last_activity: Option<String>,
+    pub archive_candidate: bool,
+}
+
+impl From<AccountActivityStats> for AccountActivityStatsViewModel {
+    fn from(stats: AccountActivityStats) -> Self {
+        Self {
+            archive_candidate: stats.is_archive_candidate(),
+            account_id: stats.account_id.to_string(),
+            code: stats.code,
+            name: stats.name,
+            is_active: stats.is_active,
+            balance: money::format(stats.balance),
+            line_count: stats.line_count,
+            last_activity: stats.last_activity.map(|date| date.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountNoteViewModel {
+    pub id: String,
+    pub account_id: String,
+    pub body: String,
+    pub author: String,
+    pub created_at: String,
+}
+
+impl From<AccountNote> for AccountNoteViewModel {
+    fn from(note: AccountNote) -> Self {
+        Self {
+            id: note.id.to_string(),
+            account_id: note.account_id.to_string(),
+            body: note.body,
+            author: note.author.unwrap_or_else(|| "System".to_string()),
+            created_at: note.created_at.to_rfc3339(),
+        }
+    }
+}
+
+// A code an account was renumbered from and what it became
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeChangeViewModel {
+    pub account_id: String,
+    pub old_code: String,
+    pub new_code: String,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+impl From<CodeChange> for CodeChangeViewModel {
+    fn from(change: CodeChange) -> Self {
+        Self {
+            account_id: change.account_id.to_string(),
+            old_code: change.old_code,
+            new_code: change.new_code,
+            changed_by: change.username.unwrap_or_else(|| "System".to_string()),
+            changed_at: change.changed_at.to_rfc3339(),
+        }
+    }
+}
+
+// The ancestors of an account from the root down, ending with the account itself
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountPath {
+    pub accounts: Vec<AccountViewModel>,
+    // Number of ancestors; a root account is at depth 0
+    pub depth: u32,
+}
+
+// An account with the summed balance of itself and everything below it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubtreeBalance {
+    pub account: AccountViewModel,
+    pub subtree_balance: String,
+    pub descendant_count: usize,
+}
+
+/// Number of accounts returned per chunk by `get_accounts_stream`
+const ACCOUNT_STREAM_CHUNK_SIZE: usize = 200;
+
+// Shortest password accepted by create_user
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+// Upper bound for a single page of get_accounts_paged and get_accounts_after
+const MAX_PAGE_SIZE: u32 = 500;
+
+// Entries returned by get_recent_activities when no limit is given, and the cap
+const DEFAULT_ACTIVITY_LIMIT: u32 = 10;
+const MAX_ACTIVITY_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountPage {
+    pub accounts: Vec<AccountViewModel>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityPage {
+    pub activities: Vec<ActivityViewModel>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStreamChunk {
+    pub accounts: Vec<AccountViewModel>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserViewModel {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub role: String,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<User> for UserViewModel {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id.to_string(),
+            username: user.username,
+            email: user.email,
+            role: user.role.to_string(),
+            is_active: user.is_active,
+            created_at: user.created_at.to_rfc3339(),
+            updated_at: user.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+// A signed session token and the user it was issued to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginResponse {
+    pub token: String,
+    pub user: UserViewModel,
+}
+
+// The user a valid session token belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInfo {
+    pub user_id: String,
+    pub expires_at: String,
+}
+
+// An account type together with the categories it allows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountTypeMetadata {
+    pub account_type: String,
+    pub categories: Vec<String>,
+}
+
+// A currency accounts may be kept in, with how its amounts are shown
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyViewModel {
+    pub code: String,
+    pub symbol: String,
+    pub decimal_places: u32,
+    pub name: String,
+}
+
+impl From<&Currency> for CurrencyViewModel {
+    fn from(currency: &Currency) -> Self {
+        Self {
+            code: currency.code.to_string(),
+            symbol: currency.symbol.to_string(),
+            decimal_places: currency.decimal_places,
+            name: currency.name.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkingCapitalMetrics {
+    pub current_assets: String,
+    pub inventory: String,
+    pub current_liabilities: String,
+    pub net_working_capital: String,
+    pub current_ratio: Option<String>,
+    pub quick_ratio: Option<String>,
+}
+
+impl WorkingCapitalMetrics {
+    /// Derives the metrics from category totals. Inventory is excluded from the
+    /// quick ratio, and both ratios are undefined without current liabilities.
+    fn from_totals(
+        current_assets: Decimal,
+        inventory: Decimal,
+        current_liabilities: Decimal,
+    ) -> Self {
+        let ratio = |numerator: Decimal| {
+            if current_liabilities.is_zero() {
+                None
+            } else {
+                Some(money::format(numerator / current_liabilities))
+            }
+        };
+
+        Self {
+            current_assets: money::format(current_assets),
+            inventory: money::format(inventory),
+            current_liabilities: money::format(current_liabilities),
+            net_working_capital: money::format(current_assets - current_liabilities),
+            current_ratio: ratio(current_assets),
+            quick_ratio: ratio(current_assets - inventory),
+        }
+    }
+}
+
+// Activity log entry for the recent activity feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityViewModel {
+    pub id: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: Option<String>,
+    pub description: String,
+    pub user: String,
+    pub timestamp: String,
+    pub details: Vec<FieldChange>,
+}
+
+impl From<ActivityLog> for ActivityViewModel {
+    fn from(activity: ActivityLog) -> Self {
+        Self {
+            id: activity.id.to_string(),
+            action: activity.action.label().to_string(),
+            entity_type: activity.entity_type,
+            entity_id: activity.entity_id.map(|id| id.to_string()),
+            description: activity.description,
+            user: activity.username.unwrap_or_else(|| "System".to_string()),
+            timestamp: activity.created_at.to_rfc3339(),
+            details: activity.details,
+        }
+    }
+}
+
+// An account balance as it stood at the end of a given date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceAsOfViewModel {
+    pub account_id: String,
+    pub as_of: String,
+    /// None when nothing had been posted to the account by that date
+    pub balance: Option<String>,
+    pub recorded_at: Option<String>,
+}
+
+// Fiscal period view model for the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiscalPeriodViewModel {
+    pub id: String,
+    pub name: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub is_closed: bool,
+    pub closed_at: Option<String>,
+}
+
+impl From<FiscalPeriod> for FiscalPeriodViewModel {
+    fn from(period: FiscalPeriod) -> Self {
+        Self {
+            id: period.id.to_string(),
+            name: period.name,
+            start_date: period.start_date.to_string(),
+            end_date: period.end_date.to_string(),
+            is_closed: period.is_closed,
+            closed_at: period.closed_at.map(|at| at.to_rfc3339()),
+        }
+    }
+}
+
+// Outcome of closing a period, with the inactive accounts that still carry a balance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosePeriodResult {
+    pub period: FiscalPeriodViewModel,
+    pub inactive_nonzero_accounts: Vec<AccountViewModel>,
+}
+
+// Application settings safe to show in the UI; secrets and the database URL
+// (which may embed credentials) are left out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfigView {
+    pub app_name: String,
+    pub version: String,
+    pub log_level: String,
+    pub data_dir: String,
+    pub fiscal_year_start_month: u32,
+    pub db_max_connections: u32,
+    pub db_timeout_seconds: u64,
+    pub token_expiry_hours: u64,
+}
+
+impl From<&AppConfig> for AppConfigView {
+    fn from(config: &AppConfig) -> Self {
+        Self {
+            app_name: config.app.name.clone(),
+            version: config.app.version.clone(),
+            log_level: config.app.log_level.to_string(),
+            data_dir: config.app.data_dir.clone(),
+            fiscal_year_start_month: config.app.fiscal_year_start_month,
+            db_max_connections: config.database.max_connections,
+            db_timeout_seconds: config.database.timeout_seconds,
+            token_expiry_hours: config.security.token_expiry_hours,
+        }
+    }
+}
+
+// Health and version information shown on the home page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemStatus {
+    pub database_connected: bool,
+    pub version: String,
+    pub last_backup: Option<String>,
+    pub fiscal_year: String,
+}
+
+// A backup file written to the data directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupViewModel {
+    pub id: String,
+    pub file_path: String,
+    pub size_bytes: i64,
+    pub account_count: i32,
+    pub created_at: String,
+}
+
+impl From<BackupRecord> for BackupViewModel {
+    fn from(backup: BackupRecord) -> Self {
+        Self {
+            id: backup.id.to_string(),
+            file_path: backup.file_path,
+            size_bytes: backup.size_bytes,
+            account_count: backup.account_count,
+            created_at: backup.created_at.to_rfc3339(),
+        }
+    }
+}
+
+// The budget of an account for a fiscal period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetViewModel {
+    pub id: String,
+    pub account_id: String,
+    pub period_id: String,
+    pub amount: String,
+    pub updated_at: String,
+}
+
+impl From<Budget> for BudgetViewModel {
+    fn from(budget: Budget) -> Self {
+        Self {
+            id: budget.id.to_string(),
+            account_id: budget.account_id.to_string(),
+            period_id: budget.period_id.to_string(),
+            amount: money::format(budget.amount),
+            updated_at: budget.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+// Outcome of restoring the accounts from a backup file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupRestoreResult {
+    pub file_path: String,
+    pub backup_created_at: String,
+    pub accounts_before: i64,
+    pub accounts_after: i64,
+}
+
+// Debit-normal against credit-normal balance totals. When they differ the
+// books are corrupt, and `accounts` lists every account to track it down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BooksBalanceCheck {
+    pub debit_normal_total: String,
+    pub credit_normal_total: String,
+    pub difference: String,
+    pub is_balanced: bool,
+    pub checked_at: String,
+    pub accounts: Vec<AccountViewModel>,
+}
+
+// An account whose stored balance was corrected to match its journal lines
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceCorrectionViewModel {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub old_balance: String,
+    pub new_balance: String,
+    pub difference: String,
+}
+
+impl From<BalanceCorrection> for BalanceCorrectionViewModel {
+    fn from(correction: BalanceCorrection) -> Self {
+        Self {
+            account_id: correction.account_id.to_string(),
+            difference: money::format(correction.difference()),
+            old_balance: money::format(correction.old_balance),
+            new_balance: money::format(correction.new_balance),
+            code: correction.code,
+            name: correction.name,
+        }
+    }
+}
+
+// Result of recomputing balances from journal lines; `corrections` is empty
+// when every stored balance already matched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceRecomputeReport {
+    pub corrections: Vec<BalanceCorrectionViewModel>,
+    pub recomputed_at: String,
+}
+
+// Liveness probe result; `status` is always "ok" while the app is running
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingResponse {
+    pub status: String,
+    pub version: String,
+    pub db_reachable: bool,
+}
+
+// Cached balance totals per account type and when they were computed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancialSummaryViewModel {
+    pub total_assets: String,
+    pub total_liabilities: String,
+    pub total_equity: String,
+    pub total_revenue: String,
+    pub total_expenses: String,
+    pub refreshed_at: String,
+}
+
+impl From<FinancialSummary> for FinancialSummaryViewModel {
+    fn from(summary: FinancialSummary) -> Self {
+        Self {
+            total_assets: money::format(summary.total_assets),
+            total_liabilities: money::format(summary.total_liabilities),
+            total_equity: money::format(summary.total_equity),
+            total_revenue: money::format(summary.total_revenue),
+            total_expenses: money::format(summary.total_expenses),
+            refreshed_at: summary.refreshed_at.to_rfc3339(),
+        }
+    }
+}
+
+// A headline figure for the home page; `change` is a percentage when known
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinancialMetric {
+    pub name: String,
+    pub value: String,
+    pub change: Option<f64>,
+    pub period: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnmappedIifAccount {
+    pub name: String,
+    pub foreign_type: String,
+}
+
+// Outcome of a batch status change: the requested accounts in their new state,
+// and the ids that matched no account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountStatusBatch {
+    pub updated: Vec<AccountViewModel>,
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IifImportReport {
+    pub imported: Vec<AccountViewModel>,
+    pub unmapped: Vec<UnmappedIifAccount>,
+    pub skipped: Vec<String>,
+}
+
+impl From<Account> for AccountViewModel {
+    fn from(account: Account) -> Self {
+        Self {
+            id: account.id.to_string(),
+            code: account.code,
+            name: account.name,
+            description: account.description,
+            account_type: account.account_type.to_string(),
+            category: account.category.to_string(),
+            subcategory: account.subcategory,
+            is_active: account.is_active,
+            parent_id: account.parent_id.map(|id| id.to_string()),
+            balance: money::format(account.balance),
+            currency: account.currency,
+            version: account.version,
+            note_count: account.note_count,
+            created_at: account.created_at.to_rfc3339(),
+            updated_at: account.updated_at.to_rfc3339(),
+            created_by: account.created_by.map(|id| id.to_string()),
+            updated_by: account.updated_by.map(|id| id.to_string()),
+        }
+    }
+}
+
+/// Strips control and invisible characters from the free-text fields of a DTO
+fn sanitize_account_text(dto: &NewAccountDto) -> Result<(String, String, Option<String>)> {
+    let code = sanitize_text("Account code", &dto.code)?;
+    let name = sanitize_text("Account name", &dto.name)?;
+    let description = sanitize_optional_text("Description", dto.description.clone())?;
+
+    Ok((code, name, description))
+}
+
+/// Fields of the account form, in the order their errors are listed
+const ACCOUNT_FORM_FIELDS: &[&str] = &[
+    "code",
+    "name",
+    "description",
+    "account_type",
+    "category",
+    "subcategory",
+    "currency",
+    "parent_id",
+    "opening_balance",
+    "opening_balance_date",
+];
+
+/// Validates an account DTO from the frontend and converts it to the domain
+/// model. Every field is checked before failing, and the error lists each bad one.
+fn parse_new_account(dto: NewAccountDto, chart: &ChartConfig) -> Result<NewAccount> {
+    let mut errors = FieldErrors::new();
+    let result = parse_account_fields(&mut errors, dto, chart);
+    errors.sort_by_field(ACCOUNT_FORM_FIELDS);
+    match result? {
+        Some(new_account) => {
+            errors.finish()?;
+            Ok(new_account)
+        }
+        None => Err(errors.into_error()),
+    }
+}
+
+/// Checks every field of an account DTO, recording the bad ones in `errors`.
+/// Returns the account if every field parsed, though it may still have failed
+/// a later check.
+fn parse_account_fields(
+    errors: &mut FieldErrors,
+    dto: NewAccountDto,
+    chart: &ChartConfig,
+) -> Result<Option<NewAccount>> {
+    // Clean up pasted text before storing it
+    let code = errors.check("code", sanitize_text("Account code", &dto.code));
+    let name = errors.check("name", sanitize_text("Account name", &dto.name));
+    let description = errors.check(
+        "description",
+        sanitize_optional_text("Description", dto.description),
+    );
+
+    let account_type = errors.check(
+        "account_type",
+        AccountType::from_str(&dto.account_type)
+            .ok_or_else(|| validation_error("Invalid account type")),
+    );
+    let category = errors.check(
+        "category",
+        AccountCategory::from_str(&dto.category)
+            .ok_or_else(|| validation_error("Invalid account category")),
+    );
+
+    let currency = errors.check("currency", parse_currency(dto.currency.as_deref()));
+
+    let parent_id = errors.check(
+        "parent_id",
+        match dto.parent_id.as_deref() {
+            None | Some("") => Ok(None),
+            Some(parent_id) => parent_id
+                .parse::<AccountId>()
+                .map(|id| Some(Uuid::from(id))),
+        },
+    );
+
+    // The rules every new account must satisfy, wherever it comes from. The
+    // remaining fields are filled in once they are known to be valid.
+    let mut new_account = match (code, name, account_type, category) {
+        (Some(code), Some(name), Some(account_type), Some(category)) => NewAccount {
+            code,
+            name,
+            description: None,
+            account_type,
+            category,
+            subcategory: dto.subcategory,
+            parent_id: None,
+            currency: String::new(),
+        },
+        (code, name, _, _) => {
+            // Without a type and category the account cannot be checked as a
+            // whole, but its code and name can still be reported
+            if let Some(code) = code {
+                errors.check("code", validate_code(&code));
+            }
+            if let Some(name) = name {
+                errors.check("name", validate_name(&name));
+            }
+            return Ok(None);
+        }
+    };
+    errors.merge(new_account.validate())?;
+
+    // A malformed code is reported once, not again for its range
+    if !errors.contains("code") {
+        errors.check(
+            "code",
+            numbering::check_code_range(chart, &new_account.code, new_account.account_type),
+        );
+    }
+
+    let (Some(description), Some(currency), Some(parent_id)) = (description, currency, parent_id)
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some(NewAccount {
+        description,
+        currency,
+        parent_id,
+        ..new_account
+    }))
+}
+
+/// Validates a new account together with its opening balance, if one is
+/// given. Every field is checked before failing, and the error lists each
+/// bad one.
+fn parse_new_account_with_opening_balance(
+    dto: NewAccountDto,
+    chart: &ChartConfig,
+) -> Result<(NewAccount, Option<(Decimal, NaiveDate)>)> {
+    let mut errors = FieldErrors::new();
+
+    let amount = dto.opening_balance.clone();
+    let date = dto.opening_balance_date.clone();
+    let new_account = parse_account_fields(&mut errors, dto, chart)?;
+    let opening_balance = parse_opening_balance(&mut errors, amount.as_deref(), date);
+    errors.sort_by_field(ACCOUNT_FORM_FIELDS);
+
+    match (new_account, opening_balance) {
+        (Some(new_account), Some(opening_balance)) => {
+            errors.finish()?;
+            Ok((new_account, opening_balance))
+        }
+        _ => Err(errors.into_error()),
+    }
+}
+
+/// Checks that an account of `account_type` may sit under `parent`. Passes
+/// any pairing when the chart allows mixed-type hierarchies.
+fn check_parent_type(
+    chart: &ChartConfig,
+    account_type: AccountType,
+    parent: &Account,
+) -> Result<()> {
+    if chart.enforce_parent_type && parent.account_type != account_type {
+        return Err(validation_error("Child account type must match parent"));
+    }
+    Ok(())
+}
+
+/// Loads the parent an account of `account_type` is to be placed under and
+/// checks its type
+async fn check_parent(
+    store: &dyn AccountStore,
+    chart: &ChartConfig,
+    account_type: AccountType,
+    parent_id: Uuid,
+) -> Result<()> {
+    if !chart.enforce_parent_type {
+        return Ok(());
+    }
+    let parent = store
+        .find_by_id(parent_id)
+        .await?
+        .ok_or_else(|| not_found("Parent account"))?;
+    check_parent_type(chart, account_type, &parent)
+}
+
+/// Parses an account id and loads the live account it names
+async fn find_account(store: &dyn AccountStore, id: &str) -> Result<Account> {
+    let account_id = Uuid::from(id.parse::<AccountId>()?);
+    store
+        .find_by_id(account_id)
+        .await?
+        .ok_or_else(|| not_found("Account"))
+}
+
+/// Validates `update_data` against the stored `account` and returns the
+/// account with the changes applied, not yet saved. Checks against other
+/// tables, such as closed periods, are left to the caller.
+async fn prepare_account_update(
+    store: &dyn AccountStore,
+    account: &Account,
+    update_data: AccountUpdateDto,
+    chart: &ChartConfig,
+) -> Result<Account> {
+    let AccountUpdateDto {
+        account: update_data,
+        version,
+    } = update_data;
+
+    // Clean up pasted text before storing it
+    let (code, name, description) = sanitize_account_text(&update_data)?;
+
+    // Codes stored before the format rules existed stay valid until changed
+    if code != account.code {
+        validate_code(&code)?;
+    }
+    validate_name(&name)?;
+
+    let account_type = AccountType::from_str(&update_data.account_type)
+        .ok_or_else(|| validation_error("Invalid account type"))?;
+    let category = AccountCategory::from_str(&update_data.category)
+        .ok_or_else(|| validation_error("Invalid account category"))?;
+
+    // The category must belong to the account type
+    category.check_valid_for(account_type)?;
+
+    // Subcategories stored before they were restricted are kept until changed
+    let subcategory =
+        if update_data.subcategory == account.subcategory && category == account.category {
+            account.subcategory.clone()
+        } else {
+            normalize_subcategory(category, update_data.subcategory.as_deref())?
+        };
+
+    // Accounts numbered before ranges were enforced keep their codes until the
+    // code or type changes
+    if code != account.code || account_type != account.account_type {
+        numbering::check_code_range(chart, &code, account_type)?;
+    }
+
+    // The currency cannot change while the account carries a balance
+    let currency = parse_currency(update_data.currency.as_deref())?;
+    if currency != account.currency && !account.balance.is_zero() {
+        return Err(validation_error(
+            "Cannot change the currency of an account with a balance",
+        ));
+    }
+
+    let parent_id = match update_data.parent_id.as_deref() {
+        None | Some("") => None,
+        Some(parent_id) => Some(Uuid::from(parent_id.parse::<AccountId>()?)),
+    };
+
+    // Guard against circular hierarchies
+    if let Some(parent_id) = parent_id {
+        if parent_id == account.id {
+            return Err(validation_error("An account cannot be its own parent"));
+        }
+        if store.would_create_cycle(account.id, parent_id).await? {
+            return Err(validation_error("Circular account hierarchy"));
+        }
+
+        // Hierarchies built before the type rule are kept until they change
+        if Some(parent_id) != account.parent_id || account_type != account.account_type {
+            check_parent(store, chart, account_type, parent_id).await?;
+        }
+    }
+
+    // A new type must still match the account's children
+    if account_type != account.account_type && chart.enforce_parent_type {
+        let children = store.find_children(account.id).await?;
+        if children
+            .iter()
+            .any(|child| child.account_type != account_type)
+        {
+            return Err(validation_error("Child account type must match parent"));
+        }
+    }
+
+    let mut updated = account.clone();
+    updated.code = code;
+    updated.name = name;
+    updated.description = description;
+    updated.account_type = account_type;
+    updated.category = category;
+    updated.subcategory = subcategory;
+    updated.parent_id = parent_id;
+    updated.currency = currency;
+    updated.updated_at = Utc::now();
+
+    // Save against the version the client loaded so concurrent edits conflict
+    updated.version = version;
+
+    Ok(updated)
+}
+
+/// Parses an optional opening balance and the date to enter it on, which
+/// defaults to today. `None` when no balance, or a zero one, was given.
+fn parse_opening_balance(
+    errors: &mut FieldErrors,
+    amount: Option<&str>,
+    date: Option<String>,
+) -> Option<Option<(Decimal, NaiveDate)>> {
+    let amount = match amount.map(str::trim) {
+        None | Some("") => return Some(None),
+        Some(amount) => errors.check("opening_balance", money::parse("Opening balance", amount)),
+    };
+    let date = errors.check(
+        "opening_balance_date",
+        parse_optional_date("Opening balance date", date),
+    );
+
+    let (amount, date) = (amount?, date?);
+    if amount.is_zero() {
+        return Some(None);
+    }
+
+    Some(Some((
+        amount,
+        date.unwrap_or_else(|| Utc::now().date_naive()),
+    )))
+}
+
+/// Name of the equity account that opening balances are offset against
+const OPENING_BALANCE_EQUITY: &str = "Opening Balance Equity";
+
+/// Finds the equity account that offsets opening balances in `currency`, or
+/// builds one at the next free top-level equity code for the caller to insert
+/// with the opening entry. The flag is set when it is not saved yet.
+async fn find_or_build_opening_balance_equity(
+    repo: &AccountRepository<'_>,
+    chart: &ChartConfig,
+    currency: &str,
+    user_id: Option<Uuid>,
+) -> Result<(Account, bool)> {
+    let name = if currency == DEFAULT_CURRENCY {
+        OPENING_BALANCE_EQUITY.to_string()
+    } else {
+        format!("{} ({})", OPENING_BALANCE_EQUITY, currency)
+    };
+
+    if let Some(account) = repo
+        .find_by_name(AccountType::Equity, &name, currency)
+        .await?
+    {
+        return Ok((account, false));
+    }
+
+    // Deleted accounts still hold their codes
+    let taken: HashSet<String> = repo.find_all_codes().await?.into_iter().collect();
+    let siblings: Vec<String> = repo
+        .find_roots()
+        .await?
+        .into_iter()
+        .filter(|account| account.account_type == AccountType::Equity)
+        .map(|account| account.code)
+        .collect();
+
+    let range = chart.code_range(AccountType::Equity);
+    let code = numbering::next_code(range, &siblings, None, &taken).ok_or_else(|| {
+        Error::Conflict(format!(
+            "No free account code left in the {} range for {} accounts",
+            range,
+            AccountType::Equity
+        ))
+    })?;
+
+    let new_account = NewAccount {
+        code,
+        name,
+        description: Some("Offsets the opening balances of new accounts".to_string()),
+        account_type: AccountType::Equity,
+        category: AccountCategory::OwnerEquity,
+        subcategory: None,
+        parent_id: None,
+        currency: currency.to_string(),
+    };
+
+    let mut account = Account::new(new_account);
+    account.created_by = user_id;
+    account.updated_by = user_id;
+
+    Ok((account, true))
+}
+
+/// Appends an activity log entry. A failure is recorded and printed but never
+/// undoes the change being logged.
+async fn record_activity(state: &AppState, activity: NewActivity) {
+    let repo = ActivityRepository::new(&state.db_pool);
+
+    if let Err(err) = repo.record(activity).await {
+        let message = state.record_error("record_activity", Error::Database(err));
+        tracing::warn!("Failed to record activity: {}", message);
+    }
+}
+
+/// Tells every open window that an account changed. The change is already
+/// saved, so a failed emit is only logged.
+fn emit_account_changed(
+    app: &tauri::AppHandle,
+    kind: AccountChangeKind,
+    account_id: Uuid,
+    account: Option<AccountViewModel>,
+) {
+    let event = AccountChangedEvent {
+        account_id: account_id.to_string(),
+        kind,
+        account,
+    };
+    if let Err(err) = app.emit(ACCOUNT_CHANGED_EVENT, event) {
+        tracing::warn!("Failed to emit {}: {}", ACCOUNT_CHANGED_EVENT, err);
+    }
+}
+
+/// Validates an optional ISO 4217 currency code, defaulting to `DEFAULT_CURRENCY`
+fn parse_currency(value: Option<&str>) -> Result<String> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(DEFAULT_CURRENCY.to_string()),
+        Some(code) => currency::normalize_currency(code)
+            .ok_or_else(|| Error::Validation(format!("Unsupported currency {}", code))),
+    }
+}
+
+/// Parses an optional YYYY-MM-DD date, treating an empty string as no date
+fn parse_optional_date(field: &str, value: Option<String>) -> Result<Option<NaiveDate>> {
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| {
+                Error::Validation(format!("{} must be a date in YYYY-MM-DD format", field))
+            }),
+    }
+}
+
+/// Loads an account, the balance its posted lines before `start_date` carry
+/// forward, and its posted lines within the range
+async fn load_ledger(
+    db_pool: &sqlx::PgPool,
+    account_id: Uuid,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+) -> Result<(Account, Decimal, Vec<LedgerLine>)> {
+    let journal_repo = JournalRepository::new(db_pool);
+
+    let account = AccountRepository::new(db_pool)
+        .find_by_id(account_id)
+        .await?
+        .ok_or_else(|| not_found("Account"))?;
+
+    let opening_balance = match start_date {
+        Some(start) => {
+            let (debit, credit) = journal_repo.sum_lines_before(account_id, start).await?;
+            account.account_type.balance_delta(debit, credit)
+        }
+        None => Decimal::ZERO,
+    };
+
+    let lines = journal_repo
+        .find_ledger_lines(account_id, start_date, end_date)
+        .await?;
+
+    Ok((account, opening_balance, lines))
+}
+
+/// Gives each line the running balance after it, growing on the account's
+/// normal side, and returns the rows with the closing balance
+fn ledger_rows(
+    account_type: AccountType,
+    opening_balance: Decimal,
+    lines: Vec<LedgerLine>,
+) -> (Vec<LedgerRowViewModel>, Decimal) {
+    let mut balance = opening_balance;
+    let rows = lines
+        .into_iter()
+        .map(|line| {
+            balance += account_type.balance_delta(line.debit, line.credit);
+            LedgerRowViewModel::new(line, balance)
+        })
+        .collect();
+
+    (rows, balance)
+}
+
+/// Loads the accounts and their posted activity and builds the income statement
+async fn build_income_statement(
+    db_pool: &sqlx::PgPool,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+) -> Result<IncomeStatement> {
+    let accounts = AccountRepository::new(db_pool).find_all().await?;
+    let activity = JournalRepository::new(db_pool)
+        .sum_activity_by_account(period_start, period_end)
+        .await?;
+
+    Ok(IncomeStatement::from_activity(
+        &accounts,
+        &activity,
+        period_start,
+        period_end,
+    ))
+}
+
+/// Loads the accounts and builds the trial balance from their current
+/// balances or, with `as_of`, from their posted activity up to that date
+async fn build_trial_balance(
+    db_pool: &sqlx::PgPool,
+    as_of: Option<NaiveDate>,
+) -> Result<TrialBalance> {
+    let accounts = AccountRepository::new(db_pool).find_all().await?;
+
+    match as_of {
+        Some(as_of) => {
+            let activity = JournalRepository::new(db_pool)
+                .sum_activity_through(as_of)
+                .await?;
+            Ok(TrialBalance::from_activity(&accounts, &activity))
+        }
+        None => Ok(TrialBalance::from_accounts(&accounts)),
+    }
+}
+
+/// Sums the balances of debit-normal and of credit-normal accounts, which
+/// must agree for every posted entry to have balanced
+async fn check_books_balanced(db_pool: &sqlx::PgPool) -> Result<BooksBalanceCheck> {
+    let accounts = AccountRepository::new(db_pool).find_all().await?;
+
+    let mut debit_normal_total = Decimal::ZERO;
+    let mut credit_normal_total = Decimal::ZERO;
+    for account in &accounts {
+        if account.account_type.is_debit_normal() {
+            debit_normal_total += account.balance;
+        } else if account.account_type.is_credit_normal() {
+            credit_normal_total += account.balance;
+        }
+    }
+
+    let is_balanced = money::approx_eq(debit_normal_total, credit_normal_total);
+    let difference = debit_normal_total - credit_normal_total;
+    if !is_balanced {
+        tracing::error!(
+            debit_normal_total = %debit_normal_total,
+            credit_normal_total = %credit_normal_total,
+            "Books do not balance"
+        );
+    }
+
+    Ok(BooksBalanceCheck {
+        debit_normal_total: money::format(debit_normal_total),
+        credit_normal_total: money::format(credit_normal_total),
+        difference: money::format(difference),
+        is_balanced,
+        checked_at: Utc::now().to_rfc3339(),
+        accounts: if is_balanced {
+            Vec::new()
+        } else {
+            accounts.into_iter().map(AccountViewModel::from).collect()
+        },
+    })
+}
+
+async fn build_budget_variance(
+    db_pool: &sqlx::PgPool,
+    period: &FiscalPeriod,
+) -> Result<BudgetVarianceReport> {
+    let accounts = AccountRepository::new(db_pool).find_all().await?;
+    let budgets = BudgetRepository::new(db_pool)
+        .find_by_period(period.id)
+        .await?;
+    let activity = JournalRepository::new(db_pool)
+        .sum_activity_by_account(period.start_date, period.end_date)
+        .await?;
+
+    Ok(BudgetVarianceReport::from_activity(
+        period, &accounts, &budgets, &activity,
+    ))
+}
+
+// Command to get all accounts
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_accounts", Error::Database(err))),
+    }
+}
+
+// Command to get all accounts in a chosen order. The direction defaults to
+// ascending; unknown fields or directions are rejected.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_sorted(
+    sort_by: String,
+    direction: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the sort field
+    let field = match AccountSortField::parse(&sort_by) {
+        Some(field) => field,
+        None => {
+            return Err(state.record_error(
+                "get_accounts_sorted",
+                Error::Validation(format!(
+                    "Cannot sort by '{}'; use code, name, type, balance or created_at",
+                    sort_by
+                )),
+            ))
+        }
+    };
+
+    // Parse the direction
+    let direction = match direction.as_deref() {
+        None | Some("") => SortDirection::Asc,
+        Some(value) => match SortDirection::parse(value) {
+            Some(direction) => direction,
+            None => {
+                return Err(state.record_error(
+                    "get_accounts_sorted",
+                    validation_error("Sort direction must be asc or desc"),
+                ))
+            }
+        },
+    };
+
+    match repo.find_sorted(field, direction).await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_accounts_sorted", Error::Database(err))),
+    }
+}
+
+// Command to get the posted line count and last activity date of every
+// account, to find dormant accounts. Sorts by code, line_count or
+// last_activity; accounts never posted to sort as the least recently active.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_activity_stats(
+    sort_by: Option<String>,
+    direction: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountActivityStatsViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the sort field
+    let field = match sort_by.as_deref() {
+        None | Some("") => ActivityStatsSortField::Code,
+        Some(value) => match ActivityStatsSortField::parse(value) {
+            Some(field) => field,
+            None => {
+                return Err(state.record_error(
+                    "get_account_activity_stats",
+                    Error::Validation(format!(
+                        "Cannot sort by '{}'; use code, line_count or last_activity",
+                        value
+                    )),
+                ))
+            }
+        },
+    };
+
+    // Parse the direction
+    let direction = match direction.as_deref() {
+        None | Some("") => SortDirection::Asc,
+        Some(value) => match SortDirection::parse(value) {
+            Some(direction) => direction,
+            None => {
+                return Err(state.record_error(
+                    "get_account_activity_stats",
+                    validation_error("Sort direction must be asc or desc"),
+                ))
+            }
+        },
+    };
+
+    match repo.activity_stats(field, direction).await {
+        Ok(stats) => Ok(stats
+            .into_iter()
+            .map(AccountActivityStatsViewModel::from)
+            .collect()),
+        Err(err) => Err(state.record_error("get_account_activity_stats", Error::Database(err))),
+    }
+}
+
+// Command to get accounts filtered by type and/or category. Filters combine
+// with AND; an empty or missing filter matches everything.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_filtered(
+    account_type: Option<String>,
+    category: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account type if present
+    let account_type = match account_type.as_deref() {
+        None | Some("") => None,
+        Some(value) => match AccountType::from_str(value) {
+            Some(t) => Some(t),
+            None => {
+                return Err(state.record_error(
+                    "get_accounts_filtered",
+                    validation_error("Invalid account type"),
+                ))
+            }
+        },
+    };
+
+    // Parse the category if present
+    let category = match category.as_deref() {
+        None | Some("") => None,
+        Some(value) => match AccountCategory::from_str(value) {
+            Some(c) => Some(c),
+            None => {
+                return Err(state.record_error(
+                    "get_accounts_filtered",
+                    validation_error("Invalid account category"),
+                ))
+            }
+        },
+    };
+
+    match repo.find_filtered(account_type, category).await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_accounts_filtered", Error::Database(err))),
+    }
+}
+
+// Command to get one page of accounts, ordered by code (pages start at 1)
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_paged(
+    page: u32,
+    page_size: u32,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountPage, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    if page == 0 {
+        return Err(state.record_error(
+            "get_accounts_paged",
+            validation_error("Page numbers start at 1"),
+        ));
+    }
+
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        return Err(state.record_error(
+            "get_accounts_paged",
+            Error::Validation(format!("Page size must be between 1 and {}", MAX_PAGE_SIZE)),
+        ));
+    }
+
+    let total_count = match repo.count().await {
+        Ok(count) => count,
+        Err(err) => return Err(state.record_error("get_accounts_paged", Error::Database(err))),
+    };
+
+    let offset = (page as i64 - 1) * page_size as i64;
+    match repo.find_paginated(page_size as i64, offset).await {
+        Ok(accounts) => Ok(AccountPage {
+            accounts: accounts.into_iter().map(AccountViewModel::from).collect(),
+            page,
+            page_size,
+            total_count,
+        }),
+        Err(err) => Err(state.record_error("get_accounts_paged", Error::Database(err))),
+    }
+}
+
+// Command to get up to `limit` accounts ordered by code, starting after
+// `cursor_code` (the first page when empty). `next_cursor` is None on the last page.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_after(
+    cursor_code: Option<String>,
+    limit: u32,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountStreamChunk, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return Err(state.record_error(
+            "get_accounts_after",
+            Error::Validation(format!("Limit must be between 1 and {}", MAX_PAGE_SIZE)),
+        ));
+    }
+
+    let cursor_code = cursor_code
+        .as_deref()
+        .map(str::trim)
+        .filter(|code| !code.is_empty());
+
+    // Fetch one extra row so a full last page is not followed by an empty one
+    let mut accounts = match repo.find_after_code(cursor_code, limit as i64 + 1).await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("get_accounts_after", Error::Database(err))),
+    };
+
+    let next_cursor = if accounts.len() > limit as usize {
+        accounts.truncate(limit as usize);
+        accounts.last().map(|account| account.code.clone())
+    } else {
+        None
+    };
+
+    Ok(AccountStreamChunk {
+        accounts: accounts.into_iter().map(AccountViewModel::from).collect(),
+        next_cursor,
+    })
+}
+
+// Command to search accounts by code or name, most relevant first
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn search_accounts(
+    query: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountSearchResult>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.search(&query).await {
+        Ok(matches) => Ok(matches
+            .into_iter()
+            .map(|(account, match_kind)| AccountSearchResult {
+                account: AccountViewModel::from(account),
+                match_kind,
+            })
+            .collect()),
+        Err(err) => Err(state.record_error("search_accounts", Error::Database(err))),
+    }
+}
+
+// Command to get the accounts changed after an RFC 3339 timestamp, for clients
+// that keep a local copy and only pull the changes
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_modified_since(
+    timestamp: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<ModifiedAccount>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the timestamp
+    let since = match DateTime::parse_from_rfc3339(timestamp.trim()) {
+        Ok(since) => since.with_timezone(&Utc),
+        Err(_) => {
+            return Err(state.record_error(
+                "get_accounts_modified_since",
+                Error::Validation(format!(
+                    "Invalid timestamp '{}', expected RFC 3339 such as 2025-03-01T12:00:00Z",
+                    timestamp
+                )),
+            ))
+        }
+    };
+
+    match repo.find_modified_since(since).await {
+        Ok(accounts) => Ok(accounts
+            .into_iter()
+            .map(|account| ModifiedAccount {
+                is_deleted: account.deleted_at.is_some(),
+                account: AccountViewModel::from(account),
+            })
+            .collect()),
+        Err(err) => Err(state.record_error("get_accounts_modified_since", Error::Database(err))),
+    }
+}
+
+// Command to get an account by ID
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account(
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Option<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_account", err)),
+    };
+
+    match repo.find_by_id(account_id).await {
+        Ok(Some(account)) => Ok(Some(AccountViewModel::from(account))),
+        Ok(None) => Ok(None),
+        Err(err) => Err(state.record_error("get_account", Error::Database(err))),
+    }
+}
+
+// Command to create a new account
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn create_account(
+    new_account: NewAccountDto,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("create_account", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Validate and convert to the domain model, with the opening balance
+    let (domain_new_account, opening_balance) =
+        match parse_new_account_with_opening_balance(new_account, &state.config.chart) {
+            Ok(parsed) => parsed,
+            Err(err) => return Err(state.record_error("create_account", err)),
+        };
+
+    // Children share their parent's account type unless the chart allows mixing
+    if let Some(parent_id) = domain_new_account.parent_id {
+        if let Err(err) = check_parent(
+            &repo,
+            &state.config.chart,
+            domain_new_account.account_type,
+            parent_id,
+        )
+        .await
+        {
+            return Err(state.record_error("create_account", err));
+        }
+    }
+
+    // Create the account. An opening balance is posted as a journal entry
+    // against the opening balance equity account, keeping the books balanced.
+    let account = match opening_balance {
+        None => match repo.create(domain_new_account, user_id).await {
+            Ok(account) => account,
+            Err(err) => return Err(state.record_error("create_account", err)),
+        },
+        Some((amount, entry_date)) => {
+            let (equity, equity_is_new) = match find_or_build_opening_balance_equity(
+                &repo,
+                &state.config.chart,
+                &domain_new_account.currency,
+                user_id,
+            )
+            .await
+            {
+                Ok(equity) => equity,
+                Err(err) => return Err(state.record_error("create_account", err)),
+            };
+
+            // A new equity account is saved in the same transaction as the
+            // account and its opening entry
+            let account = match repo
+                .create_with_opening_balance(
+                    domain_new_account,
+                    user_id,
+                    equity.id,
+                    equity_is_new.then_some(&equity),
+                    amount,
+                    entry_date,
+                )
+                .await
+            {
+                Ok((account, _)) => account,
+                Err(err) => return Err(state.record_error("create_account", err)),
+            };
+
+            if equity_is_new {
+                record_activity(
+                    &state,
+                    NewActivity {
+                        action: ActivityAction::AccountCreated,
+                        entity_id: Some(equity.id),
+                        description: format!("Created account {} {}", equity.code, equity.name),
+                        user_id,
+                        details: Vec::new(),
+                    },
+                )
+                .await;
+            }
+
+            account
+        }
+    };
+
+    let description = match opening_balance {
+        Some((amount, _)) => format!(
+            "Created account {} {} with opening balance {}",
+            account.code,
+            account.name,
+            money::format(amount)
+        ),
+        None => format!("Created account {} {}", account.code, account.name),
+    };
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountCreated,
+            entity_id: Some(account.id),
+            description,
+            user_id,
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    let account_id = account.id;
+    let view = AccountViewModel::from(account);
+    emit_account_changed(
+        &app,
+        AccountChangeKind::Created,
+        account_id,
+        Some(view.clone()),
+    );
+    Ok(view)
+}
+
+// Command to create a batch of accounts, e.g. when importing a chart of accounts.
+// Either every account is created or none is.
+#[tauri::command]
+#[tracing::instrument(skip(new_accounts, token, state), fields(count = new_accounts.len()), err)]
+pub async fn create_accounts_bulk(
+    new_accounts: Vec<NewAccountDto>,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("create_accounts_bulk", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    if new_accounts.is_empty() {
+        return Err(state.record_error(
+            "create_accounts_bulk",
+            validation_error("No accounts to create"),
+        ));
+    }
+
+    // Validate every row before touching the database, naming the failing code
+    let mut domain_new_accounts = Vec::with_capacity(new_accounts.len());
+    for dto in new_accounts {
+        let code = dto.code.clone();
+        if matches!(dto.opening_balance.as_deref().map(str::trim), Some(amount) if !amount.is_empty())
+        {
+            return Err(state.record_error(
+                "create_accounts_bulk",
+                Error::Validation(format!(
+                    "Account '{}': opening balances can only be entered one account at a time",
+                    code
+                )),
+            ));
+        }
+        match parse_new_account(dto, &state.config.chart) {
+            Ok(new_account) => domain_new_accounts.push(new_account),
+            Err(Error::InvalidFields(errors)) => {
+                return Err(state.record_error(
+                    "create_accounts_bulk",
+                    Error::Validation(format!(
+                        "Account '{}': {}",
+                        code,
+                        join_field_errors(&errors)
+                    )),
+                ))
+            }
+            Err(err) => return Err(state.record_error("create_accounts_bulk", err)),
+        }
+    }
+
+    let accounts = match repo.create_many(domain_new_accounts, user_id).await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("create_accounts_bulk", err)),
+    };
+
+    for account in &accounts {
+        record_activity(
+            &state,
+            NewActivity {
+                action: ActivityAction::AccountCreated,
+                entity_id: Some(account.id),
+                description: format!("Created account {} {}", account.code, account.name),
+                user_id,
+                details: Vec::new(),
+            },
+        )
+        .await;
+    }
+
+    Ok(accounts.into_iter().map(AccountViewModel::from).collect())
+}
+
+// Command to fill an empty chart with a standard small-business chart of
+// accounts. Refuses to run once any account exists, including deleted ones.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn seed_default_chart(
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("seed_default_chart", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    let new_accounts = match seed::default_chart(&state.config.chart) {
+        Ok(new_accounts) => new_accounts,
+        Err(err) => return Err(state.record_error("seed_default_chart", err)),
+    };
+
+    let accounts: Vec<Account> = new_accounts
+        .into_iter()
+        .map(|new_account| {
+            let mut account = Account::new(new_account);
+            account.created_by = user_id;
+            account.updated_by = user_id;
+            account
+        })
+        .collect();
+
+    if let Err(err) = repo.insert_into_empty(&accounts).await {
+        return Err(state.record_error("seed_default_chart", err));
+    }
+
+    for account in &accounts {
+        record_activity(
+            &state,
+            NewActivity {
+                action: ActivityAction::AccountCreated,
+                entity_id: Some(account.id),
+                description: format!("Created account {} {}", account.code, account.name),
+                user_id,
+                details: Vec::new(),
+            },
+        )
+        .await;
+    }
+
+    Ok(accounts.into_iter().map(AccountViewModel::from).collect())
+}
+
+// Command to suggest the next free code for a new account, within the range
+// configured for its type and following its future siblings
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn suggest_next_code(
+    account_type: String,
+    parent_id: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<String, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account type
+    let account_type = match AccountType::from_str(&account_type) {
+        Some(t) => t,
+        None => {
+            return Err(state.record_error(
+                "suggest_next_code",
+                validation_error("Invalid account type"),
+            ))
+        }
+    };
+
+    // Parse the parent ID if present
+    let parent_id = match parent_id {
+        Some(id_str) if !id_str.is_empty() => match id_str.parse::<AccountId>() {
+            Ok(id) => Some(Uuid::from(id)),
+            Err(err) => return Err(state.record_error("suggest_next_code", err)),
+        },
+        _ => None,
+    };
+
+    // New codes follow the parent's children, or the top-level accounts
+    let (parent_code, siblings) = match parent_id {
+        Some(parent_id) => {
+            let parent = match repo.find_by_id(parent_id).await {
+                Ok(Some(parent)) => parent,
+                Ok(None) => {
+                    return Err(state.record_error("suggest_next_code", not_found("Parent account")))
+                }
+                Err(err) => {
+                    return Err(state.record_error("suggest_next_code", Error::Database(err)))
+                }
+            };
+            match repo.find_children(parent_id).await {
+                Ok(children) => (Some(parent.code), children),
+                Err(err) => {
+                    return Err(state.record_error("suggest_next_code", Error::Database(err)))
+                }
+            }
+        }
+        None => match repo.find_roots().await {
+            Ok(roots) => (None, roots),
+            Err(err) => return Err(state.record_error("suggest_next_code", Error::Database(err))),
+        },
+    };
+
+    // Deleted accounts still hold their codes
+    let taken: HashSet<String> = match repo.find_all_codes().await {
+        Ok(codes) => codes.into_iter().collect(),
+        Err(err) => return Err(state.record_error("suggest_next_code", Error::Database(err))),
+    };
+
+    let range = state.config.chart.code_range(account_type);
+    let sibling_codes: Vec<String> = siblings
+        .into_iter()
+        .filter(|account| account.account_type == account_type)
+        .map(|account| account.code)
+        .collect();
+
+    match numbering::next_code(range, &sibling_codes, parent_code.as_deref(), &taken) {
+        Some(code) => Ok(code),
+        None => Err(state.record_error(
+            "suggest_next_code",
+            Error::Conflict(format!(
+                "No free account code left in the {} range for {} accounts",
+                range, account_type
+            )),
+        )),
+    }
+}
+
+// Command to update an account
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn update_account(
+    id: String,
+    update_data: AccountUpdateDto,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("update_account", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Load the account and validate the changes against it
+    let existing = match find_account(&repo, &id).await {
+        Ok(account) => account,
+        Err(err) => return Err(state.record_error("update_account", err)),
+    };
+    let account_id = existing.id;
+    let mut account =
+        match prepare_account_update(&repo, &existing, update_data, &state.config.chart).await {
+            Ok(account) => account,
+            Err(err) => return Err(state.record_error("update_account", err)),
+        };
+
+    // Type and currency decide how posted amounts read, so they are frozen once
+    // the account has activity in a closed period
+    if account.account_type != existing.account_type || account.currency != existing.currency {
+        match PeriodRepository::new(db_pool)
+            .has_closed_activity(account_id)
+            .await
+        {
+            Ok(false) => {}
+            Ok(true) => {
+                return Err(state.record_error(
+                    "update_account",
+                    Error::Conflict("Period is closed".to_string()),
+                ))
+            }
+            Err(err) => return Err(state.record_error("update_account", Error::Database(err))),
+        }
+    }
+
+    // Save the updated account
+    if let Err(err) = repo.update(&mut account, user_id).await {
+        return Err(state.record_error("update_account", err));
+    }
+
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountUpdated,
+            entity_id: Some(account.id),
+            description: format!("Updated account {} {}", account.code, account.name),
+            user_id,
+            details: account.changes_from(&existing),
+        },
+    )
+    .await;
+
+    let view = AccountViewModel::from(account);
+    emit_account_changed(
+        &app,
+        AccountChangeKind::Updated,
+        account_id,
+        Some(view.clone()),
+    );
+    Ok(view)
+}
+
+// Command to delete an account
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn delete_account(
+    id: String,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<(), ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("delete_account", err)),
+    };
+
+    // Look the account up first so the log can name it
+    let account = match find_account(&repo, &id).await {
+        Ok(account) => account,
+        Err(err) => return Err(state.record_error("delete_account", err)),
+    };
+    let account_id = account.id;
+
+    // Accounts with activity in a closed period must stay in the books
+    match PeriodRepository::new(db_pool)
+        .has_closed_activity(account_id)
+        .await
+    {
+        Ok(false) => {}
+        Ok(true) => {
+            return Err(state.record_error(
+                "delete_account",
+                Error::Conflict("Period is closed".to_string()),
+            ))
+        }
+        Err(err) => return Err(state.record_error("delete_account", Error::Database(err))),
+    }
+
+    if let Err(err) = repo.delete(account_id).await {
+        return Err(state.record_error("delete_account", err));
+    }
+
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountDeleted,
+            entity_id: Some(account.id),
+            description: format!("Deleted account {} {}", account.code, account.name),
+            user_id: Some(claims.sub),
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    emit_account_changed(&app, AccountChangeKind::Deleted, account_id, None);
+    Ok(())
+}
+
+// Command to move an account under a new parent, or to the top level when no
+// parent is given. With `inherit_category` the account adopts the parent's
+// category, which must be valid for the account's type.
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn reparent_account(
+    id: String,
+    new_parent_id: Option<String>,
+    inherit_category: bool,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("reparent_account", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Parse the account ids
+    let account_id = match id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("reparent_account", err)),
+    };
+    let parent_id = match new_parent_id.as_deref() {
+        None | Some("") => None,
+        Some(parent_id) => match parent_id.parse::<AccountId>() {
+            Ok(id) => Some(Uuid::from(id)),
+            Err(err) => return Err(state.record_error("reparent_account", err)),
+        },
+    };
+
+    let mut account = match repo.find_by_id(account_id).await {
+        Ok(Some(account)) => account,
+        Ok(None) => return Err(state.record_error("reparent_account", not_found("Account"))),
+        Err(err) => return Err(state.record_error("reparent_account", Error::Database(err))),
+    };
+
+    let parent = match parent_id {
+        Some(parent_id) => match repo.find_by_id(parent_id).await {
+            Ok(Some(parent)) => Some(parent),
+            Ok(None) => {
+                return Err(state.record_error("reparent_account", not_found("Parent account")))
+            }
+            Err(err) => return Err(state.record_error("reparent_account", Error::Database(err))),
+        },
+        None => None,
+    };
+
+    // Guard against circular hierarchies
+    if let Some(parent) = &parent {
+        if parent.id == account_id {
+            return Err(state.record_error(
+                "reparent_account",
+                validation_error("An account cannot be its own parent"),
+            ));
+        }
+
+        match repo.would_create_cycle(account_id, parent.id).await {
+            Ok(false) => {}
+            Ok(true) => {
+                return Err(state.record_error(
+                    "reparent_account",
+                    validation_error("Circular account hierarchy"),
+                ))
+            }
+            Err(err) => return Err(state.record_error("reparent_account", Error::Database(err))),
+        }
+
+        if let Err(err) = check_parent_type(&state.config.chart, account.account_type, parent) {
+            return Err(state.record_error("reparent_account", err));
+        }
+    }
+
+    // A top-level account has no category to inherit
+    if inherit_category {
+        if let Some(parent) = &parent {
+            if !parent.category.is_valid_for(account.account_type) {
+                return Err(state.record_error(
+                    "reparent_account",
+                    Error::Validation(format!(
+                        "Parent category {} is not valid for account type {}",
+                        parent.category, account.account_type
+                    )),
+                ));
+            }
+            account.set_category(parent.category);
+        }
+    }
+
+    account.parent_id = parent_id;
+    account.updated_at = Utc::now();
+
+    if let Err(err) = repo.update(&mut account, user_id).await {
+        return Err(state.record_error("reparent_account", err));
+    }
+
+    let destination = match &parent {
+        Some(parent) => format!("under {} {}", parent.code, parent.name),
+        None => "to the top level".to_string(),
+    };
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountUpdated,
+            entity_id: Some(account.id),
+            description: format!(
+                "Moved account {} {} {}",
+                account.code, account.name, destination
+            ),
+            user_id,
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    let view = AccountViewModel::from(account);
+    emit_account_changed(
+        &app,
+        AccountChangeKind::Updated,
+        account_id,
+        Some(view.clone()),
+    );
+    Ok(view)
+}
+
+// Command to preview what deleting an account would affect, without deleting it
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_delete_impact(
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<DeleteImpactViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_account_delete_impact", err)),
+    };
+
+    match repo.delete_impact(account_id).await {
+        Ok(Some(impact)) => Ok(DeleteImpactViewModel::from(impact)),
+        Ok(None) => Err(state.record_error("get_account_delete_impact", not_found("Account"))),
+        Err(err) => Err(state.record_error("get_account_delete_impact", Error::Database(err))),
+    }
+}
+
+// Command to restore a soft-deleted account
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn restore_account(
+    id: String,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("restore_account", err)),
+    };
+
+    // Parse the account id
+    let account_id = match id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("restore_account", err)),
+    };
+
+    let account = match repo.restore(account_id).await {
+        Ok(account) => account,
+        Err(err) => return Err(state.record_error("restore_account", err)),
+    };
+
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountRestored,
+            entity_id: Some(account.id),
+            description: format!("Restored account {} {}", account.code, account.name),
+            user_id: Some(claims.sub),
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    // Lists dropped the account when it was deleted, so it comes back as new
+    let account_id = account.id;
+    let view = AccountViewModel::from(account);
+    emit_account_changed(
+        &app,
+        AccountChangeKind::Created,
+        account_id,
+        Some(view.clone()),
+    );
+    Ok(view)
+}
+
+// Command to toggle account active status
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn toggle_account_status(
+    id: String,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("toggle_account_status", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Retrieve the existing account
+    let mut account = match find_account(&repo, &id).await {
+        Ok(account) => account,
+        Err(err) => return Err(state.record_error("toggle_account_status", err)),
+    };
+    let account_id = account.id;
+
+    // Toggle the active status
+    account.is_active = !account.is_active;
+    account.updated_at = Utc::now();
+
+    // Save the updated account
+    if let Err(err) = repo.update(&mut account, user_id).await {
+        return Err(state.record_error("toggle_account_status", err));
+    }
+
+    let status = if account.is_active {
+        "Activated"
+    } else {
+        "Deactivated"
+    };
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::AccountStatusChanged,
+            entity_id: Some(account.id),
+            description: format!("{} account {} {}", status, account.code, account.name),
+            user_id,
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    let view = AccountViewModel::from(account);
+    emit_account_changed(
+        &app,
+        AccountChangeKind::StatusChanged,
+        account_id,
+        Some(view.clone()),
+    );
+    Ok(view)
+}
+
+// Command to activate or deactivate a batch of accounts. Unlike
+// toggle_account_status it sets the given state, so repeating it is harmless.
+// Ids without an account are reported rather than failing the batch.
+#[tauri::command]
+#[tracing::instrument(skip(token, app, state), err)]
+pub async fn toggle_accounts_status(
+    ids: Vec<String>,
+    active: bool,
+    token: String,
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountStatusBatch, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("toggle_accounts_status", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Parse the account ids
+    let mut account_ids = Vec::with_capacity(ids.len());
+    for id in &ids {
+        match id.parse::<AccountId>() {
+            Ok(id) => account_ids.push(Uuid::from(id)),
+            Err(err) => return Err(state.record_error("toggle_accounts_status", err)),
+        }
+    }
+    account_ids.sort();
+    account_ids.dedup();
+
+    let changed = match repo.set_active(&account_ids, active, user_id).await {
+        Ok(changed) => changed,
+        Err(err) => return Err(state.record_error("toggle_accounts_status", Error::Database(err))),
+    };
+
+    let accounts = match repo.find_by_ids(&account_ids).await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("toggle_accounts_status", Error::Database(err))),
+    };
+
+    let found: HashSet<Uuid> = accounts.iter().map(|account| account.id).collect();
+    let missing = account_ids
+        .iter()
+        .filter(|id| !found.contains(id))
+        .map(Uuid::to_string)
+        .collect();
+
+    let status = if active { "Activated" } else { "Deactivated" };
+    for account in accounts
+        .iter()
+        .filter(|account| changed.contains(&account.id))
+    {
+        record_activity(
+            &state,
+            NewActivity {
+                action: ActivityAction::AccountStatusChanged,
+                entity_id: Some(account.id),
+                description: format!("{} account {} {}", status, account.code, account.name),
+                user_id,
+                details: Vec::new(),
+            },
+        )
+        .await;
+        emit_account_changed(
+            &app,
+            AccountChangeKind::StatusChanged,
+            account.id,
+            Some(AccountViewModel::from(account.clone())),
+        );
+    }
+
+    Ok(AccountStatusBatch {
+        updated: accounts.into_iter().map(AccountViewModel::from).collect(),
+        missing,
+    })
+}
+
+/// Moves every account to `category`, or none of them if the category is
+/// not valid for any one account's type
+fn reclassify(accounts: &[Account], category: AccountCategory) -> Result<Vec<Account>> {
+    if let Some(account) = accounts
+        .iter()
+        .find(|account| !category.is_valid_for(account.account_type))
+    {
+        return Err(Error::Validation(format!(
+            "Category {} is not valid for account {} of type {}",
+            category, account.code, account.account_type
+        )));
+    }
+
+    Ok(accounts
+        .iter()
+        .map(|account| {
+            let mut account = account.clone();
+            account.set_category(category);
+            account
+        })
+        .collect())
+}
+
+// Command to move a batch of accounts to a new category
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn reclassify_accounts(
+    ids: Vec<String>,
+    new_category: String,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("reclassify_accounts", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    // Parse the category
+    let category = match AccountCategory::from_str(&new_category) {
+        Some(c) => c,
+        None => {
+            return Err(state.record_error(
+                "reclassify_accounts",
+                validation_error("Invalid account category"),
+            ))
+        }
+    };
+
+    // Parse the account ids
+    let mut account_ids = Vec::with_capacity(ids.len());
+    for id in &ids {
+        match id.parse::<AccountId>() {
+            Ok(id) => account_ids.push(Uuid::from(id)),
+            Err(err) => return Err(state.record_error("reclassify_accounts", err)),
+        }
+    }
+    account_ids.sort();
+    account_ids.dedup();
+
+    // Retrieve the accounts and make sure every one of them exists
+    let accounts = match repo.find_by_ids(&account_ids).await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("reclassify_accounts", Error::Database(err))),
+    };
+    if accounts.len() != account_ids.len() {
+        return Err(state.record_error("reclassify_accounts", not_found("Account")));
+    }
+
+    let reclassified = match reclassify(&accounts, category) {
+        Ok(reclassified) => reclassified,
+        Err(err) => return Err(state.record_error("reclassify_accounts", err)),
+    };
+
+    // The audit entries are written in the same transaction as the change
+    let activities: Vec<NewActivity> = reclassified
+        .iter()
+        .zip(&accounts)
+        .map(|(account, existing)| NewActivity {
+            action: ActivityAction::AccountUpdated,
+            entity_id: Some(account.id),
+            description: format!(
+                "Reclassified account {} {} as {}",
+                account.code, account.name, category
+            ),
+            user_id,
+            details: account.changes_from(existing),
+        })
+        .collect();
+
+    if let Err(err) = repo
+        .update_category(&reclassified, user_id, &activities)
+        .await
+    {
+        return Err(state.record_error("reclassify_accounts", err));
+    }
+
+    match repo.find_by_ids(&account_ids).await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("reclassify_accounts", Error::Database(err))),
+    }
+}
+
+/// Turns IIF rows into accounts ready to insert. Rows with an unknown type
+/// are returned as unmapped; rows that fail validation, repeat an earlier
+/// row's number or sit under a parent that was not imported are skipped with
+/// the reason.
+fn prepare_iif_accounts(
+    rows: Vec<iif::IifAccount>,
+    chart: &ChartConfig,
+    user_id: Option<Uuid>,
+) -> Result<(Vec<Account>, Vec<UnmappedIifAccount>, Vec<String>)> {
+    let mut accounts: Vec<Account> = Vec::new();
+    let mut indexes_by_name: HashMap<String, usize> = HashMap::new();
+    let mut codes: HashSet<String> = HashSet::new();
+    let mut unmapped = Vec::new();
+    let mut skipped = Vec::new();
+
+    // IIF lists parents before their sub-accounts, so parents resolve in one pass
+    for row in rows {
+        let Some((account_type, category)) = iif::map_account_type(&row.foreign_type) else {
+            unmapped.push(UnmappedIifAccount {
+                name: row.name,
+                foreign_type: row.foreign_type,
+            });
+            continue;
+        };
+
+        let Some(code) = row.number.clone() else {
+            skipped.push(format!("{}: missing account number", row.name));
+            continue;
+        };
+
+        // A sub-account of a row that was left out would otherwise become a
+        // top-level account
+        let parent = match row.parent_name() {
+            Some(parent_name) => match indexes_by_name.get(parent_name) {
+                Some(&index) => Some(&accounts[index]),
+                None => {
+                    skipped.push(format!(
+                        "{}: parent {} was not imported",
+                        row.name, parent_name
+                    ));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        if let Some(parent) = parent {
+            // The type check only ever fails with Error::Validation
+            if let Err(Error::Validation(message)) = check_parent_type(chart, account_type, parent)
+            {
+                skipped.push(format!("{}: {}", row.name, message));
+                continue;
+            }
+        }
+
+        // Rows go through the same checks as accounts created by hand
+        let dto = NewAccountDto {
+            code,
+            name: row.short_name().to_string(),
+            description: row.description.clone(),
+            account_type: account_type.to_string(),
+            category: category.to_string(),
+            subcategory: None,
+            parent_id: parent.map(|parent| parent.id.to_string()),
+            currency: None,
+            opening_balance: None,
+            opening_balance_date: None,
+        };
+        let new_account = match parse_new_account(dto, chart) {
+            Ok(new_account) => new_account,
+            Err(Error::InvalidFields(errors)) => {
+                skipped.push(format!("{}: {}", row.name, join_field_errors(&errors)));
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        // The database only checks codes against the existing chart, so a
+        // number repeated within the file is caught here
+        if !codes.insert(new_account.code.clone()) {
+            skipped.push(format!(
+                "{}: account number {} appears earlier in the file",
+                row.name, new_account.code
+            ));
+            continue;
+        }
+
+        let mut account = Account::new(new_account);
+        account.created_by = user_id;
+        account.updated_by = user_id;
+
+        indexes_by_name.insert(row.name, accounts.len());
+        accounts.push(account);
+    }
+
+    Ok((accounts, unmapped, skipped))
+}
+
+// Command to import a chart of accounts from a QuickBooks IIF export
+#[tauri::command]
+#[tracing::instrument(skip(content, token, state), err)]
+pub async fn import_chart_iif(
+    content: String,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<IifImportReport, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Only accountants and admins may change accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("import_chart_iif", err)),
+    };
+    let user_id = Some(claims.sub);
+
+    let rows = match iif::parse_accounts(&content) {
+        Ok(rows) => rows,
+        Err(err) => return Err(state.record_error("import_chart_iif", err)),
+    };
+
+    let (accounts, unmapped, skipped) =
+        match prepare_iif_accounts(rows, &state.config.chart, user_id) {
+            Ok(prepared) => prepared,
+            Err(err) => return Err(state.record_error("import_chart_iif", err)),
+        };
+
+    // Report a clash with the existing chart as a conflict rather than
+    // letting the unique index reject the insert
+    let codes: Vec<String> = accounts.iter().map(|a| a.code.clone()).collect();
+    if let Err(err) = repo.check_codes_available(&codes).await {
+        return Err(state.record_error("import_chart_iif", err));
+    }
+
+    if let Err(err) = repo.insert_many(&accounts).await {
+        return Err(state.record_error("import_chart_iif", Error::Database(err)));
+    }
+
+    for account in &accounts {
+        record_activity(
+            &state,
+            NewActivity {
+                action: ActivityAction::AccountCreated,
+                entity_id: Some(account.id),
+                description: format!(
+                    "Imported account {} {} from IIF",
+                    account.code, account.name
+                ),
+                user_id,
+                details: Vec::new(),
+            },
+        )
+        .await;
+    }
+
+    Ok(IifImportReport {
+        imported: accounts.into_iter().map(AccountViewModel::from).collect(),
+        unmapped,
+        skipped,
+    })
+}
+
+// Command to export the chart of accounts as CSV text in the import format
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn export_accounts_csv(
+    active_only: Option<bool>,
+    include_balances: Option<bool>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<String, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(csv::export_accounts(
+            &accounts,
+            active_only.unwrap_or(false),
+            include_balances.unwrap_or(false),
+        )),
+        Err(err) => Err(state.record_error("export_accounts_csv", Error::Database(err))),
+    }
+}
+
+// Command to get root accounts (top-level)
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_root_accounts(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_roots().await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_root_accounts", Error::Database(err))),
+    }
+}
+
+// Command to get all accounts with balances rolled up from their descendants
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_rolled_up(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all_with_rolled_up_balances().await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_accounts_rolled_up", Error::Database(err))),
+    }
+}
+
+// Command to get all accounts grouped by type, each group with its balance subtotal
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_grouped(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountGroup>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(AccountGroup::group(accounts)),
+        Err(err) => Err(state.record_error("get_accounts_grouped", Error::Database(err))),
+    }
+}
+
+// Command to get the full chart of accounts as a nested tree
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_tree(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountTreeNode>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_tree().await {
+        Ok(accounts) => Ok(AccountTreeNode::build(accounts)),
+        Err(err) => Err(state.record_error("get_account_tree", Error::Database(err))),
+    }
+}
+
+// Command to get the ancestors of an account from the root down, for breadcrumbs
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_path(
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountPath, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_account_path", err)),
+    };
+
+    match repo.find_path(account_id).await {
+        Ok(accounts) if accounts.is_empty() => {
+            Err(state.record_error("get_account_path", not_found("Account")))
+        }
+        Ok(accounts) => Ok(AccountPath {
+            depth: accounts.len() as u32 - 1,
+            accounts: accounts.into_iter().map(AccountViewModel::from).collect(),
+        }),
+        Err(err) => Err(state.record_error("get_account_path", Error::Database(err))),
+    }
+}
+
+// Command to get an account with the total balance of its subtree. Cheaper than
+// get_accounts_rolled_up when only one account is being drilled into.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_subtree_balance(
+    id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<SubtreeBalance, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    let account = match find_account(&repo, &id).await {
+        Ok(account) => account,
+        Err(err) => return Err(state.record_error("get_subtree_balance", err)),
+    };
+
+    let descendants = match repo.find_descendants(account.id).await {
+        Ok(descendants) => descendants,
+        Err(err) => return Err(state.record_error("get_subtree_balance", Error::Database(err))),
+    };
+
+    // A leaf account's subtree is just the account itself
+    let subtree_balance = account.balance
+        + descendants
+            .iter()
+            .map(|descendant| descendant.balance)
+            .sum::<Decimal>();
+
+    Ok(SubtreeBalance {
+        account: AccountViewModel::from(account),
+        subtree_balance: money::format(subtree_balance),
+        descendant_count: descendants.len(),
+    })
+}
+
+// Command to append a dated note to an account; notes cannot be edited or removed
+#[tauri::command]
+#[tracing::instrument(skip(body, token, state), err)]
+pub async fn add_account_note(
+    account_id: String,
+    body: String,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountNoteViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = NoteRepository::new(db_pool);
+
+    // Only accountants and admins may annotate accounts
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("add_account_note", err)),
+    };
+
+    // Parse the account id
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("add_account_note", err)),
+    };
+
+    let body = match sanitize_text("Note", &body) {
+        Ok(body) if body.is_empty() => {
+            return Err(state.record_error(
+                "add_account_note",
+                validation_error("Note must not be empty"),
+            ))
+        }
+        Ok(body) => body,
+        Err(err) => return Err(state.record_error("add_account_note", err)),
+    };
+
+    match repo.add_note(account_id, &body, Some(claims.sub)).await {
+        Ok(note) => Ok(AccountNoteViewModel::from(note)),
+        Err(err) => Err(state.record_error("add_account_note", err)),
+    }
+}
+
+// Command to get the codes an account has had, most recent change first
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_code_history(
+    account_id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<CodeChangeViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = CodeHistoryRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_code_history", err)),
+    };
+
+    match repo.find_by_account(account_id).await {
+        Ok(changes) => Ok(changes.into_iter().map(CodeChangeViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_code_history", Error::Database(err))),
+    }
+}
+
+// Command to get the notes on an account, newest first
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_notes(
+    account_id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountNoteViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = NoteRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_account_notes", err)),
+    };
+
+    match repo.list_notes(account_id).await {
+        Ok(notes) => Ok(notes.into_iter().map(AccountNoteViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_account_notes", Error::Database(err))),
+    }
+}
+
+// Command to get child accounts
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_child_accounts(
+    parent_id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account id
+    let account_id = match parent_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_child_accounts", err)),
+    };
+
+    match repo.find_children(account_id).await {
+        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
+        Err(err) => Err(state.record_error("get_child_accounts", Error::Database(err))),
+    }
+}
+
+// Command to export the trial balance as a self-contained HTML table, from
+// current balances or, with `as_of`, from the lines posted up to that date
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn export_trial_balance_html(
+    state: tauri::State<'_, AppState>,
+    as_of: Option<String>,
+) -> std::result::Result<String, ErrorResponse> {
+    let as_of = match parse_optional_date("As of date", as_of) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("export_trial_balance_html", err)),
+    };
+
+    match build_trial_balance(&state.db_pool, as_of).await {
+        Ok(trial_balance) => Ok(trial_balance.to_html()),
+        Err(err) => Err(state.record_error("export_trial_balance_html", err)),
+    }
+}
+
+// Command to build the balance sheet from current account balances
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_balance_sheet(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BalanceSheet, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(BalanceSheet::from_accounts(&accounts)),
+        Err(err) => Err(state.record_error("get_balance_sheet", Error::Database(err))),
+    }
+}
+
+// Command to build the income statement from posted activity in a date range,
+// by default the current fiscal year to date
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_income_statement(
+    period_start: Option<String>,
+    period_end: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<IncomeStatement, ErrorResponse> {
+    let today = Utc::now().date_naive();
+    let fiscal_year = FiscalYear::containing(today, state.config.app.fiscal_year_start_month);
+
+    // Parse the date range; an inverted range matches no postings and yields zeros
+    let period_start = match period_start.as_deref() {
+        None | Some("") => fiscal_year.start_date,
+        Some(value) => match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(_) => {
+                return Err(state.record_error(
+                    "get_income_statement",
+                    validation_error("Invalid start date, expected YYYY-MM-DD"),
+                ))
+            }
+        },
+    };
+    let period_end = match period_end.as_deref() {
+        None | Some("") => today,
+        Some(value) => match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            Ok(date) => date,
+            Err(_) => {
+                return Err(state.record_error(
+                    "get_income_statement",
+                    validation_error("Invalid end date, expected YYYY-MM-DD"),
+                ))
+            }
+        },
+    };
+
+    match build_income_statement(&state.db_pool, period_start, period_end).await {
+        Ok(statement) => Ok(statement),
+        Err(err) => Err(state.record_error("get_income_statement", err)),
+    }
+}
+
+// Command to set the budget of a revenue or expense account for a fiscal period
+#[tauri::command]
+#[tracing::instrument(skip(state, token), err)]
+pub async fn set_account_budget(
+    account_id: String,
+    period_id: String,
+    amount: String,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BudgetViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let account_repo = AccountRepository::new(db_pool);
+    let period_repo = PeriodRepository::new(db_pool);
+    let budget_repo = BudgetRepository::new(db_pool);
+
+    // Only accountants and admins may set budgets
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("set_account_budget", err)),
+    };
+
+    // Parse the account ids
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("set_account_budget", err)),
+    };
+    let period_id = match period_id.parse::<Uuid>() {
+        Ok(id) => id,
+        Err(_) => {
+            return Err(
+                state.record_error("set_account_budget", validation_error("Invalid period id"))
+            )
+        }
+    };
+
+    // Parse the amount
+    let amount = match money::parse("Budget amount", &amount) {
+        Ok(amount) if amount >= Decimal::ZERO => amount,
+        Ok(_) => {
+            return Err(state.record_error(
+                "set_account_budget",
+                validation_error("Budget amount cannot be negative"),
+            ))
+        }
+        Err(err) => return Err(state.record_error("set_account_budget", err)),
+    };
+
+    // The account must exist and be budgetable
+    match account_repo.find_by_id(account_id).await {
+        Ok(Some(account)) if account.account_type.is_budgetable() => {}
+        Ok(Some(account)) => {
+            return Err(state.record_error(
+                "set_account_budget",
+                Error::Validation(format!(
+                    "Account {} cannot be budgeted; only revenue and expense accounts can",
+                    account.code
+                )),
+            ))
+        }
+        Ok(None) => return Err(state.record_error("set_account_budget", not_found("Account"))),
+        Err(err) => return Err(state.record_error("set_account_budget", Error::Database(err))),
+    }
+
+    // The period must exist
+    match period_repo.find_by_id(period_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Err(state.record_error("set_account_budget", not_found("Fiscal period")))
+        }
+        Err(err) => return Err(state.record_error("set_account_budget", Error::Database(err))),
+    }
+
+    match budget_repo
+        .upsert(account_id, period_id, amount, Some(claims.sub))
+        .await
+    {
+        Ok(budget) => Ok(BudgetViewModel::from(budget)),
+        Err(err) => Err(state.record_error("set_account_budget", Error::Database(err))),
+    }
+}
+
+// Command to compare the budgets of a fiscal period against posted activity
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_budget_variance(
+    period_id: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BudgetVarianceReport, ErrorResponse> {
+    let period_repo = PeriodRepository::new(&state.db_pool);
+
+    // Parse the UUID
+    let period_id = match period_id.parse::<Uuid>() {
+        Ok(id) => id,
+        Err(_) => {
+            return Err(
+                state.record_error("get_budget_variance", validation_error("Invalid period id"))
+            )
+        }
+    };
+
+    let period = match period_repo.find_by_id(period_id).await {
+        Ok(Some(period)) => period,
+        Ok(None) => {
+            return Err(state.record_error("get_budget_variance", not_found("Fiscal period")))
+        }
+        Err(err) => return Err(state.record_error("get_budget_variance", Error::Database(err))),
+    };
+
+    match build_budget_variance(&state.db_pool, &period).await {
+        Ok(report) => Ok(report),
+        Err(err) => Err(state.record_error("get_budget_variance", err)),
+    }
+}
+
+// Command to score how complete the chart of accounts is
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_chart_completeness(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<ChartCompleteness, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(completeness::evaluate(&accounts)),
+        Err(err) => Err(state.record_error("get_chart_completeness", Error::Database(err))),
+    }
+}
+
+// Command to compute working-capital metrics, from current balances or, with
+// `as_of`, from the lines posted up to that date. Accounts are not tagged, so
+// the inventory left out of the quick ratio is the current assets with the
+// "Inventory" subcategory.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_working_capital_metrics(
+    state: tauri::State<'_, AppState>,
+    as_of: Option<String>,
+) -> std::result::Result<WorkingCapitalMetrics, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    let as_of = match parse_optional_date("As of date", as_of) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("get_working_capital_metrics", err)),
+    };
+
+    let current_assets = match repo
+        .sum_balance_by_category(AccountCategory::CurrentAsset, None, as_of)
+        .await
+    {
+        Ok(total) => total,
+        Err(err) => {
+            return Err(state.record_error("get_working_capital_metrics", Error::Database(err)))
+        }
+    };
+
+    // Inventory is excluded from the quick ratio
+    let inventory = match repo
+        .sum_balance_by_category(AccountCategory::CurrentAsset, Some("inventory"), as_of)
+        .await
+    {
+        Ok(total) => total,
+        Err(err) => {
+            return Err(state.record_error("get_working_capital_metrics", Error::Database(err)))
+        }
+    };
+
+    let current_liabilities = match repo
+        .sum_balance_by_category(AccountCategory::CurrentLiability, None, as_of)
+        .await
+    {
+        Ok(total) => total,
+        Err(err) => {
+            return Err(state.record_error("get_working_capital_metrics", Error::Database(err)))
+        }
+    };
+
+    Ok(WorkingCapitalMetrics::from_totals(
+        current_assets,
+        inventory,
+        current_liabilities,
+    ))
+}
+
+// Command to get the application settings without secrets
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_app_config(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AppConfigView, ErrorResponse> {
+    Ok(AppConfigView::from(&state.config))
+}
+
+// Command to change the log level and data directory in the config file.
+// The running application keeps its settings until it is restarted.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn update_app_config(
+    log_level: String,
+    data_dir: String,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AppConfigView, ErrorResponse> {
+    // Only admins may change settings
+    if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Admin) {
+        return Err(state.record_error("update_app_config", err));
+    }
+
+    // Parse the log level
+    let log_level = match log_level.parse::<LogLevel>() {
+        Ok(level) => level,
+        Err(_) => {
+            return Err(state.record_error(
+                "update_app_config",
+                Error::Validation(format!("Invalid log level: {}", log_level)),
+            ))
+        }
+    };
+
+    let data_dir = match sanitize_text("Data directory", &data_dir) {
+        Ok(dir) if !dir.is_empty() => dir,
+        Ok(_) => {
+            return Err(state.record_error(
+                "update_app_config",
+                validation_error("Data directory is required"),
+            ))
+        }
+        Err(err) => return Err(state.record_error("update_app_config", err)),
+    };
+
+    // Edit the file contents so environment overrides are never written back
+    let mut file_config = match config::load_file_config() {
+        Ok(config) => config,
+        Err(err) => return Err(state.record_error("update_app_config", err)),
+    };
+    file_config.app.log_level = log_level;
+    file_config.app.data_dir = data_dir;
+
+    if let Err(err) = config::save_config(&file_config) {
+        return Err(state.record_error("update_app_config", err));
+    }
+
+    Ok(AppConfigView::from(&file_config))
+}
+
+// Command to report database connectivity and the application version
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_system_status(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<SystemStatus, ErrorResponse> {
+    let database_connected = sqlx::query("SELECT 1")
+        .execute(&state.db_pool)
+        .await
+        .is_ok();
+
+    // Fall back to the configured fiscal year when no period covers today
+    let today = Utc::now().date_naive();
+    let fiscal_year = match PeriodRepository::new(&state.db_pool).find_current().await {
+        Ok(Some(period)) => period.name,
+        _ => FiscalYear::containing(today, state.config.app.fiscal_year_start_month).name,
+    };
+
+    let last_backup = match BackupRepository::new(&state.db_pool).find_latest().await {
+        Ok(backup) => backup.map(|backup| backup.created_at.to_rfc3339()),
+        Err(err) => {
+            tracing::warn!(error = %err, "Failed to look up the last backup");
+            None
+        }
+    };
+
+    Ok(SystemStatus {
+        database_connected,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        last_backup,
+        fiscal_year,
+    })
+}
+
+// Command to write a backup of the accounts table to a timestamped file in the
+// data directory. Only one backup runs at a time.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn create_backup(
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BackupViewModel, ErrorResponse> {
+    let repo = BackupRepository::new(&state.db_pool);
+
+    // Only admins may take backups
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Admin) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("create_backup", err)),
+    };
+
+    let _guard = match state.backup_lock.try_lock() {
+        Ok(guard) => guard,
+        Err(_) => {
+            return Err(state.record_error(
+                "create_backup",
+                Error::Conflict("A backup is already in progress".to_string()),
+            ))
+        }
+    };
+
+    let data_dir = match state.config.app.ensure_data_dir() {
+        Ok(dir) => dir,
+        Err(err) => return Err(state.record_error("create_backup", err)),
+    };
+
+    let schema_version = match repo.schema_version().await {
+        Ok(version) => version,
+        Err(err) => return Err(state.record_error("create_backup", Error::Database(err))),
+    };
+    let accounts = match repo.dump_accounts().await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("create_backup", Error::Database(err))),
+    };
+    let account_count = i32::try_from(accounts.len()).unwrap_or(i32::MAX);
+
+    let backup_file = BackupFile::new(schema_version, accounts);
+    let (path, size) = match backup::write(&data_dir, &backup_file).await {
+        Ok(written) => written,
+        Err(err) => return Err(state.record_error("create_backup", err)),
+    };
+
+    let file_path = path.display().to_string();
+    let size_bytes = i64::try_from(size).unwrap_or(i64::MAX);
+    match repo
+        .record(&file_path, size_bytes, account_count, Some(claims.sub))
+        .await
+    {
+        Ok(backup) => {
+            tracing::info!(path = %file_path, size_bytes, "Backup written");
+            Ok(BackupViewModel::from(backup))
+        }
+        Err(err) => Err(state.record_error("create_backup", Error::Database(err))),
+    }
+}
+
+// Command to report database connectivity, latency, pool usage and the
+// latest applied migration
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_db_health(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<DbHealth, ErrorResponse> {
+    let health = database::check_health(&state.db_pool).await;
+    if let Some(error) = &health.error {
+        tracing::warn!(error = %error, "Database health check failed");
+    }
+
+    Ok(health)
+}
+
+// Command to replace the accounts with those in a backup file. Destructive, so
+// it only runs with `confirm` set, and only for a backup taken at the current
+// schema version.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn restore_backup(
+    path: String,
+    confirm: bool,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BackupRestoreResult, ErrorResponse> {
+    let repo = BackupRepository::new(&state.db_pool);
+
+    // Only admins may restore backups
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Admin) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("restore_backup", err)),
+    };
+
+    if !confirm {
+        return Err(state.record_error(
+            "restore_backup",
+            validation_error("Restoring a backup replaces every account; confirm to continue"),
+        ));
+    }
+
+    let _guard = match state.backup_lock.try_lock() {
+        Ok(guard) => guard,
+        Err(_) => {
+            return Err(state.record_error(
+                "restore_backup",
+                Error::Conflict("A backup or restore is already in progress".to_string()),
+            ))
+        }
+    };
+
+    let backup_file = match backup::read(Path::new(&path)).await {
+        Ok(backup_file) => backup_file,
+        Err(err) => return Err(state.record_error("restore_backup", err)),
+    };
+
+    // Rows from another schema version may not fit the current tables
+    let schema_version = match repo.schema_version().await {
+        Ok(version) => version,
+        Err(err) => return Err(state.record_error("restore_backup", Error::Database(err))),
+    };
+    if backup_file.schema_version != schema_version {
+        let describe = |version: Option<i64>| version.map_or("none".to_string(), |v| v.to_string());
+        return Err(state.record_error(
+            "restore_backup",
+            Error::Migration(format!(
+                "Backup was taken at schema version {} but the database is at {}",
+                describe(backup_file.schema_version),
+                describe(schema_version)
+            )),
+        ));
+    }
+
+    let (before, after) = match repo.restore_accounts(&backup_file.accounts).await {
+        Ok(counts) => counts,
+        Err(err) => return Err(state.record_error("restore_backup", err)),
+    };
+
+    tracing::info!(
+        path = %path,
+        accounts_before = before,
+        accounts_after = after,
+        "Accounts restored from backup"
+    );
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::BackupRestored,
+            entity_id: None,
+            description: format!(
+                "Restored accounts from {}: {} accounts before, {} after",
+                path, before, after
+            ),
+            user_id: Some(claims.sub),
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    Ok(BackupRestoreResult {
+        file_path: path,
+        backup_created_at: backup_file.created_at.to_rfc3339(),
+        accounts_before: before,
+        accounts_after: after,
+    })
 }
 
-impl From<Account> for AccountViewModel {
-    fn from(account: Account) -> Self {
-        Self {
-            id: account.id.to_string(),
-            code: account.code,
-            name: account.name,
-            description: account.description,
-            account_type: account.account_type.to_string(),
-            category: account.category.to_string(),
-            subcategory: account.subcategory,
-            is_active: account.is_active,
-            parent_id: account.parent_id.map(|id| id.to_string()),
-            balance: account.balance.to_string(),
-            created_at: account.created_at.to_rfc3339(),
-            updated_at: account.updated_at.to_rfc3339(),
+// Command for a cheap liveness probe. An unreachable database is reported in
+// the response rather than as an error, so a running app with the database
+// down can be told apart from one that does not answer at all.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn ping(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<PingResponse, ErrorResponse> {
+    let db_reachable = match sqlx::query("SELECT 1").execute(&state.db_pool).await {
+        Ok(_) => true,
+        Err(err) => {
+            tracing::warn!(error = %err, "Ping could not reach the database");
+            false
         }
+    };
+
+    Ok(PingResponse {
+        status: "ok".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        db_reachable,
+    })
+}
+
+// Command to get the latest activity log entries, newest first
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_recent_activities(
+    limit: Option<u32>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<ActivityViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = ActivityRepository::new(db_pool);
+
+    let limit = limit
+        .unwrap_or(DEFAULT_ACTIVITY_LIMIT)
+        .clamp(1, MAX_ACTIVITY_LIMIT);
+
+    match repo.find_recent(limit as i64).await {
+        Ok(activities) => Ok(activities
+            .into_iter()
+            .map(ActivityViewModel::from)
+            .collect()),
+        Err(err) => Err(state.record_error("get_recent_activities", Error::Database(err))),
     }
 }
 
-// Command to get all accounts
+// Command to browse the activity log one page at a time, newest first. Dates
+// are YYYY-MM-DD and both ends of the range are inclusive; empty filters are
+// ignored.
 #[tauri::command]
-pub async fn get_accounts(
+#[tracing::instrument(skip(state), err)]
+pub async fn get_activity_log(
+    entity_type: Option<String>,
+    user_id: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    page: u32,
+    page_size: u32,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<ActivityPage, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = ActivityRepository::new(db_pool);
+
+    if page == 0 {
+        return Err(state.record_error(
+            "get_activity_log",
+            validation_error("Page numbers start at 1"),
+        ));
+    }
+
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        return Err(state.record_error(
+            "get_activity_log",
+            Error::Validation(format!("Page size must be between 1 and {}", MAX_PAGE_SIZE)),
+        ));
+    }
+
+    // Parse the user UUID
+    let user_id = match user_id.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(user_id) => match user_id.parse::<Uuid>() {
+            Ok(id) => Some(id),
+            Err(_) => {
+                return Err(
+                    state.record_error("get_activity_log", validation_error("Invalid user id"))
+                )
+            }
+        },
+    };
+
+    // Parse the date range
+    let start_date = match parse_optional_date("Start date", start_date) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("get_activity_log", err)),
+    };
+    let end_date = match parse_optional_date("End date", end_date) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("get_activity_log", err)),
+    };
+    if let (Some(start), Some(end)) = (start_date, end_date) {
+        if start > end {
+            return Err(state.record_error(
+                "get_activity_log",
+                validation_error("Start date must not be after end date"),
+            ));
+        }
+    }
+
+    // The end date covers its whole day, so stop at the following midnight
+    let filter = ActivityFilter {
+        entity_type: entity_type
+            .map(|entity_type| entity_type.trim().to_uppercase())
+            .filter(|entity_type| !entity_type.is_empty()),
+        user_id,
+        from: start_date.map(|date| date.and_time(NaiveTime::MIN).and_utc()),
+        to: end_date
+            .and_then(|date| date.succ_opt())
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc()),
+    };
+
+    let total_count = match repo.count_filtered(&filter).await {
+        Ok(count) => count,
+        Err(err) => return Err(state.record_error("get_activity_log", Error::Database(err))),
+    };
+
+    let offset = (page as i64 - 1) * page_size as i64;
+    match repo.find_filtered(&filter, page_size as i64, offset).await {
+        Ok(activities) => Ok(ActivityPage {
+            activities: activities
+                .into_iter()
+                .map(ActivityViewModel::from)
+                .collect(),
+            page,
+            page_size,
+            total_count,
+        }),
+        Err(err) => Err(state.record_error("get_activity_log", Error::Database(err))),
+    }
+}
+
+// Command to get the headline figures for the home page
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_financial_metrics(
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<Vec<AccountViewModel>, String> {
+) -> std::result::Result<Vec<FinancialMetric>, ErrorResponse> {
     let db_pool = &state.db_pool;
     let repo = AccountRepository::new(db_pool);
 
-    match repo.find_all().await {
+    // Profit covers the current fiscal period, or the fiscal year to date when
+    // no period covers today
+    let today = Utc::now().date_naive();
+    let fiscal_year = FiscalYear::containing(today, state.config.app.fiscal_year_start_month);
+    let (period_name, period_start, period_end) = match PeriodRepository::new(db_pool)
+        .find_current()
+        .await
+    {
+        Ok(Some(period)) => (period.name, period.start_date, period.end_date),
+        Ok(None) => (fiscal_year.name, fiscal_year.start_date, today),
+        Err(err) => return Err(state.record_error("get_financial_metrics", Error::Database(err))),
+    };
+
+    let statement = match build_income_statement(db_pool, period_start, period_end).await {
+        Ok(statement) => statement,
+        Err(err) => return Err(state.record_error("get_financial_metrics", err)),
+    };
+
+    let cash = match repo
+        .sum_balance_by_category(AccountCategory::CurrentAsset, Some("cash"), None)
+        .await
+    {
+        Ok(total) => total,
+        Err(err) => return Err(state.record_error("get_financial_metrics", Error::Database(err))),
+    };
+
+    // There is no prior period to compare against yet
+    let metric = |name: &str, value: Decimal, period: &str| FinancialMetric {
+        name: name.to_string(),
+        value: money::format(value),
+        change: None,
+        period: period.to_string(),
+    };
+
+    let totals = statement.totals;
+    Ok(vec![
+        metric("Revenue", totals.revenue, &period_name),
+        metric("Expenses", totals.expenses, &period_name),
+        metric("Net Profit", totals.net_profit, &period_name),
+        metric("Cash Balance", cash, "Current"),
+    ])
+}
+
+// Command to get the headline figures for the current month, quarter or year
+// to date, each with its percentage change against the same stretch of the
+// prior period
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_metrics_with_change(
+    period: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<FinancialMetric>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+
+    let period = match ComparisonPeriod::parse(&period) {
+        Some(period) => period,
+        None => {
+            return Err(state.record_error(
+                "get_metrics_with_change",
+                Error::Validation(format!(
+                    "Unknown period '{}'; use month, quarter or year",
+                    period
+                )),
+            ))
+        }
+    };
+
+    let today = Utc::now().date_naive();
+    let (current, prior) = period.windows(today, state.config.app.fiscal_year_start_month);
+
+    let current_statement = match build_income_statement(db_pool, current.start, current.end).await
+    {
+        Ok(statement) => statement,
+        Err(err) => return Err(state.record_error("get_metrics_with_change", err)),
+    };
+    let prior_statement = match build_income_statement(db_pool, prior.start, prior.end).await {
+        Ok(statement) => statement,
+        Err(err) => return Err(state.record_error("get_metrics_with_change", err)),
+    };
+
+    let cash = match AccountRepository::new(db_pool)
+        .sum_balance_by_category(AccountCategory::CurrentAsset, Some("cash"), None)
+        .await
+    {
+        Ok(total) => total,
+        Err(err) => return Err(state.record_error("get_metrics_with_change", Error::Database(err))),
+    };
+
+    // Cash at the end of the prior window is today's balance with everything
+    // posted since then taken back out
+    let (debit, credit) = match JournalRepository::new(db_pool)
+        .sum_category_activity_after(AccountCategory::CurrentAsset, Some("cash"), prior.end)
+        .await
+    {
+        Ok(totals) => totals,
+        Err(err) => return Err(state.record_error("get_metrics_with_change", Error::Database(err))),
+    };
+    let prior_cash = cash - (debit - credit);
+
+    let metric = |name: &str, value: Decimal, prior_value: Decimal| FinancialMetric {
+        name: name.to_string(),
+        value: money::format(value),
+        change: metrics::percent_change(value, prior_value),
+        period: period.label().to_string(),
+    };
+
+    let totals = current_statement.totals;
+    let prior_totals = prior_statement.totals;
+    Ok(vec![
+        metric("Revenue", totals.revenue, prior_totals.revenue),
+        metric("Expenses", totals.expenses, prior_totals.expenses),
+        metric("Net Profit", totals.net_profit, prior_totals.net_profit),
+        metric("Cash Balance", cash, prior_cash),
+    ])
+}
+
+// Command to read the cached balance totals shown on the dashboard. They are
+// refreshed in the background and by refresh_financial_summary.
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_financial_summary(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<FinancialSummaryViewModel, ErrorResponse> {
+    let repo = SummaryRepository::new(&state.db_pool);
+
+    match repo.find().await {
+        Ok(summary) => Ok(FinancialSummaryViewModel::from(summary)),
+        Err(err) => Err(state.record_error("get_financial_summary", Error::Database(err))),
+    }
+}
+
+// Command to recompute the cached dashboard totals now
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn refresh_financial_summary(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<FinancialSummaryViewModel, ErrorResponse> {
+    let repo = SummaryRepository::new(&state.db_pool);
+
+    match repo.refresh().await {
+        Ok(summary) => Ok(FinancialSummaryViewModel::from(summary)),
+        Err(err) => Err(state.record_error("refresh_financial_summary", Error::Database(err))),
+    }
+}
+
+// Command to find inactive accounts that still carry a balance (pre-close check)
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn find_inactive_nonzero_accounts(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match repo.find_inactive_nonzero().await {
         Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+        Err(err) => Err(state.record_error("find_inactive_nonzero_accounts", Error::Database(err))),
     }
 }
 
-// Command to get an account by ID
+/// Loads up to `chunk_size` accounts following the position `cursor` encodes,
+/// or the first chunk when there is none
+async fn load_stream_chunk(
+    store: &dyn AccountStore,
+    cursor: Option<&str>,
+    chunk_size: usize,
+) -> Result<AccountStreamChunk> {
+    let after = cursor.map(str::parse::<AccountCursor>).transpose()?;
+
+    // Fetch one extra row to know whether another chunk follows
+    let mut accounts = store
+        .find_after_cursor(after.as_ref(), chunk_size as i64 + 1)
+        .await?;
+
+    let next_cursor = if accounts.len() > chunk_size {
+        accounts.truncate(chunk_size);
+        accounts
+            .last()
+            .map(|account| AccountCursor::after(account).to_string())
+    } else {
+        None
+    };
+
+    Ok(AccountStreamChunk {
+        accounts: accounts.into_iter().map(AccountViewModel::from).collect(),
+        next_cursor,
+    })
+}
+
+// Command to stream accounts in chunks using an opaque cursor
 #[tauri::command]
-pub async fn get_account(
-    id: String,
+#[tracing::instrument(skip(state), err)]
+pub async fn get_accounts_stream(
+    cursor: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountStreamChunk, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    match load_stream_chunk(&repo, cursor.as_deref(), ACCOUNT_STREAM_CHUNK_SIZE).await {
+        Ok(chunk) => Ok(chunk),
+        Err(err) => Err(state.record_error("get_accounts_stream", err)),
+    }
+}
+
+/// The accounts of `account_type` that may become the parent of `account_id`:
+/// every one except the account itself and its descendants, which would make
+/// the hierarchy circular
+fn eligible_parents(
+    accounts: Vec<Account>,
+    account_type: AccountType,
+    account_id: Option<Uuid>,
+) -> Vec<Account> {
+    let mut excluded: HashSet<Uuid> = account_id.into_iter().collect();
+    let mut frontier: Vec<Uuid> = excluded.iter().copied().collect();
+    while let Some(parent_id) = frontier.pop() {
+        for account in &accounts {
+            if account.parent_id == Some(parent_id) && excluded.insert(account.id) {
+                frontier.push(account.id);
+            }
+        }
+    }
+
+    accounts
+        .into_iter()
+        .filter(|account| account.account_type == account_type && !excluded.contains(&account.id))
+        .collect()
+}
+
+// Command to get accounts that may be selected as parent for an account
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_eligible_parents(
+    account_id: Option<String>,
+    account_type: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<AccountViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = AccountRepository::new(db_pool);
+
+    // Parse the account type
+    let account_type = match AccountType::from_str(&account_type) {
+        Some(t) => t,
+        None => {
+            return Err(state.record_error(
+                "get_eligible_parents",
+                validation_error("Invalid account type"),
+            ))
+        }
+    };
+
+    // Parse the account ID if present (new accounts have none)
+    let account_id = match account_id {
+        Some(id_str) if !id_str.is_empty() => match id_str.parse::<AccountId>() {
+            Ok(id) => Some(Uuid::from(id)),
+            Err(err) => return Err(state.record_error("get_eligible_parents", err)),
+        },
+        _ => None,
+    };
+
+    match repo.find_all().await {
+        Ok(accounts) => Ok(eligible_parents(accounts, account_type, account_id)
+            .into_iter()
+            .map(AccountViewModel::from)
+            .collect()),
+        Err(err) => Err(state.record_error("get_eligible_parents", Error::Database(err))),
+    }
+}
+
+// Command to get a checksum over all account balances for sync verification
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_balance_checksum(
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<Option<AccountViewModel>, String> {
+) -> std::result::Result<String, ErrorResponse> {
     let db_pool = &state.db_pool;
     let repo = AccountRepository::new(db_pool);
 
+    match repo.find_all().await {
+        Ok(accounts) => Ok(checksum::balance_checksum(&accounts)),
+        Err(err) => Err(state.record_error("get_balance_checksum", Error::Database(err))),
+    }
+}
+
+/// Builds the journal entry moving `amount` from one account to another. Both
+/// accounts must exist and be active.
+async fn prepare_transfer(
+    store: &dyn AccountStore,
+    from_id: Uuid,
+    to_id: Uuid,
+    amount: Decimal,
+    entry_date: NaiveDate,
+    description: String,
+) -> Result<NewJournalEntry> {
+    for account_id in [from_id, to_id] {
+        match store.find_by_id(account_id).await? {
+            Some(account) if account.is_active => {}
+            Some(account) => {
+                return Err(Error::Validation(format!(
+                    "Account {} is inactive",
+                    account.code
+                )))
+            }
+            None => return Err(not_found("Account")),
+        }
+    }
+
+    // Money flows out of the source account (credit) into the destination (debit)
+    Ok(NewJournalEntry {
+        entry_date,
+        description,
+        reference: None,
+        exchange_rate: None,
+        lines: vec![
+            NewJournalLine {
+                account_id: to_id,
+                description: None,
+                debit: amount,
+                credit: Decimal::ZERO,
+            },
+            NewJournalLine {
+                account_id: from_id,
+                description: None,
+                debit: Decimal::ZERO,
+                credit: amount,
+            },
+        ],
+    })
+}
+
+// Command to record a transfer between two accounts as a two-line journal entry
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn create_transfer(
+    transfer: TransferDto,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<JournalEntryViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let account_repo = AccountRepository::new(db_pool);
+    let journal_repo = JournalRepository::new(db_pool);
+
+    // Only accountants and admins may post entries
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+
+    // Parse the account ids
+    let from_id = match transfer.from_account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+    let to_id = match transfer.to_account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+    if from_id == to_id {
+        return Err(state.record_error(
+            "create_transfer",
+            validation_error("Cannot transfer between the same account"),
+        ));
+    }
+
+    // Parse the amount
+    let amount = match money::parse("Transfer amount", &transfer.amount) {
+        Ok(amount) if amount > Decimal::ZERO => amount,
+        Ok(_) => {
+            return Err(state.record_error(
+                "create_transfer",
+                validation_error("Transfer amount must be positive"),
+            ))
+        }
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+
+    // Parse the entry date
+    let entry_date = match NaiveDate::parse_from_str(&transfer.date, "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => {
+            return Err(state.record_error(
+                "create_transfer",
+                validation_error("Invalid date, expected YYYY-MM-DD"),
+            ))
+        }
+    };
+
+    let new_entry = match prepare_transfer(
+        &account_repo,
+        from_id,
+        to_id,
+        amount,
+        entry_date,
+        transfer.description,
+    )
+    .await
+    {
+        Ok(new_entry) => new_entry,
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+
+    let entry = match journal_repo.create(new_entry, transfer.post).await {
+        Ok(entry) => entry,
+        Err(err) => return Err(state.record_error("create_transfer", err)),
+    };
+
+    record_activity(
+        &state,
+        NewActivity {
+            action: ActivityAction::TransferCreated,
+            entity_id: Some(entry.id),
+            description: format!(
+                "{} transfer of {}: {}",
+                if entry.is_posted { "Posted" } else { "Drafted" },
+                money::format(amount),
+                entry.description
+            ),
+            user_id: Some(claims.sub),
+            details: Vec::new(),
+        },
+    )
+    .await;
+
+    match journal_repo.find_lines(entry.id).await {
+        Ok(lines) => Ok(JournalEntryViewModel::new(entry, lines)),
+        Err(err) => Err(state.record_error("create_transfer", Error::Database(err))),
+    }
+}
+
+// Command to correct a posted journal entry by posting a reversing entry with
+// debits and credits swapped. The original entry is kept for the audit trail.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn reverse_journal_entry(
+    entry_id: String,
+    reversal_date: String,
+    description: Option<String>,
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<JournalEntryViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let journal_repo = JournalRepository::new(db_pool);
+
+    // Only accountants and admins may post corrections
+    if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        return Err(state.record_error("reverse_journal_entry", err));
+    }
+
     // Parse the UUID
-    let account_id = match Uuid::parse_str(&id) {
+    let entry_id = match entry_id.parse::<Uuid>() {
         Ok(id) => id,
-        Err(e) => return Err(format!("Invalid UUID format: {}", e)),
+        Err(_) => {
+            return Err(state.record_error(
+                "reverse_journal_entry",
+                validation_error("Invalid journal entry id"),
+            ))
+        }
+    };
+
+    // Parse the reversal date
+    let reversal_date = match NaiveDate::parse_from_str(&reversal_date, "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => {
+            return Err(state.record_error(
+                "reverse_journal_entry",
+                validation_error("Invalid date, expected YYYY-MM-DD"),
+            ))
+        }
+    };
+
+    // A blank description falls back to "Reversal of <reference>"
+    let description = match sanitize_optional_text("Description", description) {
+        Ok(description) => description,
+        Err(err) => return Err(state.record_error("reverse_journal_entry", err)),
+    };
+
+    let entry = match journal_repo
+        .reverse(entry_id, reversal_date, description)
+        .await
+    {
+        Ok(entry) => entry,
+        Err(err) => return Err(state.record_error("reverse_journal_entry", err)),
+    };
+
+    match journal_repo.find_lines(entry.id).await {
+        Ok(lines) => Ok(JournalEntryViewModel::new(entry, lines)),
+        Err(err) => Err(state.record_error("reverse_journal_entry", Error::Database(err))),
+    }
+}
+
+// Command to get the posted journal lines of an account with a running balance
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_account_ledger(
+    account_id: String,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<AccountLedger, ErrorResponse> {
+    let db_pool = &state.db_pool;
+
+    // Parse the account id
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_account_ledger", err)),
+    };
+
+    // Parse the date range
+    let start_date = match parse_optional_date("Start date", start_date) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("get_account_ledger", err)),
+    };
+    let end_date = match parse_optional_date("End date", end_date) {
+        Ok(date) => date,
+        Err(err) => return Err(state.record_error("get_account_ledger", err)),
+    };
+    if let (Some(start), Some(end)) = (start_date, end_date) {
+        if start > end {
+            return Err(state.record_error(
+                "get_account_ledger",
+                validation_error("Start date must not be after end date"),
+            ));
+        }
+    }
+
+    let (account, opening_balance, lines) =
+        match load_ledger(db_pool, account_id, start_date, end_date).await {
+            Ok(ledger) => ledger,
+            Err(err) => return Err(state.record_error("get_account_ledger", err)),
+        };
+    let (rows, closing_balance) = ledger_rows(account.account_type, opening_balance, lines);
+
+    Ok(AccountLedger {
+        account_id: account.id.to_string(),
+        code: account.code,
+        name: account.name,
+        account_type: account.account_type.to_string(),
+        opening_balance: money::format(opening_balance),
+        closing_balance: money::format(closing_balance),
+        rows,
+    })
+}
+
+// Command to get an account statement for printing: the ledger rows of a
+// date range with the account details, the balances carried in and out, and
+// the range's debit and credit totals
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_ledger_export(
+    account_id: String,
+    start_date: String,
+    end_date: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<LedgerExport, ErrorResponse> {
+    let db_pool = &state.db_pool;
+
+    // Parse the account id
+    let account_id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_ledger_export", err)),
+    };
+
+    // Parse the date range; a statement always covers an explicit range
+    let period_start = match NaiveDate::parse_from_str(start_date.trim(), "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => {
+            return Err(state.record_error(
+                "get_ledger_export",
+                validation_error("Invalid start date, expected YYYY-MM-DD"),
+            ))
+        }
+    };
+    let period_end = match NaiveDate::parse_from_str(end_date.trim(), "%Y-%m-%d") {
+        Ok(date) => date,
+        Err(_) => {
+            return Err(state.record_error(
+                "get_ledger_export",
+                validation_error("Invalid end date, expected YYYY-MM-DD"),
+            ))
+        }
+    };
+    if period_start > period_end {
+        return Err(state.record_error(
+            "get_ledger_export",
+            validation_error("Start date must not be after end date"),
+        ));
+    }
+
+    let (account, opening_balance, lines) =
+        match load_ledger(db_pool, account_id, Some(period_start), Some(period_end)).await {
+            Ok(ledger) => ledger,
+            Err(err) => return Err(state.record_error("get_ledger_export", err)),
+        };
+
+    let total_debits: Decimal = lines.iter().map(|line| line.debit).sum();
+    let total_credits: Decimal = lines.iter().map(|line| line.credit).sum();
+    let (rows, closing_balance) = ledger_rows(account.account_type, opening_balance, lines);
+
+    Ok(LedgerExport {
+        account_id: account.id.to_string(),
+        code: account.code,
+        name: account.name,
+        account_type: account.account_type.to_string(),
+        currency: account.currency,
+        period_start: period_start.to_string(),
+        period_end: period_end.to_string(),
+        opening_balance: money::format(opening_balance),
+        closing_balance: money::format(closing_balance),
+        total_debits: money::format(total_debits),
+        total_credits: money::format(total_credits),
+        rows,
+        generated_at: Utc::now().to_rfc3339(),
+    })
+}
+
+// Command to get an account's balance at the end of a date from the latest
+// snapshot recorded on or before it
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_balance_as_of(
+    account_id: String,
+    date: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BalanceAsOfViewModel, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = SnapshotRepository::new(db_pool);
+
+    // Parse the account id
+    let id = match account_id.parse::<AccountId>() {
+        Ok(id) => Uuid::from(id),
+        Err(err) => return Err(state.record_error("get_balance_as_of", err)),
+    };
+
+    // Parse the date
+    let as_of = match parse_optional_date("Date", Some(date)) {
+        Ok(Some(date)) => date,
+        Ok(None) => {
+            return Err(
+                state.record_error("get_balance_as_of", validation_error("Date is required"))
+            )
+        }
+        Err(err) => return Err(state.record_error("get_balance_as_of", err)),
     };
 
-    match repo.find_by_id(account_id).await {
-        Ok(Some(account)) => Ok(Some(AccountViewModel::from(account))),
-        Ok(None) => Ok(None),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    match repo.find_as_of(id, as_of).await {
+        Ok(snapshot) => Ok(BalanceAsOfViewModel {
+            account_id,
+            as_of: as_of.to_string(),
+            balance: snapshot.as_ref().map(|s| money::format(s.balance)),
+            recorded_at: snapshot.map(|s| s.recorded_at.to_rfc3339()),
+        }),
+        Err(err) => Err(state.record_error("get_balance_as_of", Error::Database(err))),
+    }
+}
+
+// Command to get the fiscal period containing today's date
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_current_period(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Option<FiscalPeriodViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = PeriodRepository::new(db_pool);
+
+    match repo.find_current().await {
+        Ok(period) => Ok(period.map(FiscalPeriodViewModel::from)),
+        Err(err) => Err(state.record_error("get_current_period", Error::Database(err))),
+    }
+}
+
+// Command to list all fiscal periods in date order
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn list_periods(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<FiscalPeriodViewModel>, ErrorResponse> {
+    let db_pool = &state.db_pool;
+    let repo = PeriodRepository::new(db_pool);
+
+    match repo.find_all().await {
+        Ok(periods) => Ok(periods
+            .into_iter()
+            .map(FiscalPeriodViewModel::from)
+            .collect()),
+        Err(err) => Err(state.record_error("list_periods", Error::Database(err))),
     }
 }
 
-// Command to create a new account
+// Command to create a fiscal period
 #[tauri::command]
-pub async fn create_account(
-    new_account: NewAccountDto,
+#[tracing::instrument(skip(token, state), err)]
+pub async fn create_period(
+    name: String,
+    start_date: String,
+    end_date: String,
+    token: String,
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<AccountViewModel, String> {
+) -> std::result::Result<FiscalPeriodViewModel, ErrorResponse> {
     let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+    let repo = PeriodRepository::new(db_pool);
 
-    // Parse the account type
-    let account_type = match AccountType::from_str(&new_account.account_type) {
-        Some(t) => t,
-        None => return Err(ErrorResponse::from(validation_error("Invalid account type")).into()),
-    };
+    // Only accountants and admins may manage periods
+    if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        return Err(state.record_error("create_period", err));
+    }
 
-    // Parse the category
-    let category = match AccountCategory::from_str(&new_account.category) {
-        Some(c) => c,
-        None => {
-            return Err(ErrorResponse::from(validation_error("Invalid account category")).into())
+    let name = match sanitize_text("Period name", &name) {
+        Ok(name) if !name.is_empty() => name,
+        Ok(_) => {
+            return Err(
+                state.record_error("create_period", validation_error("Period name is required"))
+            )
         }
+        Err(err) => return Err(state.record_error("create_period", err)),
     };
 
-    // Parse the parent ID if present
-    let parent_id = if let Some(parent_id_str) = new_account.parent_id {
-        if parent_id_str.is_empty() {
-            None
-        } else {
-            match Uuid::parse_str(&parent_id_str) {
-                Ok(id) => Some(id),
-                Err(e) => return Err(format!("Invalid parent UUID format: {}", e)),
-            }
+    // Parse the date range
+    let (start_date, end_date) = match (
+        parse_optional_date("Start date", Some(start_date)),
+        parse_optional_date("End date", Some(end_date)),
+    ) {
+        (Ok(Some(start)), Ok(Some(end))) => (start, end),
+        (Err(err), _) | (_, Err(err)) => return Err(state.record_error("create_period", err)),
+        _ => {
+            return Err(state.record_error(
+                "create_period",
+                validation_error("Start and end dates are required"),
+            ))
         }
-    } else {
-        None
     };
 
-    // Create the new account domain model
-    let domain_new_account = NewAccount {
-        code: new_account.code,
-        name: new_account.name,
-        description: new_account.description,
-        account_type,
-        category,
-        subcategory: new_account.subcategory,
-        parent_id,
+    let new_period = NewFiscalPeriod {
+        name,
+        start_date,
+        end_date,
     };
 
-    // Create the account
-    match repo.create(domain_new_account).await {
-        Ok(account) => Ok(AccountViewModel::from(account)),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    match repo.create(new_period).await {
+        Ok(period) => Ok(FiscalPeriodViewModel::from(period)),
+        Err(err) => Err(state.record_error("create_period", err)),
     }
 }
 
-// Command to update an account
+/// Finds the inactive accounts that still carry a balance. In strict mode any
+/// such account blocks the close.
+async fn check_period_close(store: &dyn AccountStore, strict: bool) -> Result<Vec<Account>> {
+    let inactive_nonzero = store.find_inactive_nonzero().await?;
+
+    if strict && !inactive_nonzero.is_empty() {
+        let codes: Vec<&str> = inactive_nonzero
+            .iter()
+            .map(|account| account.code.as_str())
+            .collect();
+        return Err(Error::Conflict(format!(
+            "Inactive accounts still carry a balance: {}",
+            codes.join(", ")
+        )));
+    }
+
+    Ok(inactive_nonzero)
+}
+
+// Command to close a fiscal period. Inactive accounts that still carry a balance
+// are reported; in strict mode they block the close.
 #[tauri::command]
-pub async fn update_account(
+#[tracing::instrument(skip(token, state), err)]
+pub async fn close_period(
     id: String,
-    update_data: NewAccountDto,
+    strict: bool,
+    token: String,
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<AccountViewModel, String> {
+) -> std::result::Result<ClosePeriodResult, ErrorResponse> {
     let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+    let account_repo = AccountRepository::new(db_pool);
+    let period_repo = PeriodRepository::new(db_pool);
+
+    // Only accountants and admins may manage periods
+    if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Accountant) {
+        return Err(state.record_error("close_period", err));
+    }
 
     // Parse the UUID
-    let account_id = match Uuid::parse_str(&id) {
+    let period_id = match id.parse::<Uuid>() {
         Ok(id) => id,
-        Err(e) => return Err(format!("Invalid UUID format: {}", e)),
-    };
-
-    // Retrieve the existing account
-    let mut account = match repo.find_by_id(account_id).await {
-        Ok(Some(account)) => account,
-        Ok(None) => return Err(ErrorResponse::from(not_found("Account")).into()),
-        Err(err) => return Err(ErrorResponse::from(Error::Database(err)).into()),
-    };
-
-    // Parse the account type
-    let account_type = match AccountType::from_str(&update_data.account_type) {
-        Some(t) => t,
-        None => return Err(ErrorResponse::from(validation_error("Invalid account type")).into()),
-    };
-
-    // Parse the category
-    let category = match AccountCategory::from_str(&update_data.category) {
-        Some(c) => c,
-        None => {
-            return Err(ErrorResponse::from(validation_error("Invalid account category")).into())
+        Err(_) => {
+            return Err(state.record_error("close_period", validation_error("Invalid period id")))
         }
     };
 
-    // Parse the parent ID if present
-    let parent_id = if let Some(parent_id_str) = update_data.parent_id {
-        if parent_id_str.is_empty() {
-            None
-        } else {
-            match Uuid::parse_str(&parent_id_str) {
-                Ok(id) => Some(id),
-                Err(e) => return Err(format!("Invalid parent UUID format: {}", e)),
-            }
-        }
-    } else {
-        None
+    // Pre-close check
+    let inactive_nonzero = match check_period_close(&account_repo, strict).await {
+        Ok(accounts) => accounts,
+        Err(err) => return Err(state.record_error("close_period", err)),
     };
 
-    // Update the account fields
-    account.code = update_data.code;
-    account.name = update_data.name;
-    account.description = update_data.description;
-    account.account_type = account_type;
-    account.category = category;
-    account.subcategory = update_data.subcategory;
-    account.parent_id = parent_id;
-    account.updated_at = Utc::now();
-
-    // Save the updated account
-    match repo.update(&account).await {
-        Ok(()) => Ok(AccountViewModel::from(account)),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    match period_repo.close_period(period_id).await {
+        Ok(period) => Ok(ClosePeriodResult {
+            period: FiscalPeriodViewModel::from(period),
+            inactive_nonzero_accounts: inactive_nonzero
+                .into_iter()
+                .map(AccountViewModel::from)
+                .collect(),
+        }),
+        Err(err) => Err(state.record_error("close_period", err)),
     }
 }
 
-// Command to delete an account
+// Command to reopen a closed fiscal period
 #[tauri::command]
-pub async fn delete_account(
+#[tracing::instrument(skip(token, state), err)]
+pub async fn reopen_period(
     id: String,
+    token: String,
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<(), String> {
+) -> std::result::Result<FiscalPeriodViewModel, ErrorResponse> {
     let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+    let repo = PeriodRepository::new(db_pool);
+
+    // Reopening changes closed books, so only admins may do it
+    if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Admin) {
+        return Err(state.record_error("reopen_period", err));
+    }
 
     // Parse the UUID
-    let account_id = match Uuid::parse_str(&id) {
+    let period_id = match id.parse::<Uuid>() {
         Ok(id) => id,
-        Err(e) => return Err(format!("Invalid UUID format: {}", e)),
+        Err(_) => {
+            return Err(state.record_error("reopen_period", validation_error("Invalid period id")))
+        }
     };
 
-    match repo.delete(account_id).await {
-        Ok(()) => Ok(()),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    match repo.reopen_period(period_id).await {
+        Ok(period) => Ok(FiscalPeriodViewModel::from(period)),
+        Err(err) => Err(state.record_error("reopen_period", err)),
     }
 }
 
-// Command to toggle account active status
+// Command to check that debit-normal and credit-normal balances agree
 #[tauri::command]
-pub async fn toggle_account_status(
-    id: String,
+#[tracing::instrument(skip(state), err)]
+pub async fn verify_books_balanced(
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<AccountViewModel, String> {
-    let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+) -> std::result::Result<BooksBalanceCheck, ErrorResponse> {
+    match check_books_balanced(&state.db_pool).await {
+        Ok(check) => Ok(check),
+        Err(err) => Err(state.record_error("verify_books_balanced", err)),
+    }
+}
 
-    // Parse the UUID
-    let account_id = match Uuid::parse_str(&id) {
-        Ok(id) => id,
-        Err(e) => return Err(format!("Invalid UUID format: {}", e)),
+// Command to recalculate every account balance from its posted journal lines
+// and correct those that have drifted. A recovery tool, so only admins may run it.
+#[tauri::command]
+#[tracing::instrument(skip(token, state), err)]
+pub async fn recompute_balances(
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<BalanceRecomputeReport, ErrorResponse> {
+    let repo = AccountRepository::new(&state.db_pool);
+
+    // Only admins may rewrite balances
+    let claims = match auth::require_role(&state.config.security, &token, UserRole::Admin) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("recompute_balances", err)),
     };
 
-    // Retrieve the existing account
-    let mut account = match repo.find_by_id(account_id).await {
-        Ok(Some(account)) => account,
-        Ok(None) => return Err(ErrorResponse::from(not_found("Account")).into()),
-        Err(err) => return Err(ErrorResponse::from(Error::Database(err)).into()),
+    let corrections = match repo.recompute_balances().await {
+        Ok(corrections) => corrections,
+        Err(err) => return Err(state.record_error("recompute_balances", err)),
     };
 
-    // Toggle the active status
-    account.is_active = !account.is_active;
-    account.updated_at = Utc::now();
+    if !corrections.is_empty() {
+        tracing::warn!(
+            corrected = corrections.len(),
+            "Corrected account balances that drifted from their journal lines"
+        );
+        record_activity(
+            &state,
+            NewActivity {
+                action: ActivityAction::BalancesRecomputed,
+                entity_id: None,
+                description: format!(
+                    "Recomputed balances from journal lines, correcting {} accounts",
+                    corrections.len()
+                ),
+                user_id: Some(claims.sub),
+                details: corrections
+                    .iter()
+                    .filter_map(|correction| {
+                        FieldChange::between(
+                            &format!("{} balance", correction.code),
+                            Some(money::format(correction.old_balance)),
+                            Some(money::format(correction.new_balance)),
+                        )
+                    })
+                    .collect(),
+            },
+        )
+        .await;
+    }
 
-    // Save the updated account
-    match repo.update(&account).await {
-        Ok(()) => Ok(AccountViewModel::from(account)),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    Ok(BalanceRecomputeReport {
+        corrections: corrections
+            .into_iter()
+            .map(BalanceCorrectionViewModel::from)
+            .collect(),
+        recomputed_at: Utc::now().to_rfc3339(),
+    })
+}
+
+// Command to list the most recent command errors for bug reports
+#[tauri::command]
+#[tracing::instrument(skip(state), err)]
+pub async fn get_recent_errors(
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<Vec<RecordedError>, ErrorResponse> {
+    Ok(state.recent_errors.snapshot())
+}
+
+// Command to list account types and the categories valid for each
+#[tauri::command]
+#[tracing::instrument(err)]
+pub async fn get_account_metadata() -> std::result::Result<Vec<AccountTypeMetadata>, ErrorResponse>
+{
+    Ok(AccountType::all()
+        .into_iter()
+        .map(|account_type| AccountTypeMetadata {
+            account_type: account_type.to_string(),
+            categories: AccountCategory::for_account_type(account_type)
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        })
+        .collect())
+}
+
+// Command to list the currencies accounts may be kept in
+#[tauri::command]
+#[tracing::instrument(err)]
+pub async fn get_supported_currencies() -> std::result::Result<Vec<CurrencyViewModel>, ErrorResponse>
+{
+    Ok(currency::SUPPORTED_CURRENCIES
+        .iter()
+        .map(CurrencyViewModel::from)
+        .collect())
+}
+
+// Command to list the subcategories allowed for a category
+#[tauri::command]
+#[tracing::instrument(err)]
+pub async fn get_subcategories_for_category(
+    category: String,
+) -> std::result::Result<Vec<String>, ErrorResponse> {
+    match AccountCategory::from_str(&category) {
+        Some(category) => Ok(category
+            .subcategories()
+            .iter()
+            .map(ToString::to_string)
+            .collect()),
+        None => Err(ErrorResponse::from(validation_error(
+            "Invalid account category",
+        ))),
     }
 }
 
-// Command to get root accounts (top-level)
+// Command to check a session token and return who it belongs to
 #[tauri::command]
-pub async fn get_root_accounts(
+#[tracing::instrument(skip(token, state), err)]
+pub async fn verify_session(
+    token: String,
+    state: tauri::State<'_, AppState>,
+) -> std::result::Result<SessionInfo, ErrorResponse> {
+    let claims = match auth::verify_token(&state.config.security, &token) {
+        Ok(claims) => claims,
+        Err(err) => return Err(state.record_error("verify_session", err)),
+    };
+
+    let expires_at = DateTime::<Utc>::from_timestamp(claims.exp, 0)
+        .map(|at| at.to_rfc3339())
+        .unwrap_or_default();
+
+    Ok(SessionInfo {
+        user_id: claims.sub.to_string(),
+        expires_at,
+    })
+}
+
+// Command to create a user account
+#[tauri::command]
+#[tracing::instrument(skip(password, token, state), err)]
+pub async fn create_user(
+    username: String,
+    email: String,
+    password: String,
+    role: String,
+    token: Option<String>,
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<Vec<AccountViewModel>, String> {
+) -> std::result::Result<UserViewModel, ErrorResponse> {
     let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+    let repo = UserRepository::new(db_pool);
 
-    match repo.find_roots().await {
-        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    // Anyone may create the first user; after that only admins can add users
+    let user_count = match repo.count().await {
+        Ok(count) => count,
+        Err(err) => return Err(state.record_error("create_user", Error::Database(err))),
+    };
+    if user_count > 0 {
+        let token = token.unwrap_or_default();
+        if let Err(err) = auth::require_role(&state.config.security, &token, UserRole::Admin) {
+            return Err(state.record_error("create_user", err));
+        }
+    }
+
+    // Clean up the identifying fields
+    let username = match sanitize_text("Username", &username) {
+        Ok(username) if !username.is_empty() => username,
+        Ok(_) => {
+            return Err(state.record_error("create_user", validation_error("Username is required")))
+        }
+        Err(err) => return Err(state.record_error("create_user", err)),
+    };
+    let email = match sanitize_text("Email", &email) {
+        Ok(email) if email.contains('@') => email,
+        Ok(_) => {
+            return Err(state.record_error("create_user", validation_error("Invalid email address")))
+        }
+        Err(err) => return Err(state.record_error("create_user", err)),
+    };
+
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(state.record_error(
+            "create_user",
+            Error::Validation(format!(
+                "Password must be at least {} characters",
+                MIN_PASSWORD_LENGTH
+            )),
+        ));
+    }
+
+    // Parse the role
+    let role = match UserRole::parse(&role) {
+        Some(role) => role,
+        None => return Err(state.record_error("create_user", validation_error("Invalid role"))),
+    };
+
+    let new_user = NewUser {
+        username,
+        email,
+        password,
+        role,
+    };
+
+    match repo.create(new_user, state.config.security.hash_cost).await {
+        Ok(user) => Ok(UserViewModel::from(user)),
+        Err(err) => Err(state.record_error("create_user", err)),
     }
 }
 
-// Command to get child accounts
+// Command to check credentials and issue a session token
 #[tauri::command]
-pub async fn get_child_accounts(
-    parent_id: String,
+#[tracing::instrument(skip(password, state), err)]
+pub async fn login(
+    username: String,
+    password: String,
     state: tauri::State<'_, AppState>,
-) -> std::result::Result<Vec<AccountViewModel>, String> {
+) -> std::result::Result<LoginResponse, ErrorResponse> {
     let db_pool = &state.db_pool;
-    let repo = AccountRepository::new(db_pool);
+    let repo = UserRepository::new(db_pool);
 
-    // Parse the UUID
-    let account_id = match Uuid::parse_str(&parent_id) {
-        Ok(id) => id,
-        Err(e) => return Err(format!("Invalid UUID format: {}", e)),
+    let user = match repo.find_by_username(username.trim()).await {
+        Ok(user) => user,
+        Err(err) => return Err(state.record_error("login", Error::Database(err))),
     };
 
-    match repo.find_children(account_id).await {
-        Ok(accounts) => Ok(accounts.into_iter().map(AccountViewModel::from).collect()),
-        Err(err) => Err(ErrorResponse::from(Error::Database(err)).into()),
+    // Same error for unknown users, wrong passwords and disabled users
+    let user = match user {
+        Some(user) if user.is_active && user.verify_password(&password) => user,
+        _ => {
+            return Err(state.record_error(
+                "login",
+                Error::Auth("Invalid username or password".to_string()),
+            ))
+        }
+    };
+
+    match auth::generate_token(&state.config.security, user.id, user.role) {
+        Ok(token) => Ok(LoginResponse {
+            token,
+            user: UserViewModel::from(user),
+        }),
+        Err(err) => Err(state.record_error("login", err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FieldError;
+    use crate::repositories::memory::InMemoryAccountStore;
+
+    fn dto(code: &str, account_type: &str, category: &str) -> NewAccountDto {
+        NewAccountDto {
+            code: code.to_string(),
+            name: format!("Account {}", code),
+            description: None,
+            account_type: account_type.to_string(),
+            category: category.to_string(),
+            subcategory: None,
+            parent_id: None,
+            currency: None,
+            opening_balance: None,
+            opening_balance_date: None,
+        }
+    }
+
+    /// The changes in `dto`, made against the version of `account` given
+    fn update_of(account: &Account, dto: NewAccountDto) -> AccountUpdateDto {
+        AccountUpdateDto {
+            account: dto,
+            version: account.version,
+        }
+    }
+
+    async fn create(store: &InMemoryAccountStore, dto: NewAccountDto) -> Account {
+        let new_account = parse_new_account(dto, &ChartConfig::default()).unwrap();
+        store.create(new_account, None).await.unwrap()
+    }
+
+    /// The fields named by a field validation error, in the order reported
+    fn invalid_fields(err: Error) -> Vec<String> {
+        match err {
+            Error::InvalidFields(errors) => errors.into_iter().map(|error| error.field).collect(),
+            err => panic!("expected invalid fields, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn new_account_rejects_category_of_another_type() {
+        let err = parse_new_account(
+            dto("1000", "ASSET", "OPERATING_EXPENSE"),
+            &ChartConfig::default(),
+        )
+        .unwrap_err();
+        match err {
+            Error::InvalidFields(errors) => assert_eq!(
+                errors,
+                [FieldError {
+                    field: "category".to_string(),
+                    message: "Category OPERATING_EXPENSE is not valid for account type ASSET"
+                        .to_string(),
+                }]
+            ),
+            err => panic!("expected invalid fields, got {:?}", err),
+        }
+    }
+
+    #[test]
+    fn new_account_rejects_malformed_parent_id() {
+        let mut dto = dto("1000", "ASSET", "CURRENT_ASSET");
+        dto.parent_id = Some("not-a-uuid".to_string());
+        let err = parse_new_account(dto, &ChartConfig::default()).unwrap_err();
+        assert_eq!(invalid_fields(err), ["parent_id"]);
+    }
+
+    #[test]
+    fn new_account_reports_every_invalid_field() {
+        let mut dto = dto(" ", "BOGUS", "NOPE");
+        dto.name = String::new();
+        let err = parse_new_account(dto, &ChartConfig::default()).unwrap_err();
+        assert_eq!(
+            invalid_fields(err),
+            ["code", "name", "account_type", "category"]
+        );
+    }
+
+    #[test]
+    fn new_account_reports_mismatch_alongside_blank_fields() {
+        let mut dto = dto("", "ASSET", "OPERATING_EXPENSE");
+        dto.name = "   ".to_string();
+        let err = parse_new_account(dto, &ChartConfig::default()).unwrap_err();
+        assert_eq!(invalid_fields(err), ["code", "name", "category"]);
+    }
+
+    #[test]
+    fn new_account_is_checked_by_the_shared_validator() {
+        let mut valid = dto("1000", "ASSET", "CURRENT_ASSET");
+        valid.subcategory = Some("cash".to_string());
+        let new_account = parse_new_account(valid, &ChartConfig::default()).unwrap();
+        assert_eq!(new_account.subcategory.as_deref(), Some("Cash"));
+
+        let mut invalid = dto("10/00", "ASSET", "CURRENT_ASSET");
+        invalid.subcategory = Some("Rent".to_string());
+        invalid.currency = Some("XXX".to_string());
+        let err = parse_new_account(invalid, &ChartConfig::default()).unwrap_err();
+        assert_eq!(invalid_fields(err), ["code", "subcategory", "currency"]);
+    }
+
+    #[test]
+    fn bad_opening_balance_is_listed_with_other_field_errors() {
+        let mut invalid = dto("", "ASSET", "CURRENT_ASSET");
+        invalid.opening_balance = Some("12,34.5".to_string());
+        invalid.opening_balance_date = Some("yesterday".to_string());
+        let err =
+            parse_new_account_with_opening_balance(invalid, &ChartConfig::default()).unwrap_err();
+        assert_eq!(
+            invalid_fields(err),
+            ["code", "opening_balance", "opening_balance_date"]
+        );
+
+        let mut valid = dto("1000", "ASSET", "CURRENT_ASSET");
+        valid.opening_balance = Some("250.00".to_string());
+        valid.opening_balance_date = Some("2025-01-01".to_string());
+        let (_, opening_balance) =
+            parse_new_account_with_opening_balance(valid, &ChartConfig::default()).unwrap();
+        assert_eq!(
+            opening_balance,
+            Some((
+                Decimal::new(25000, 2),
+                NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn field_errors_are_sent_as_json_details() {
+        let err = parse_new_account(dto("", "BOGUS", "CURRENT_ASSET"), &ChartConfig::default())
+            .unwrap_err();
+        let response = ErrorResponse::from(err);
+        assert_eq!(response.code, "VALIDATION_ERROR");
+        let details: Vec<FieldError> =
+            serde_json::from_str(response.details.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            details,
+            [
+                FieldError {
+                    field: "code".to_string(),
+                    message: "Account code is required".to_string(),
+                },
+                FieldError {
+                    field: "account_type".to_string(),
+                    message: "Invalid account type".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn find_account_rejects_malformed_id() {
+        let store = InMemoryAccountStore::new();
+        let err = find_account(&store, "42").await.unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn find_account_reports_missing_account() {
+        let store = InMemoryAccountStore::new();
+        let err = find_account(&store, &Uuid::new_v4().to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::NotFound(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn update_rejects_category_of_another_type() {
+        let store = InMemoryAccountStore::new();
+        let account = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+
+        let err = prepare_account_update(
+            &store,
+            &account,
+            update_of(&account, dto("1000", "ASSET", "OPERATING_EXPENSE")),
+            &ChartConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn update_rejects_circular_hierarchy() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let mut child_dto = dto("1100", "ASSET", "CURRENT_ASSET");
+        child_dto.parent_id = Some(parent.id.to_string());
+        let child = create(&store, child_dto).await;
+
+        let mut update = dto("1000", "ASSET", "CURRENT_ASSET");
+        update.parent_id = Some(child.id.to_string());
+        let err = prepare_account_update(
+            &store,
+            &parent,
+            update_of(&parent, update),
+            &ChartConfig::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(msg) if msg == "Circular account hierarchy"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn eligible_parents_exclude_the_account_and_its_descendants() {
+        let store = InMemoryAccountStore::new();
+        let root = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let mut child_dto = dto("1100", "ASSET", "CURRENT_ASSET");
+        child_dto.parent_id = Some(root.id.to_string());
+        let child = create(&store, child_dto).await;
+        let mut grandchild_dto = dto("1110", "ASSET", "CURRENT_ASSET");
+        grandchild_dto.parent_id = Some(child.id.to_string());
+        create(&store, grandchild_dto).await;
+        create(&store, dto("1500", "ASSET", "FIXED_ASSET")).await;
+        create(&store, dto("2000", "LIABILITY", "CURRENT_LIABILITY")).await;
+
+        let accounts = store.find_all().await.unwrap();
+        let codes = |account_id: Option<Uuid>| -> Vec<String> {
+            eligible_parents(accounts.clone(), AccountType::Asset, account_id)
+                .into_iter()
+                .map(|account| account.code)
+                .collect()
+        };
+
+        assert_eq!(codes(Some(child.id)), vec!["1000", "1500"]);
+        assert_eq!(codes(Some(root.id)), vec!["1500"]);
+        assert_eq!(codes(None), vec!["1000", "1100", "1110", "1500"]);
+    }
+
+    #[tokio::test]
+    async fn strict_close_is_blocked_by_inactive_account_with_balance() {
+        let store = InMemoryAccountStore::new();
+        create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let mut dormant = create(&store, dto("1900", "ASSET", "OTHER_ASSET")).await;
+        dormant.is_active = false;
+        dormant.balance = Decimal::new(2500, 2);
+        store.update(&mut dormant, None).await.unwrap();
+
+        let err = check_period_close(&store, true).await.unwrap_err();
+        assert!(
+            matches!(&err, Error::Conflict(msg) if msg == "Inactive accounts still carry a balance: 1900"),
+            "{:?}",
+            err
+        );
+
+        let reported = check_period_close(&store, false).await.unwrap();
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].code, "1900");
+    }
+
+    #[tokio::test]
+    async fn strict_close_allows_inactive_account_without_balance() {
+        let store = InMemoryAccountStore::new();
+        let mut dormant = create(&store, dto("1900", "ASSET", "OTHER_ASSET")).await;
+        dormant.is_active = false;
+        store.update(&mut dormant, None).await.unwrap();
+
+        assert!(check_period_close(&store, true).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn working_capital_ratios_divide_by_current_liabilities() {
+        let metrics = WorkingCapitalMetrics::from_totals(
+            Decimal::new(5000, 0),
+            Decimal::new(1000, 0),
+            Decimal::new(2000, 0),
+        );
+
+        assert_eq!(metrics.net_working_capital, "3000.00");
+        assert_eq!(metrics.current_ratio.as_deref(), Some("2.50"));
+        assert_eq!(metrics.quick_ratio.as_deref(), Some("2.00"));
+    }
+
+    #[test]
+    fn working_capital_ratios_are_null_without_liabilities() {
+        let metrics = WorkingCapitalMetrics::from_totals(
+            Decimal::new(5000, 0),
+            Decimal::new(1000, 0),
+            Decimal::ZERO,
+        );
+
+        assert_eq!(metrics.net_working_capital, "5000.00");
+        assert_eq!(metrics.current_ratio, None);
+        assert_eq!(metrics.quick_ratio, None);
+    }
+
+    #[tokio::test]
+    async fn update_of_stale_version_conflicts() {
+        let store = InMemoryAccountStore::new();
+        let account = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+
+        let update = update_of(&account, dto("1000", "ASSET", "FIXED_ASSET"));
+        let mut first =
+            prepare_account_update(&store, &account, update.clone(), &ChartConfig::default())
+                .await
+                .unwrap();
+        store.update(&mut first, None).await.unwrap();
+
+        let mut second = prepare_account_update(&store, &account, update, &ChartConfig::default())
+            .await
+            .unwrap();
+        let err = store.update(&mut second, None).await.unwrap_err();
+        assert!(matches!(err, Error::Conflict(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn update_without_a_version_is_rejected() {
+        let mut json = serde_json::to_value(dto("1000", "ASSET", "CURRENT_ASSET")).unwrap();
+        assert!(serde_json::from_value::<AccountUpdateDto>(json.clone()).is_err());
+
+        json["version"] = serde_json::json!(3);
+        let update = serde_json::from_value::<AccountUpdateDto>(json).unwrap();
+        assert_eq!(update.version, 3);
+        assert_eq!(update.account.code, "1000");
+    }
+
+    fn chart(enforce_parent_type: bool) -> ChartConfig {
+        ChartConfig {
+            enforce_parent_type,
+            ..ChartConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn child_of_same_type_is_accepted() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+
+        check_parent(&store, &chart(true), AccountType::Asset, parent.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn child_of_another_type_is_rejected() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+
+        let err = check_parent(&store, &chart(true), AccountType::Expense, parent.id)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(msg) if msg == "Child account type must match parent"),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn child_of_another_type_is_accepted_when_not_enforced() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+
+        check_parent(&store, &chart(false), AccountType::Expense, parent.id)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_rejects_parent_of_another_type() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let account = create(&store, dto("5000", "EXPENSE", "OPERATING_EXPENSE")).await;
+
+        let mut update = dto("5000", "EXPENSE", "OPERATING_EXPENSE");
+        update.parent_id = Some(parent.id.to_string());
+        let update = update_of(&account, update);
+        let err = prepare_account_update(&store, &account, update.clone(), &chart(true))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+
+        prepare_account_update(&store, &account, update, &chart(false))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_rejects_type_its_children_do_not_share() {
+        let store = InMemoryAccountStore::new();
+        let parent = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let mut child_dto = dto("1100", "ASSET", "CURRENT_ASSET");
+        child_dto.parent_id = Some(parent.id.to_string());
+        create(&store, child_dto).await;
+
+        let err = prepare_account_update(
+            &store,
+            &parent,
+            update_of(&parent, dto("1000", "LIABILITY", "CURRENT_LIABILITY")),
+            &chart(true),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn transfer_moves_balance_between_accounts() {
+        let store = InMemoryAccountStore::new();
+        let mut checking = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        checking.balance = Decimal::from(500);
+        store.update(&mut checking, None).await.unwrap();
+        let savings = create(&store, dto("1010", "ASSET", "CURRENT_ASSET")).await;
+
+        let entry = prepare_transfer(
+            &store,
+            checking.id,
+            savings.id,
+            Decimal::from(100),
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            "Move to savings".to_string(),
+        )
+        .await
+        .unwrap();
+        assert!(entry.is_balanced());
+
+        let mut balances: HashMap<Uuid, Decimal> = [
+            (checking.id, checking.balance),
+            (savings.id, savings.balance),
+        ]
+        .into_iter()
+        .collect();
+        for line in &entry.lines {
+            *balances.get_mut(&line.account_id).unwrap() +=
+                AccountType::Asset.balance_delta(line.debit, line.credit);
+        }
+        assert_eq!(balances[&checking.id], Decimal::from(400));
+        assert_eq!(balances[&savings.id], Decimal::from(100));
+    }
+
+    #[tokio::test]
+    async fn transfer_from_inactive_account_is_rejected() {
+        let store = InMemoryAccountStore::new();
+        let mut closed = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        closed.is_active = false;
+        store.update(&mut closed, None).await.unwrap();
+        let savings = create(&store, dto("1010", "ASSET", "CURRENT_ASSET")).await;
+
+        let err = prepare_transfer(
+            &store,
+            closed.id,
+            savings.id,
+            Decimal::from(100),
+            NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            "Move to savings".to_string(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+    }
+
+    #[tokio::test]
+    async fn reclassify_moves_a_compatible_batch() {
+        let store = InMemoryAccountStore::new();
+        let mut cash_dto = dto("1000", "ASSET", "CURRENT_ASSET");
+        cash_dto.subcategory = Some("Cash".to_string());
+        let cash = create(&store, cash_dto).await;
+        let deposit = create(&store, dto("1900", "ASSET", "CURRENT_ASSET")).await;
+
+        let reclassified =
+            reclassify(&[cash.clone(), deposit], AccountCategory::OtherAsset).unwrap();
+        assert!(reclassified
+            .iter()
+            .all(|account| account.category == AccountCategory::OtherAsset));
+        assert_eq!(reclassified[0].subcategory, None);
+        let changed: Vec<String> = reclassified[0]
+            .changes_from(&cash)
+            .into_iter()
+            .map(|change| change.field)
+            .collect();
+        assert_eq!(changed, ["category", "subcategory"]);
+    }
+
+    #[tokio::test]
+    async fn reclassify_rejects_the_whole_batch_for_one_incompatible_account() {
+        let store = InMemoryAccountStore::new();
+        let cash = create(&store, dto("1000", "ASSET", "CURRENT_ASSET")).await;
+        let rent = create(&store, dto("5000", "EXPENSE", "OPERATING_EXPENSE")).await;
+
+        let err = reclassify(&[cash, rent], AccountCategory::OtherAsset).unwrap_err();
+        assert!(
+            matches!(&err, Error::Validation(msg) if msg.contains("5000")),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_cursors_visit_every_account_once() {
+        let store = InMemoryAccountStore::new();
+        for n in 0..7 {
+            create(
+                &store,
+                dto(&format!("{}", 1000 + n), "ASSET", "CURRENT_ASSET"),
+            )
+            .await;
+        }
+
+        let mut seen = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let chunk = load_stream_chunk(&store, cursor.as_deref(), 3)
+                .await
+                .unwrap();
+            assert!(chunk.accounts.len() <= 3);
+            seen.extend(chunk.accounts.into_iter().map(|account| account.code));
+            match chunk.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        let expected: Vec<String> = (0..7).map(|n| format!("{}", 1000 + n)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[tokio::test]
+    async fn stream_rejects_a_tampered_cursor() {
+        let store = InMemoryAccountStore::new();
+        for cursor in ["1000", "zz", "3a"] {
+            let err = load_stream_chunk(&store, Some(cursor), 3)
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(&err, Error::Validation(msg) if msg == "Invalid cursor"),
+                "{:?}",
+                err
+            );
+        }
+    }
+
+    fn iif_row(name: &str, foreign_type: &str, number: &str) -> iif::IifAccount {
+        iif::IifAccount {
+            name: name.to_string(),
+            foreign_type: foreign_type.to_string(),
+            description: None,
+            number: Some(number.to_string()),
+        }
+    }
+
+    #[test]
+    fn iif_import_skips_sub_accounts_of_rows_left_out() {
+        let rows = vec![
+            iif_row("Checking", "BANK", ""),
+            iif_row("Checking:Payroll", "BANK", "1010"),
+            iif_row("Savings", "BANK", "1020"),
+            iif_row("Savings:Reserve", "BANK", "1030"),
+        ];
+
+        let (accounts, _, skipped) =
+            prepare_iif_accounts(rows, &ChartConfig::default(), None).unwrap();
+
+        let codes: Vec<&str> = accounts.iter().map(|a| a.code.as_str()).collect();
+        assert_eq!(codes, ["1020", "1030"]);
+        assert_eq!(accounts[1].parent_id, Some(accounts[0].id));
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(
+            skipped[1],
+            "Checking:Payroll: parent Checking was not imported"
+        );
+    }
+
+    #[test]
+    fn iif_import_skips_numbers_repeated_in_the_file() {
+        let rows = vec![
+            iif_row("Checking", "BANK", "1000"),
+            iif_row("Petty Cash", "BANK", "1000"),
+        ];
+
+        let (accounts, _, skipped) =
+            prepare_iif_accounts(rows, &ChartConfig::default(), None).unwrap();
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].name, "Checking");
+        assert_eq!(
+            skipped,
+            ["Petty Cash: account number 1000 appears earlier in the file"]
+        );
     }
 }
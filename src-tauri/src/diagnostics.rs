@@ -0,0 +1,101 @@
+// src/diagnostics.rs
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use crate::error::ErrorResponse;
+
+/// Number of errors kept for in-app diagnostics
+pub const RECENT_ERROR_CAPACITY: usize = 50;
+
+/// An error returned by a command, captured for bug reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedError {
+    pub command: String,
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Fixed-size ring buffer of the most recent command errors
+#[derive(Debug)]
+pub struct RecentErrors {
+    capacity: usize,
+    entries: Mutex<VecDeque<RecordedError>>,
+}
+
+impl RecentErrors {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Records an error, evicting the oldest entry once the buffer is full
+    pub fn record(&self, command: &str, error: &ErrorResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+
+        entries.push_back(RecordedError {
+            command: command.to_string(),
+            code: error.code.clone(),
+            message: error.message.clone(),
+            details: error.details.clone(),
+            timestamp: Utc::now(),
+        });
+    }
+
+    /// Returns the recorded errors, newest first
+    pub fn snapshot(&self) -> Vec<RecordedError> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter().rev().cloned().collect()
+    }
+}
+
+impl Default for RecentErrors {
+    fn default() -> Self {
+        Self::new(RECENT_ERROR_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{validation_error, Error};
+
+    #[test]
+    fn failing_command_is_recorded() {
+        let recent = RecentErrors::default();
+        let response = ErrorResponse::from(validation_error("Invalid period id"));
+
+        recent.record("close_period", &response);
+
+        let recorded = recent.snapshot();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].command, "close_period");
+        assert_eq!(recorded[0].code, "VALIDATION_ERROR");
+        assert_eq!(recorded[0].details.as_deref(), Some("Invalid period id"));
+    }
+
+    #[test]
+    fn newest_errors_come_first_and_oldest_are_evicted() {
+        let recent = RecentErrors::new(2);
+        for command in ["first", "second", "third"] {
+            recent.record(command, &Error::NotFound(command.to_string()).into());
+        }
+
+        let commands: Vec<String> = recent
+            .snapshot()
+            .into_iter()
+            .map(|error| error.command)
+            .collect();
+        assert_eq!(commands, vec!["third", "second"]);
+    }
+}
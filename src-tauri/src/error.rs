@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
 use thiserror::Error;
 
 /// Comprehensive internal error type
@@ -19,6 +21,9 @@ pub enum Error {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Validation error: {}", join_field_errors(.0))]
+    InvalidFields(Vec<FieldError>),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
@@ -38,6 +43,22 @@ pub enum Error {
     Unknown(String),
 }
 
+/// A submitted field that failed validation, named as in the request
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// The messages of `errors` in one line, e.g. for logs or a single-line report
+pub fn join_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|error| error.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// Result type alias for convenience
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -46,8 +67,22 @@ pub fn map_err<E: std::error::Error>(err: E) -> Error {
     Error::Unknown(err.to_string())
 }
 
+/// Whether error responses carry internal details such as database messages.
+/// Set from the configured log level on startup; debug builds show them until then.
+static DETAILED_ERRORS: AtomicBool = AtomicBool::new(cfg!(debug_assertions));
+
+/// Shows or hides internal details in error responses from now on
+pub fn set_detailed_errors(enabled: bool) {
+    DETAILED_ERRORS.store(enabled, Ordering::Relaxed);
+}
+
+/// `details` when internal details are shown, otherwise `None`
+fn internal_details(details: String) -> Option<String> {
+    DETAILED_ERRORS.load(Ordering::Relaxed).then_some(details)
+}
+
 /// Serializable error response for client consumption
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ErrorResponse {
     pub code: String,
     pub message: String,
@@ -70,11 +105,7 @@ impl From<sqlx::Error> for ErrorResponse {
         Self {
             code: "DATABASE_ERROR".into(),
             message: error_message.to_string(),
-            details: if cfg!(debug_assertions) {
-                Some(err.to_string())
-            } else {
-                None
-            },
+            details: internal_details(err.to_string()),
         }
     }
 }
@@ -86,27 +117,33 @@ impl From<Error> for ErrorResponse {
             Error::Io(e) => Self {
                 code: "IO_ERROR".into(),
                 message: "A file system error occurred".into(),
-                details: if cfg!(debug_assertions) {
-                    Some(e.to_string())
-                } else {
-                    None
-                },
+                details: internal_details(e.to_string()),
             },
             Error::Config(msg) => Self {
                 code: "CONFIG_ERROR".into(),
                 message: "A configuration error occurred".into(),
-                details: Some(msg),
+                details: internal_details(msg),
             },
             Error::Auth(msg) => Self {
                 code: "AUTH_ERROR".into(),
                 message: "An authentication error occurred".into(),
-                details: Some(msg),
+                details: internal_details(msg),
             },
+            // Validation, not-found and conflict messages are written for users
+            // to act on, so they are shown whatever the detail setting
             Error::Validation(msg) => Self {
                 code: "VALIDATION_ERROR".into(),
                 message: "A validation error occurred".into(),
                 details: Some(msg),
             },
+            // Listed as JSON so a form can mark every bad field at once
+            Error::InvalidFields(errors) => Self {
+                code: "VALIDATION_ERROR".into(),
+                message: "A validation error occurred".into(),
+                details: Some(
+                    serde_json::to_string(&errors).unwrap_or_else(|_| join_field_errors(&errors)),
+                ),
+            },
             Error::NotFound(msg) => Self {
                 code: "NOT_FOUND".into(),
                 message: "Resource not found".into(),
@@ -120,30 +157,22 @@ impl From<Error> for ErrorResponse {
             Error::ExternalService(msg) => Self {
                 code: "EXTERNAL_SERVICE_ERROR".into(),
                 message: "An external service error occurred".into(),
-                details: Some(msg),
+                details: internal_details(msg),
             },
             Error::Serialization(e) => Self {
                 code: "SERIALIZATION_ERROR".into(),
                 message: "A data serialization error occurred".into(),
-                details: if cfg!(debug_assertions) {
-                    Some(e.to_string())
-                } else {
-                    None
-                },
+                details: internal_details(e.to_string()),
             },
             Error::Migration(msg) => Self {
                 code: "MIGRATION_ERROR".into(),
                 message: "A database migration error occurred".into(),
-                details: Some(msg),
+                details: internal_details(msg),
             },
             Error::Unknown(msg) => Self {
                 code: "UNKNOWN_ERROR".into(),
                 message: "An unknown error occurred".into(),
-                details: if cfg!(debug_assertions) {
-                    Some(msg)
-                } else {
-                    None
-                },
+                details: internal_details(msg),
             },
         }
     }
@@ -155,14 +184,18 @@ impl From<Error> for String {
     }
 }
 
-/// For compatibility with Tauri commands that return Result<T, String>
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.details {
+            Some(details) => write!(f, "{}: {}", self.message, details),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
 impl From<ErrorResponse> for String {
     fn from(err: ErrorResponse) -> Self {
-        if let Some(details) = err.details {
-            format!("{}: {}", err.message, details)
-        } else {
-            err.message
-        }
+        err.to_string()
     }
 }
 
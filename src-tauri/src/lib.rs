@@ -2,11 +2,15 @@
 pub mod commands;
 pub mod config;
 pub mod database;
+pub mod diagnostics;
 pub mod error;
+pub mod logging;
 pub mod models;
+pub mod money;
 pub mod repositories;
 pub mod services;
 pub mod state;
+pub mod validation;
 
 // Re-export commonly used items
 pub use error::{Error, Result};
@@ -0,0 +1,13 @@
+// src/logging.rs
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::config::LogLevel;
+
+/// Installs the global tracing subscriber at the configured level. Spans are
+/// reported when they close, so every command logs its arguments and timing.
+pub fn init(level: LogLevel) {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::from(level))
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+}
@@ -3,52 +3,140 @@
 
 use dotenv::dotenv;
 use erp_lib::commands;
+use erp_lib::config;
+use erp_lib::database;
+use erp_lib::error;
+use erp_lib::logging;
 use erp_lib::AppState;
-use sqlx::postgres::PgPool;
 
 #[tokio::main]
 async fn main() {
     // Load .env file from the root directory
     let root_dir = std::env::current_dir().expect("Failed to determine the current directory");
     let env_path = root_dir.join(".env");
+    let env_found = env_path.exists();
 
-    if env_path.exists() {
-        println!("Loading environment from: {}", env_path.display());
-        dotenv::from_path(env_path).ok();
+    if env_found {
+        dotenv::from_path(&env_path).ok();
     } else {
-        println!("Warning: .env file not found at {}", env_path.display());
         // Try to load from the current directory as a fallback
         dotenv().ok();
     }
 
-    // Initialize database connection
-    let database_url =
-        std::env::var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
+    // Load application configuration (file plus environment overrides)
+    let config = config::load_config().expect("Failed to load configuration");
 
-    println!("Connecting to database...");
-    let pool = PgPool::connect(&database_url)
-        .await
-        .expect("Failed to connect to Postgres");
+    logging::init(config.app.log_level);
+    error::set_detailed_errors(config.app.log_level.shows_error_details());
 
-    // Ensure database is properly set up
-    sqlx::migrate!("./migrations")
-        .run(&pool)
-        .await
-        .expect("Failed to run database migrations");
+    if env_found {
+        tracing::info!("Loaded environment from: {}", env_path.display());
+    } else {
+        tracing::warn!(".env file not found at {}", env_path.display());
+    }
+
+    // Make sure there is somewhere to write exports and backups
+    match config.app.ensure_data_dir() {
+        Ok(data_dir) => tracing::info!("Data directory: {}", data_dir.display()),
+        Err(err) => {
+            tracing::error!("Failed to create the data directory: {}", err);
+            std::process::exit(1);
+        }
+    }
+
+    // Initialize the connection pool and run migrations
+    tracing::info!("Connecting to database...");
+    let pool = match database::init_db(&config.database).await {
+        Ok(pool) => pool,
+        Err(err) => {
+            tracing::error!("Failed to initialize the database: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    tracing::info!("Database connection established");
 
-    println!("Database connection established");
+    database::spawn_summary_refresh(pool.clone(), &config.database);
 
     tauri::Builder::default()
-        .manage(AppState { db_pool: pool })
+        .manage(AppState::new(pool, config))
         .invoke_handler(tauri::generate_handler![
             commands::get_accounts,
+            commands::get_accounts_paged,
+            commands::get_accounts_after,
+            commands::get_accounts_filtered,
+            commands::get_accounts_sorted,
+            commands::get_account_activity_stats,
+            commands::search_accounts,
+            commands::get_accounts_modified_since,
             commands::get_account,
             commands::create_account,
+            commands::create_accounts_bulk,
+            commands::suggest_next_code,
+            commands::seed_default_chart,
             commands::update_account,
+            commands::reparent_account,
             commands::delete_account,
+            commands::get_account_delete_impact,
+            commands::restore_account,
             commands::toggle_account_status,
+            commands::toggle_accounts_status,
+            commands::reclassify_accounts,
+            commands::import_chart_iif,
+            commands::export_accounts_csv,
             commands::get_root_accounts,
             commands::get_child_accounts,
+            commands::get_account_tree,
+            commands::get_account_path,
+            commands::get_subtree_balance,
+            commands::add_account_note,
+            commands::get_account_notes,
+            commands::get_code_history,
+            commands::get_accounts_rolled_up,
+            commands::get_accounts_grouped,
+            commands::export_trial_balance_html,
+            commands::get_balance_sheet,
+            commands::get_income_statement,
+            commands::set_account_budget,
+            commands::get_budget_variance,
+            commands::get_chart_completeness,
+            commands::get_working_capital_metrics,
+            commands::get_system_status,
+            commands::create_backup,
+            commands::restore_backup,
+            commands::get_db_health,
+            commands::ping,
+            commands::get_recent_activities,
+            commands::get_activity_log,
+            commands::get_financial_metrics,
+            commands::get_metrics_with_change,
+            commands::get_financial_summary,
+            commands::refresh_financial_summary,
+            commands::find_inactive_nonzero_accounts,
+            commands::get_accounts_stream,
+            commands::get_eligible_parents,
+            commands::get_balance_checksum,
+            commands::create_transfer,
+            commands::reverse_journal_entry,
+            commands::get_account_ledger,
+            commands::get_ledger_export,
+            commands::get_balance_as_of,
+            commands::get_current_period,
+            commands::list_periods,
+            commands::create_period,
+            commands::close_period,
+            commands::reopen_period,
+            commands::get_recent_errors,
+            commands::verify_books_balanced,
+            commands::recompute_balances,
+            commands::get_app_config,
+            commands::update_app_config,
+            commands::get_account_metadata,
+            commands::get_supported_currencies,
+            commands::get_subcategories_for_category,
+            commands::verify_session,
+            commands::create_user,
+            commands::login,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,15 +1,119 @@
 // src-tauri/models/account.rs
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::postgres::{PgHasArrayType, PgTypeInfo};
 use sqlx::Type;
 use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::error::{Error, Result};
+use crate::models::activity::FieldChange;
+use crate::money;
+use crate::validation::FieldErrors;
+
+/// Identifier of an account, kept apart from other UUIDs such as period or
+/// user ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AccountId(Uuid);
+
+impl From<Uuid> for AccountId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<AccountId> for Uuid {
+    fn from(id: AccountId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = Error;
+
+    /// Parses an account id sent by the frontend
+    fn from_str(s: &str) -> Result<Self> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|_| Error::Validation("Invalid account id".to_string()))
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Position in the account stream: the code and id of the last account sent.
+/// Clients get it as an opaque hex token and hand it back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountCursor {
+    pub code: String,
+    pub id: Uuid,
+}
+
+impl AccountCursor {
+    pub fn after(account: &Account) -> Self {
+        Self {
+            code: account.code.clone(),
+            id: account.id,
+        }
+    }
+}
+
+impl FromStr for AccountCursor {
+    type Err = Error;
+
+    /// Decodes a token produced by `Display`
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Validation("Invalid cursor".to_string());
+
+        let digits = s
+            .chars()
+            .map(|c| c.to_digit(16))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(invalid)?;
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| match pair {
+                [high, low] => Some((high * 16 + low) as u8),
+                _ => None,
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(invalid)?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+
+        // The id goes first since a UUID never contains the separator
+        let (id, code) = text.split_once(':').ok_or_else(invalid)?;
+        let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+        if code.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            code: code.to_string(),
+            id,
+        })
+    }
+}
+
+impl fmt::Display for AccountCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in format!("{}:{}", self.id, self.code).bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 /// AccountType represents the different types of accounts in the chart of accounts
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Type)]
+#[serde(rename_all = "UPPERCASE")]
 #[sqlx(type_name = "VARCHAR", rename_all = "UPPERCASE")]
 pub enum AccountType {
     Asset,
@@ -38,6 +142,17 @@ impl fmt::Display for AccountType {
 }
 
 impl AccountType {
+    /// All account types in chart-of-accounts order
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Asset,
+            Self::Liability,
+            Self::Equity,
+            Self::Revenue,
+            Self::Expense,
+        ]
+    }
+
     /// Convert a string to AccountType
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_uppercase().as_str() {
@@ -59,11 +174,26 @@ impl AccountType {
     pub fn is_credit_normal(&self) -> bool {
         matches!(self, Self::Liability | Self::Equity | Self::Revenue)
     }
+
+    /// Revenue and expense accounts can be given budgets
+    pub fn is_budgetable(&self) -> bool {
+        matches!(self, Self::Revenue | Self::Expense)
+    }
+
+    /// Net effect of a debit/credit pair on a balance of this type
+    pub fn balance_delta(&self, debit: Decimal, credit: Decimal) -> Decimal {
+        if self.is_debit_normal() {
+            debit - credit
+        } else {
+            credit - debit
+        }
+    }
 }
 
 /// AccountCategory provides primary categorization of accounts
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Type)]
-#[sqlx(type_name = "VARCHAR", rename_all = "UPPERCASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountCategory {
     // Asset categories
     CurrentAsset,
@@ -114,6 +244,24 @@ impl fmt::Display for AccountCategory {
 }
 
 impl AccountCategory {
+    /// All categories, grouped by account type in chart-of-accounts order
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::CurrentAsset,
+            Self::FixedAsset,
+            Self::OtherAsset,
+            Self::CurrentLiability,
+            Self::LongTermLiability,
+            Self::OtherLiability,
+            Self::OwnerEquity,
+            Self::RetainedEarnings,
+            Self::OperatingRevenue,
+            Self::NonOperatingRevenue,
+            Self::OperatingExpense,
+            Self::NonOperatingExpense,
+        ]
+    }
+
     /// Convert a string to AccountCategory
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_uppercase().as_str() {
@@ -147,6 +295,81 @@ impl AccountCategory {
             AccountType::Expense => vec![Self::OperatingExpense, Self::NonOperatingExpense],
         }
     }
+
+    /// Check if the category is valid for the given account type
+    pub fn is_valid_for(&self, account_type: AccountType) -> bool {
+        Self::for_account_type(account_type).contains(self)
+    }
+
+    /// Fails with a validation error if the category does not belong to
+    /// `account_type`
+    pub fn check_valid_for(&self, account_type: AccountType) -> Result<()> {
+        if !self.is_valid_for(account_type) {
+            return Err(Error::Validation(format!(
+                "Category {} is not valid for account type {}",
+                self, account_type
+            )));
+        }
+        Ok(())
+    }
+
+    /// Subcategories accounts of this category may be grouped under in reports
+    pub fn subcategories(&self) -> &'static [&'static str] {
+        match self {
+            Self::CurrentAsset => &[
+                "Cash",
+                "Accounts Receivable",
+                "Inventory",
+                "Prepaid Expenses",
+                "Short-Term Investments",
+                "Other Current Assets",
+            ],
+            Self::FixedAsset => &[
+                "Land",
+                "Buildings",
+                "Equipment",
+                "Vehicles",
+                "Furniture and Fixtures",
+                "Accumulated Depreciation",
+            ],
+            Self::OtherAsset => &["Intangible Assets", "Long-Term Investments", "Deposits"],
+            Self::CurrentLiability => &[
+                "Accounts Payable",
+                "Accrued Liabilities",
+                "Taxes Payable",
+                "Payroll Liabilities",
+                "Short-Term Loans",
+                "Unearned Revenue",
+            ],
+            Self::LongTermLiability => &["Long-Term Loans", "Mortgages", "Bonds Payable"],
+            Self::OtherLiability => &["Deferred Taxes", "Other Liabilities"],
+            Self::OwnerEquity => &["Capital", "Drawings", "Common Stock"],
+            Self::RetainedEarnings => &["Retained Earnings"],
+            Self::OperatingRevenue => &["Sales", "Services"],
+            Self::NonOperatingRevenue => &["Interest Income", "Gains", "Other Income"],
+            Self::OperatingExpense => &[
+                "Cost of Goods Sold",
+                "Payroll",
+                "Rent",
+                "Utilities",
+                "Marketing",
+                "Office",
+                "Insurance",
+                "Depreciation",
+            ],
+            Self::NonOperatingExpense => &["Interest Expense", "Losses", "Other Expenses"],
+        }
+    }
+
+    /// Finds the allowed subcategory matching `value`, ignoring case and
+    /// surrounding whitespace, and returns its canonical spelling
+    pub fn canonical_subcategory(&self, value: &str) -> Option<&'static str> {
+        let value = value.trim();
+        self.subcategories()
+            .iter()
+            .copied()
+            .find(|subcategory| subcategory.eq_ignore_ascii_case(value))
+    }
 }
 
 /// Domain model for an Account
@@ -162,8 +385,14 @@ pub struct Account {
     pub is_active: bool,
     pub parent_id: Option<Uuid>,
     pub balance: Decimal,
+    pub currency: String,
+    pub version: i32,
+    pub note_count: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub updated_by: Option<Uuid>,
 }
 
 /// Data transfer object for account from database
@@ -179,8 +408,16 @@ pub struct AccountDto {
     pub is_active: bool,
     pub parent_id: Option<Uuid>,
     pub balance: Decimal,
+    pub currency: String,
+    pub version: i32,
+    // Maintained by the notes repository; never written through this DTO
+    #[sqlx(default)]
+    pub note_count: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_by: Option<Uuid>,
+    pub updated_by: Option<Uuid>,
 }
 
 /// Struct for creating a new account
@@ -193,6 +430,175 @@ pub struct NewAccount {
     pub category: AccountCategory,
     pub subcategory: Option<String>,
     pub parent_id: Option<Uuid>,
+    pub currency: String,
+}
+
+/// How much an account is used: its posted journal lines and the date of the
+/// latest one
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountActivityStats {
+    pub account_id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub balance: Decimal,
+    pub line_count: i64,
+    pub last_activity: Option<NaiveDate>,
+}
+
+impl AccountActivityStats {
+    /// An account that was never posted to and holds nothing can be archived
+    pub fn is_archive_candidate(&self) -> bool {
+        self.line_count == 0 && self.balance.is_zero()
+    }
+}
+
+/// Column an activity statistics listing can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityStatsSortField {
+    Code,
+    LineCount,
+    LastActivity,
+}
+
+impl ActivityStatsSortField {
+    /// Parses a sort field name as sent by the frontend
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "code" => Some(Self::Code),
+            "line_count" => Some(Self::LineCount),
+            "last_activity" => Some(Self::LastActivity),
+            _ => None,
+        }
+    }
+
+    /// The column to order by. Only these fixed names ever reach the SQL text.
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::Code => "a.code",
+            Self::LineCount => "line_count",
+            Self::LastActivity => "last_activity",
+        }
+    }
+}
+
+/// What references an account, gathered before deleting it
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountDeleteImpact {
+    pub children: i64,
+    pub posted_lines: i64,
+    pub unposted_lines: i64,
+    pub notes: i64,
+}
+
+impl AccountDeleteImpact {
+    /// Why the account cannot be deleted; empty when deletion is allowed.
+    /// Unposted lines and notes do not block deletion.
+    pub fn blocking_reasons(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if self.posted_lines > 0 {
+            reasons.push("Account has posted journal lines and cannot be deleted".to_string());
+        }
+        if self.children > 0 {
+            reasons.push("Account has child accounts and cannot be deleted".to_string());
+        }
+        reasons
+    }
+}
+
+/// Longest account code accepted for new and renamed accounts
+pub const MAX_CODE_LENGTH: usize = 20;
+
+/// Longest account name the `accounts` table accepts
+pub const MAX_NAME_LENGTH: usize = 200;
+
+/// Checks an account code: present, at most `MAX_CODE_LENGTH` characters and
+/// made only of letters, digits, '.', '_' and '-'. Surrounding whitespace is
+/// ignored since it is trimmed before the code is stored.
+pub fn validate_code(code: &str) -> Result<()> {
+    let code = code.trim();
+    if code.is_empty() {
+        return Err(Error::Validation("Account code is required".to_string()));
+    }
+    if code.chars().count() > MAX_CODE_LENGTH {
+        return Err(Error::Validation(format!(
+            "Account code must be at most {} characters",
+            MAX_CODE_LENGTH
+        )));
+    }
+    if let Some(c) = code
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+    {
+        return Err(Error::Validation(format!(
+            "Account code may only contain letters, digits, '.', '_' and '-', found '{}'",
+            c
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks an account name: present and at most `MAX_NAME_LENGTH` characters
+/// once surrounding whitespace is trimmed
+pub fn validate_name(name: &str) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::Validation("Account name is required".to_string()));
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(Error::Validation(format!(
+            "Account name must be at most {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks a submitted subcategory against those allowed for `category` and
+/// returns it in canonical spelling. A blank subcategory means none.
+pub fn normalize_subcategory(
+    category: AccountCategory,
+    subcategory: Option<&str>,
+) -> Result<Option<String>> {
+    let subcategory = match subcategory.map(str::trim) {
+        None | Some("") => return Ok(None),
+        Some(subcategory) => subcategory,
+    };
+
+    match category.canonical_subcategory(subcategory) {
+        Some(canonical) => Ok(Some(canonical.to_string())),
+        None => Err(Error::Validation(format!(
+            "Subcategory '{}' is not valid for category {}; use one of: {}",
+            subcategory,
+            category,
+            category.subcategories().join(", ")
+        ))),
+    }
+}
+
+impl NewAccount {
+    /// Checks the rules every new account must satisfy, wherever it comes
+    /// from: a well-formed code, a name that is neither blank nor too long,
+    /// a category that belongs to the account type and a subcategory that
+    /// belongs to the category. The subcategory is stored in its canonical
+    /// spelling. Every rule is checked, and the error lists each bad field.
+    pub fn validate(&mut self) -> Result<()> {
+        let mut errors = FieldErrors::new();
+
+        errors.check("code", validate_code(&self.code));
+        errors.check("name", validate_name(&self.name));
+        errors.check("category", self.category.check_valid_for(self.account_type));
+        if let Some(subcategory) = errors.check(
+            "subcategory",
+            normalize_subcategory(self.category, self.subcategory.as_deref()),
+        ) {
+            self.subcategory = subcategory;
+        }
+
+        errors.finish()
+    }
 }
 
 impl Account {
@@ -202,8 +608,8 @@ impl Account {
 
         Self {
             id: Uuid::new_v4(),
-            code: new_account.code,
-            name: new_account.name,
+            code: new_account.code.trim().to_string(),
+            name: new_account.name.trim().to_string(),
             description: new_account.description,
             account_type: new_account.account_type,
             category: new_account.category,
@@ -211,8 +617,14 @@ impl Account {
             is_active: true,
             parent_id: new_account.parent_id,
             balance: Decimal::ZERO,
+            currency: new_account.currency,
+            version: 1,
+            note_count: 0,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            created_by: None,
+            updated_by: None,
         }
     }
 
@@ -226,11 +638,55 @@ impl Account {
         self.account_type.is_credit_normal()
     }
 
-    /// Updates the account balance
+    /// Updates the account balance, keeping it at money scale
     pub fn update_balance(&mut self, amount: Decimal) {
-        self.balance += amount;
+        self.balance = money::round(self.balance + amount);
         self.updated_at = Utc::now();
     }
+
+    /// Lists the user-editable fields that differ from `old`. Bookkeeping
+    /// fields such as `version` and `updated_at` are left out.
+    pub fn changes_from(&self, old: &Account) -> Vec<FieldChange> {
+        [
+            FieldChange::between("code", Some(&old.code), Some(&self.code)),
+            FieldChange::between("name", Some(&old.name), Some(&self.name)),
+            FieldChange::between(
+                "description",
+                old.description.as_ref(),
+                self.description.as_ref(),
+            ),
+            FieldChange::between(
+                "account_type",
+                Some(old.account_type),
+                Some(self.account_type),
+            ),
+            FieldChange::between("category", Some(old.category), Some(self.category)),
+            FieldChange::between(
+                "subcategory",
+                old.subcategory.as_ref(),
+                self.subcategory.as_ref(),
+            ),
+            FieldChange::between("is_active", Some(old.is_active), Some(self.is_active)),
+            FieldChange::between("parent_id", old.parent_id, self.parent_id),
+            FieldChange::between("currency", Some(&old.currency), Some(&self.currency)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Moves the account to another category, dropping a subcategory that
+    /// the new category does not allow
+    pub fn set_category(&mut self, category: AccountCategory) {
+        if category != self.category {
+            self.subcategory = self
+                .subcategory
+                .as_deref()
+                .and_then(|subcategory| category.canonical_subcategory(subcategory))
+                .map(str::to_string);
+            self.category = category;
+        }
+    }
 }
 
 impl From<AccountDto> for Account {
@@ -247,8 +703,14 @@ impl From<AccountDto> for Account {
             is_active: dto.is_active,
             parent_id: dto.parent_id,
             balance: dto.balance,
+            currency: dto.currency,
+            version: dto.version,
+            note_count: dto.note_count,
             created_at: dto.created_at,
             updated_at: dto.updated_at,
+            deleted_at: dto.deleted_at,
+            created_by: dto.created_by,
+            updated_by: dto.updated_by,
         }
     }
 }
@@ -266,8 +728,460 @@ impl From<Account> for AccountDto {
             is_active: account.is_active,
             parent_id: account.parent_id,
             balance: account.balance,
+            currency: account.currency,
+            version: account.version,
+            note_count: account.note_count,
             created_at: account.created_at,
             updated_at: account.updated_at,
+            deleted_at: account.deleted_at,
+            created_by: account.created_by,
+            updated_by: account.updated_by,
+        }
+    }
+}
+
+/// Column an account listing can be ordered by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountSortField {
+    Code,
+    Name,
+    Type,
+    Balance,
+    CreatedAt,
+}
+
+impl AccountSortField {
+    /// Parses a sort field name as sent by the frontend
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "code" => Some(Self::Code),
+            "name" => Some(Self::Name),
+            "type" => Some(Self::Type),
+            "balance" => Some(Self::Balance),
+            "created_at" => Some(Self::CreatedAt),
+            _ => None,
+        }
+    }
+
+    /// The column to order by. Only these fixed names ever reach the SQL text.
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::Code => "code",
+            Self::Name => "name",
+            Self::Type => "account_type",
+            Self::Balance => "balance",
+            Self::CreatedAt => "created_at",
+        }
+    }
+}
+
+/// How an account matched a search, from strongest to weakest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountMatchKind {
+    ExactCode,
+    CodePrefix,
+    NamePrefix,
+    Substring,
+}
+
+impl AccountMatchKind {
+    /// Maps the rank computed by the search query back to a match kind
+    pub fn from_rank(rank: i32) -> Self {
+        match rank {
+            0 => Self::ExactCode,
+            1 => Self::CodePrefix,
+            2 => Self::NamePrefix,
+            _ => Self::Substring,
+        }
+    }
+}
+
+/// Sort direction of an account listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    /// Parses "asc" or "desc"
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "asc" => Some(Self::Asc),
+            "desc" => Some(Self::Desc),
+            _ => None,
+        }
+    }
+
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// An account whose stored balance disagreed with its posted journal lines,
+/// and the balance it was corrected to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceCorrection {
+    pub account_id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub old_balance: Decimal,
+    pub new_balance: Decimal,
+}
+
+impl BalanceCorrection {
+    /// How far the stored balance had drifted; positive when it was too low
+    pub fn difference(&self) -> Decimal {
+        self.new_balance - self.old_balance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::postgres::{PgArgumentBuffer, Postgres};
+    use sqlx::Encode;
+
+    /// The text sqlx writes to the database for a value
+    fn encoded<T: for<'q> Encode<'q, Postgres>>(value: T) -> String {
+        let mut buf = PgArgumentBuffer::default();
+        let _ = value.encode_by_ref(&mut buf);
+        String::from_utf8(buf.to_vec()).unwrap()
+    }
+
+    fn new_account(code: &str, name: &str) -> NewAccount {
+        NewAccount {
+            code: code.to_string(),
+            name: name.to_string(),
+            description: None,
+            account_type: AccountType::Asset,
+            category: AccountCategory::CurrentAsset,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        }
+    }
+
+    fn validation_message(result: Result<()>) -> String {
+        match result {
+            Err(Error::Validation(message)) => message,
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    fn field_errors(result: Result<()>) -> Vec<(String, String)> {
+        match result {
+            Err(Error::InvalidFields(errors)) => errors
+                .into_iter()
+                .map(|error| (error.field, error.message))
+                .collect(),
+            other => panic!("expected field errors, got {:?}", other),
+        }
+    }
+
+    fn error(field: &str, message: &str) -> (String, String) {
+        (field.to_string(), message.to_string())
+    }
+
+    #[test]
+    fn complete_new_account_is_valid() {
+        let mut account = new_account("1000", "Cash");
+        account.subcategory = Some(" cash ".to_string());
+
+        account.validate().unwrap();
+        assert_eq!(account.subcategory.as_deref(), Some("Cash"));
+    }
+
+    #[test]
+    fn new_account_requires_code_and_name() {
+        assert_eq!(
+            field_errors(new_account("  ", " \t").validate()),
+            [
+                error("code", "Account code is required"),
+                error("name", "Account name is required"),
+            ]
+        );
+    }
+
+    #[test]
+    fn account_code_length_is_limited() {
+        let longest = "1".repeat(MAX_CODE_LENGTH);
+        validate_code(&longest).unwrap();
+        validate_code(&format!("  {}  ", longest)).unwrap();
+        assert_eq!(
+            validation_message(validate_code(&format!("{}1", longest))),
+            format!(
+                "Account code must be at most {} characters",
+                MAX_CODE_LENGTH
+            )
+        );
+    }
+
+    #[test]
+    fn account_code_allows_only_letters_digits_and_separators() {
+        validate_code("1000-A.b_c").unwrap();
+        for (code, found) in [("10 00", ' '), ("10/00", '/'), ("1000é", 'é')] {
+            assert_eq!(
+                validation_message(validate_code(code)),
+                format!(
+                    "Account code may only contain letters, digits, '.', '_' and '-', found '{}'",
+                    found
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn account_name_length_counts_characters() {
+        let longest = "é".repeat(MAX_NAME_LENGTH);
+        validate_name(&longest).unwrap();
+        validate_name(&format!(" {} ", longest)).unwrap();
+        assert_eq!(
+            validation_message(validate_name(&format!("{}é", longest))),
+            format!(
+                "Account name must be at most {} characters",
+                MAX_NAME_LENGTH
+            )
+        );
+    }
+
+    #[test]
+    fn new_account_rejects_category_of_another_type() {
+        let mut account = new_account("1000", "Cash");
+        account.category = AccountCategory::OperatingExpense;
+        assert_eq!(
+            field_errors(account.validate()),
+            [error(
+                "category",
+                "Category OPERATING_EXPENSE is not valid for account type ASSET"
+            )]
+        );
+    }
+
+    #[test]
+    fn new_account_rejects_subcategory_of_another_category() {
+        let mut account = new_account("1000", "Cash");
+        account.subcategory = Some("Rent".to_string());
+        let errors = field_errors(account.validate());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "subcategory");
+        assert!(errors[0].1.starts_with("Subcategory 'Rent'"));
+    }
+
+    #[test]
+    fn new_account_reports_every_bad_field_at_once() {
+        let mut account = new_account("10/00", "");
+        account.category = AccountCategory::OperatingExpense;
+        account.subcategory = Some("Nonsense".to_string());
+        let fields: Vec<String> = field_errors(account.validate())
+            .into_iter()
+            .map(|(field, _)| field)
+            .collect();
+        assert_eq!(fields, ["code", "name", "category", "subcategory"]);
+    }
+
+    #[test]
+    fn account_cursor_round_trips_as_an_opaque_token() {
+        let cursor = AccountCursor {
+            code: "1000-A".to_string(),
+            id: Uuid::new_v4(),
+        };
+        let token = cursor.to_string();
+
+        assert!(token.bytes().all(|byte| byte.is_ascii_hexdigit()));
+        assert!(!token.contains("1000"));
+        assert_eq!(token.parse::<AccountCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn account_type_display_round_trips_through_from_str() {
+        for account_type in AccountType::all() {
+            assert_eq!(
+                AccountType::from_str(&account_type.to_string()),
+                Some(account_type)
+            );
         }
     }
+
+    #[test]
+    fn account_category_display_round_trips_through_from_str() {
+        for category in AccountCategory::all() {
+            assert_eq!(
+                AccountCategory::from_str(&category.to_string()),
+                Some(category)
+            );
+        }
+    }
+
+    #[test]
+    fn account_type_serializes_as_its_display_value() {
+        for account_type in AccountType::all() {
+            let json = serde_json::to_value(account_type).unwrap();
+            assert_eq!(json, serde_json::json!(account_type.to_string()));
+            assert_eq!(
+                serde_json::from_value::<AccountType>(json).unwrap(),
+                account_type
+            );
+        }
+    }
+
+    #[test]
+    fn account_category_serializes_as_its_display_value() {
+        for category in AccountCategory::all() {
+            let json = serde_json::to_value(category).unwrap();
+            assert_eq!(json, serde_json::json!(category.to_string()));
+            assert_eq!(
+                serde_json::from_value::<AccountCategory>(json).unwrap(),
+                category
+            );
+        }
+    }
+
+    #[test]
+    fn enums_deserialize_from_the_strings_the_frontend_sends() {
+        assert_eq!(
+            serde_json::from_str::<AccountType>(r#""LIABILITY""#).unwrap(),
+            AccountType::Liability
+        );
+        assert_eq!(
+            serde_json::from_str::<AccountCategory>(r#""LONG_TERM_LIABILITY""#).unwrap(),
+            AccountCategory::LongTermLiability
+        );
+        assert!(serde_json::from_str::<AccountCategory>(r#""LongTermLiability""#).is_err());
+    }
+
+    #[test]
+    fn account_category_database_display_and_serde_values_agree() {
+        let categories = AccountCategory::all();
+        assert_eq!(categories.len(), 12);
+
+        for category in categories {
+            let display = category.to_string();
+            assert_eq!(encoded(category), display);
+            assert_eq!(
+                serde_json::to_value(category).unwrap(),
+                serde_json::json!(display)
+            );
+            assert_eq!(AccountCategory::from_str(&display), Some(category));
+        }
+    }
+
+    #[test]
+    fn account_type_database_value_matches_display() {
+        for account_type in AccountType::all() {
+            assert_eq!(encoded(account_type), account_type.to_string());
+        }
+    }
+
+    #[test]
+    fn every_category_belongs_to_exactly_one_account_type() {
+        for category in AccountCategory::all() {
+            let owners = AccountType::all()
+                .into_iter()
+                .filter(|account_type| category.is_valid_for(*account_type))
+                .count();
+            assert_eq!(
+                owners, 1,
+                "{} belongs to {} account types",
+                category, owners
+            );
+        }
+    }
+
+    #[test]
+    fn each_account_type_accepts_only_its_own_categories() {
+        let expected = [
+            (
+                AccountType::Asset,
+                vec![
+                    AccountCategory::CurrentAsset,
+                    AccountCategory::FixedAsset,
+                    AccountCategory::OtherAsset,
+                ],
+            ),
+            (
+                AccountType::Liability,
+                vec![
+                    AccountCategory::CurrentLiability,
+                    AccountCategory::LongTermLiability,
+                    AccountCategory::OtherLiability,
+                ],
+            ),
+            (
+                AccountType::Equity,
+                vec![
+                    AccountCategory::OwnerEquity,
+                    AccountCategory::RetainedEarnings,
+                ],
+            ),
+            (
+                AccountType::Revenue,
+                vec![
+                    AccountCategory::OperatingRevenue,
+                    AccountCategory::NonOperatingRevenue,
+                ],
+            ),
+            (
+                AccountType::Expense,
+                vec![
+                    AccountCategory::OperatingExpense,
+                    AccountCategory::NonOperatingExpense,
+                ],
+            ),
+        ];
+        assert_eq!(expected.len(), AccountType::all().len());
+
+        for (account_type, categories) in expected {
+            assert_eq!(AccountCategory::for_account_type(account_type), categories);
+            for category in AccountCategory::all() {
+                assert_eq!(
+                    category.is_valid_for(account_type),
+                    categories.contains(&category),
+                    "{} for {}",
+                    category,
+                    account_type
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn changes_from_lists_only_changed_fields() {
+        let old = Account::new(NewAccount {
+            code: "1000".to_string(),
+            name: "Cash".to_string(),
+            description: None,
+            account_type: AccountType::Asset,
+            category: AccountCategory::CurrentAsset,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        });
+        let mut new = old.clone();
+        new.name = "Petty cash".to_string();
+        new.description = Some("Office drawer".to_string());
+        new.version += 1;
+        new.updated_at = Utc::now();
+
+        assert_eq!(
+            new.changes_from(&old),
+            vec![
+                FieldChange {
+                    field: "name".to_string(),
+                    old_value: Some("Cash".to_string()),
+                    new_value: Some("Petty cash".to_string()),
+                },
+                FieldChange {
+                    field: "description".to_string(),
+                    old_value: None,
+                    new_value: Some("Office drawer".to_string()),
+                },
+            ]
+        );
+        assert!(old.changes_from(&old).is_empty());
+    }
 }
@@ -0,0 +1,167 @@
+// src-tauri/models/activity.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use std::fmt;
+use uuid::Uuid;
+
+/// ActivityAction identifies what kind of change an activity log entry records
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ActivityAction {
+    AccountCreated,
+    AccountUpdated,
+    AccountDeleted,
+    AccountRestored,
+    AccountStatusChanged,
+    BackupRestored,
+    BalancesRecomputed,
+    TransferCreated,
+}
+
+impl fmt::Display for ActivityAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AccountCreated => write!(f, "ACCOUNT_CREATED"),
+            Self::AccountUpdated => write!(f, "ACCOUNT_UPDATED"),
+            Self::AccountDeleted => write!(f, "ACCOUNT_DELETED"),
+            Self::AccountRestored => write!(f, "ACCOUNT_RESTORED"),
+            Self::AccountStatusChanged => write!(f, "ACCOUNT_STATUS_CHANGED"),
+            Self::BackupRestored => write!(f, "BACKUP_RESTORED"),
+            Self::BalancesRecomputed => write!(f, "BALANCES_RECOMPUTED"),
+            Self::TransferCreated => write!(f, "TRANSFER_CREATED"),
+        }
+    }
+}
+
+impl ActivityAction {
+    /// Parses an action name as stored in the log, ignoring case
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "ACCOUNT_CREATED" => Some(Self::AccountCreated),
+            "ACCOUNT_UPDATED" => Some(Self::AccountUpdated),
+            "ACCOUNT_DELETED" => Some(Self::AccountDeleted),
+            "ACCOUNT_RESTORED" => Some(Self::AccountRestored),
+            "ACCOUNT_STATUS_CHANGED" => Some(Self::AccountStatusChanged),
+            "BACKUP_RESTORED" => Some(Self::BackupRestored),
+            "BALANCES_RECOMPUTED" => Some(Self::BalancesRecomputed),
+            "TRANSFER_CREATED" => Some(Self::TransferCreated),
+            _ => None,
+        }
+    }
+
+    /// Human-readable label for activity feeds
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::AccountCreated => "Account Created",
+            Self::AccountUpdated => "Account Updated",
+            Self::AccountDeleted => "Account Deleted",
+            Self::AccountRestored => "Account Restored",
+            Self::AccountStatusChanged => "Account Status Changed",
+            Self::BackupRestored => "Backup Restored",
+            Self::BalancesRecomputed => "Balances Recomputed",
+            Self::TransferCreated => "Transfer Created",
+        }
+    }
+
+    /// The kind of entity the action applies to
+    pub fn entity_type(&self) -> &'static str {
+        match self {
+            Self::BackupRestored => "BACKUP",
+            Self::TransferCreated => "JOURNAL_ENTRY",
+            _ => "ACCOUNT",
+        }
+    }
+}
+
+/// Domain model for an activity log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityLog {
+    pub id: Uuid,
+    pub action: ActivityAction,
+    pub entity_type: String,
+    pub entity_id: Option<Uuid>,
+    pub description: String,
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub details: Vec<FieldChange>,
+}
+
+/// One field an update changed; values are as shown to users, `None` when empty
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl FieldChange {
+    /// The change of `field` from `old` to `new`, or `None` if it kept its value
+    pub fn between<T: ToString + PartialEq>(
+        field: &str,
+        old: Option<T>,
+        new: Option<T>,
+    ) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        Some(Self {
+            field: field.to_string(),
+            old_value: old.map(|value| value.to_string()),
+            new_value: new.map(|value| value.to_string()),
+        })
+    }
+}
+
+/// Data transfer object for activity log entries from database, joined with
+/// the acting user's name
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ActivityLogDto {
+    pub id: Uuid,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: Option<Uuid>,
+    pub description: String,
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub details: Option<Json<Vec<FieldChange>>>,
+}
+
+/// Struct for recording a new activity
+#[derive(Debug, Clone)]
+pub struct NewActivity {
+    pub action: ActivityAction,
+    pub entity_id: Option<Uuid>,
+    pub description: String,
+    pub user_id: Option<Uuid>,
+    /// Fields the action changed; empty when not tracked
+    pub details: Vec<FieldChange>,
+}
+
+/// Criteria for browsing the activity log; `None` leaves a criterion off.
+/// `from` is inclusive and `to` exclusive.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFilter {
+    pub entity_type: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl From<ActivityLogDto> for ActivityLog {
+    fn from(dto: ActivityLogDto) -> Self {
+        Self {
+            id: dto.id,
+            action: ActivityAction::parse(&dto.action).unwrap_or(ActivityAction::AccountUpdated),
+            entity_type: dto.entity_type,
+            entity_id: dto.entity_id,
+            description: dto.description,
+            user_id: dto.user_id,
+            username: dto.username,
+            created_at: dto.created_at,
+            details: dto.details.map(|details| details.0).unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// src-tauri/models/backup.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A backup file written to the data directory
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct BackupRecord {
+    pub id: Uuid,
+    pub file_path: String,
+    pub size_bytes: i64,
+    pub account_count: i32,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
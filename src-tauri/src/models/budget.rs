@@ -0,0 +1,19 @@
+// src-tauri/models/budget.rs
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The amount budgeted for a revenue or expense account over a fiscal period
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Budget {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub period_id: Uuid,
+    pub amount: Decimal,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub created_by: Option<Uuid>,
+    pub updated_by: Option<Uuid>,
+}
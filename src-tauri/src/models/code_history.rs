@@ -0,0 +1,17 @@
+// src-tauri/models/code_history.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A change of an account's code, joined with the name of the user who made it
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CodeChange {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub old_code: String,
+    pub new_code: String,
+    pub changed_at: DateTime<Utc>,
+    pub changed_by: Option<Uuid>,
+    pub username: Option<String>,
+}
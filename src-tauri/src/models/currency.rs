@@ -0,0 +1,93 @@
+// src-tauri/models/currency.rs
+
+/// Currency assigned to accounts created without one
+pub const DEFAULT_CURRENCY: &str = "USD";
+
+/// A currency accounts may be kept in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+    /// ISO 4217 code
+    pub code: &'static str,
+    pub symbol: &'static str,
+    /// Digits after the decimal point in amounts shown to users
+    pub decimal_places: u32,
+    pub name: &'static str,
+}
+
+/// Every currency accepted for accounts; the frontend's currency list and
+/// balance formatting come from here
+pub const SUPPORTED_CURRENCIES: &[Currency] = &[
+    Currency {
+        code: "USD",
+        symbol: "$",
+        decimal_places: 2,
+        name: "US Dollar",
+    },
+    Currency {
+        code: "EUR",
+        symbol: "€",
+        decimal_places: 2,
+        name: "Euro",
+    },
+    Currency {
+        code: "GBP",
+        symbol: "£",
+        decimal_places: 2,
+        name: "British Pound",
+    },
+    Currency {
+        code: "JPY",
+        symbol: "¥",
+        decimal_places: 0,
+        name: "Japanese Yen",
+    },
+    Currency {
+        code: "CAD",
+        symbol: "CA$",
+        decimal_places: 2,
+        name: "Canadian Dollar",
+    },
+    Currency {
+        code: "AUD",
+        symbol: "A$",
+        decimal_places: 2,
+        name: "Australian Dollar",
+    },
+    Currency {
+        code: "CHF",
+        symbol: "CHF",
+        decimal_places: 2,
+        name: "Swiss Franc",
+    },
+    Currency {
+        code: "CNY",
+        symbol: "CN¥",
+        decimal_places: 2,
+        name: "Chinese Yuan",
+    },
+    Currency {
+        code: "INR",
+        symbol: "₹",
+        decimal_places: 2,
+        name: "Indian Rupee",
+    },
+    Currency {
+        code: "MXN",
+        symbol: "MX$",
+        decimal_places: 2,
+        name: "Mexican Peso",
+    },
+];
+
+/// Looks up a supported currency by its ISO code, ignoring case and surrounding whitespace
+pub fn find_currency(code: &str) -> Option<&'static Currency> {
+    let code = code.trim();
+    SUPPORTED_CURRENCIES
+        .iter()
+        .find(|currency| currency.code.eq_ignore_ascii_case(code))
+}
+
+/// Normalizes a currency code to upper case, returning `None` if it is not supported
+pub fn normalize_currency(code: &str) -> Option<String> {
+    find_currency(code).map(|currency| currency.code.to_string())
+}
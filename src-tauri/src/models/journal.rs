@@ -0,0 +1,101 @@
+// src-tauri/models/journal.rs
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Header of a journal entry
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct JournalEntry {
+    pub id: Uuid,
+    pub entry_date: NaiveDate,
+    pub description: String,
+    pub reference: Option<String>,
+    pub exchange_rate: Option<Decimal>,
+    pub is_posted: bool,
+    pub posted_at: Option<DateTime<Utc>>,
+    /// Entry this one reverses, for reversing entries
+    pub reverses_entry_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A single debit or credit line of a journal entry
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct JournalLine {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub account_id: Uuid,
+    pub line_number: i32,
+    pub description: Option<String>,
+    pub debit: Decimal,
+    pub credit: Decimal,
+}
+
+/// A posted journal line as it appears in an account's ledger
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LedgerLine {
+    pub entry_id: Uuid,
+    pub entry_date: NaiveDate,
+    pub description: String,
+    pub reference: Option<String>,
+    pub debit: Decimal,
+    pub credit: Decimal,
+}
+
+/// Posted debit and credit totals of one account over a date range
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountActivity {
+    pub account_id: Uuid,
+    pub debit: Decimal,
+    pub credit: Decimal,
+}
+
+/// Struct for creating a new journal line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewJournalLine {
+    pub account_id: Uuid,
+    pub description: Option<String>,
+    pub debit: Decimal,
+    pub credit: Decimal,
+}
+
+/// Struct for creating a new journal entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewJournalEntry {
+    pub entry_date: NaiveDate,
+    pub description: String,
+    pub reference: Option<String>,
+    pub exchange_rate: Option<Decimal>,
+    pub lines: Vec<NewJournalLine>,
+}
+
+impl JournalLine {
+    /// Copy of the line with debit and credit swapped, for a reversing entry
+    pub fn reversed(&self) -> NewJournalLine {
+        NewJournalLine {
+            account_id: self.account_id,
+            description: self.description.clone(),
+            debit: self.credit,
+            credit: self.debit,
+        }
+    }
+}
+
+impl NewJournalEntry {
+    /// Sum of all debit amounts
+    pub fn total_debit(&self) -> Decimal {
+        self.lines.iter().map(|line| line.debit).sum()
+    }
+
+    /// Sum of all credit amounts
+    pub fn total_credit(&self) -> Decimal {
+        self.lines.iter().map(|line| line.credit).sum()
+    }
+
+    /// Checks that the entry has lines and that debits equal credits
+    pub fn is_balanced(&self) -> bool {
+        !self.lines.is_empty() && self.total_debit() == self.total_credit()
+    }
+}
@@ -1 +1,12 @@
 pub mod account;
+pub mod activity;
+pub mod backup;
+pub mod budget;
+pub mod code_history;
+pub mod currency;
+pub mod journal;
+pub mod note;
+pub mod period;
+pub mod snapshot;
+pub mod summary;
+pub mod user;
@@ -0,0 +1,17 @@
+// src-tauri/models/note.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A dated note on an account, joined with the author's name. Notes are
+/// append-only.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AccountNote {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub body: String,
+    pub author_id: Option<Uuid>,
+    pub author: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,71 @@
+// src-tauri/models/period.rs
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An accounting period covering an inclusive date range
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct FiscalPeriod {
+    pub id: Uuid,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub is_closed: bool,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Struct for creating a new fiscal period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewFiscalPeriod {
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl FiscalPeriod {
+    /// Checks if `date` falls within the period
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && date <= self.end_date
+    }
+}
+
+/// Twelve months starting on the first of the configured fiscal start month
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FiscalYear {
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl FiscalYear {
+    /// The fiscal year containing `date` when years start in `start_month`
+    /// (1-12). A year starting in January is named after its calendar year,
+    /// e.g. "2025"; any other after both years it spans, e.g. "2025/26".
+    pub fn containing(date: NaiveDate, start_month: u32) -> Self {
+        let start_year = if date.month() >= start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        let start_date = NaiveDate::from_ymd_opt(start_year, start_month, 1).unwrap_or(date);
+        let end_date = start_date
+            .checked_add_months(Months::new(12))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(date);
+
+        let name = if start_month == 1 {
+            start_year.to_string()
+        } else {
+            format!("{}/{:02}", start_year, (start_year + 1) % 100)
+        };
+
+        Self {
+            name,
+            start_date,
+            end_date,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// src-tauri/models/snapshot.rs
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An account's balance right after a posting or direct adjustment
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct BalanceSnapshot {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    /// The posted entry that produced this balance; None for direct adjustments
+    pub entry_id: Option<Uuid>,
+    pub balance: Decimal,
+    pub recorded_at: DateTime<Utc>,
+}
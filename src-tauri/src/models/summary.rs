@@ -0,0 +1,17 @@
+// src-tauri/models/summary.rs
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Balance totals per account type as of the last refresh of the
+/// `financial_summary` view
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct FinancialSummary {
+    pub total_assets: Decimal,
+    pub total_liabilities: Decimal,
+    pub total_equity: Decimal,
+    pub total_revenue: Decimal,
+    pub total_expenses: Decimal,
+    pub refreshed_at: DateTime<Utc>,
+}
@@ -0,0 +1,100 @@
+// src-tauri/models/user.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// UserRole controls which operations a user may perform. Roles are ordered
+/// from least to most privileged.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UserRole {
+    Viewer,
+    Accountant,
+    Admin,
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserRole::Viewer => write!(f, "VIEWER"),
+            UserRole::Accountant => write!(f, "ACCOUNTANT"),
+            UserRole::Admin => write!(f, "ADMIN"),
+        }
+    }
+}
+
+impl UserRole {
+    /// Parses a role name, ignoring case
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "VIEWER" => Some(Self::Viewer),
+            "ACCOUNTANT" => Some(Self::Accountant),
+            "ADMIN" => Some(Self::Admin),
+            _ => None,
+        }
+    }
+
+    /// Check if this role grants at least the privileges of `required`
+    pub fn satisfies(&self, required: UserRole) -> bool {
+        *self >= required
+    }
+}
+
+/// Domain model for a User
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+    pub role: UserRole,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Data transfer object for user from database
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserDto {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub password_hash: String,
+    pub role: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Struct for creating a new user; `password` is hashed before storage
+#[derive(Debug, Clone, Deserialize)]
+pub struct NewUser {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub role: UserRole,
+}
+
+impl User {
+    /// Checks a plaintext password against the stored hash
+    pub fn verify_password(&self, password: &str) -> bool {
+        bcrypt::verify(password, &self.password_hash).unwrap_or(false)
+    }
+}
+
+impl From<UserDto> for User {
+    fn from(dto: UserDto) -> Self {
+        Self {
+            id: dto.id,
+            username: dto.username,
+            email: dto.email,
+            password_hash: dto.password_hash,
+            role: UserRole::parse(&dto.role).unwrap_or(UserRole::Viewer),
+            is_active: dto.is_active,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
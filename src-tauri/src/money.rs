@@ -0,0 +1,218 @@
+// src/money.rs
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::error::{Error, Result};
+
+/// Number of decimal places kept for stored balances and shown to users
+pub const MONEY_SCALE: u32 = 2;
+
+/// Largest difference between two amounts that still counts as equal: half of
+/// the smallest unit shown, so amounts that differ only by rounding noise agree
+pub const TOLERANCE: Decimal = Decimal::from_parts(5, 0, 0, false, MONEY_SCALE + 1);
+
+/// Rounds an amount to `MONEY_SCALE` places using bankers' rounding, so that
+/// rounding many computed values does not drift in one direction
+pub fn round(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(MONEY_SCALE, RoundingStrategy::MidpointNearestEven)
+}
+
+/// Formats an amount with exactly `MONEY_SCALE` decimal places, e.g. "100.00"
+pub fn format(amount: Decimal) -> String {
+    let mut rounded = round(amount);
+    rounded.rescale(MONEY_SCALE);
+    rounded.to_string()
+}
+
+/// Whether two amounts are equal within `TOLERANCE`
+pub fn approx_eq(a: Decimal, b: Decimal) -> bool {
+    (a - b).abs() < TOLERANCE
+}
+
+/// Parses an amount typed by a user, e.g. "-1250.50". Only an optional sign,
+/// digits and a decimal point are accepted: thousands separators, currency
+/// symbols and exponents are rejected rather than guessed at, as are more than
+/// `MONEY_SCALE` decimal places. Errors name `field`.
+pub fn parse(field: &str, input: &str) -> Result<Decimal> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(Error::Validation(format!("{} is required", field)));
+    }
+
+    let digits = input.strip_prefix(['-', '+']).unwrap_or(input);
+    if digits.contains(',') {
+        return Err(Error::Validation(format!(
+            "{} must not contain thousands separators",
+            field
+        )));
+    }
+    if !digits.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return Err(Error::Validation(format!(
+            "{} must be a plain number without currency symbols",
+            field
+        )));
+    }
+
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if fraction.contains('.') || (whole.is_empty() && fraction.is_empty()) {
+        return Err(Error::Validation(format!(
+            "{} is not a valid number",
+            field
+        )));
+    }
+    if fraction.len() > MONEY_SCALE as usize {
+        return Err(Error::Validation(format!(
+            "{} cannot have more than {} decimal places",
+            field, MONEY_SCALE
+        )));
+    }
+
+    input
+        .parse::<Decimal>()
+        .map_err(|_| Error::Validation(format!("{} is not a valid number", field)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::currency::SUPPORTED_CURRENCIES;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn format_always_shows_two_decimal_places() {
+        assert_eq!(format(dec("100")), "100.00");
+        assert_eq!(format(dec("0.5")), "0.50");
+        assert_eq!(format(dec("1250.123")), "1250.12");
+        assert_eq!(format(Decimal::ZERO), "0.00");
+    }
+
+    #[test]
+    fn format_keeps_the_sign_of_negative_amounts() {
+        assert_eq!(format(dec("-42")), "-42.00");
+        assert_eq!(format(dec("-0.125")), "-0.12");
+    }
+
+    #[test]
+    fn round_sends_midpoints_to_the_even_neighbour() {
+        assert_eq!(round(dec("2.345")), dec("2.34"));
+        assert_eq!(round(dec("2.355")), dec("2.36"));
+        assert_eq!(round(dec("-2.345")), dec("-2.34"));
+        assert_eq!(round(dec("2.3451")), dec("2.35"));
+    }
+
+    #[test]
+    fn rounding_midpoints_does_not_drift_when_summed() {
+        // Half-up rounding would add 0.005 per amount here; bankers' rounding
+        // cancels out across the evens and odds
+        let amounts: Vec<Decimal> = (0..100)
+            .map(|cents| Decimal::new(cents * 10 + 5, 3))
+            .collect();
+        let exact: Decimal = amounts.iter().sum();
+        let rounded: Decimal = amounts.iter().copied().map(round).sum();
+
+        assert_eq!(rounded, exact);
+    }
+
+    #[test]
+    fn parse_accepts_plain_and_signed_amounts() {
+        assert_eq!(parse("Amount", "100").unwrap(), dec("100"));
+        assert_eq!(parse("Amount", " 1250.50 ").unwrap(), dec("1250.50"));
+        assert_eq!(parse("Amount", "-1250.5").unwrap(), dec("-1250.5"));
+        assert_eq!(parse("Amount", "+3").unwrap(), dec("3"));
+        assert_eq!(parse("Amount", ".25").unwrap(), dec("0.25"));
+    }
+
+    #[test]
+    fn parsed_amounts_format_back_to_the_same_value() {
+        for input in ["0", "100", "-19.99", "1234567.89"] {
+            let amount = parse("Amount", input).unwrap();
+            assert_eq!(parse("Amount", &format(amount)).unwrap(), amount);
+        }
+    }
+
+    #[test]
+    fn approx_eq_ignores_differences_below_half_a_cent() {
+        assert!(approx_eq(dec("10.004"), dec("10")));
+        assert!(!approx_eq(dec("10.005"), dec("10")));
+        assert!(approx_eq(dec("-3.331"), dec("-3.33")));
+    }
+
+    fn parse_error(input: &str) -> String {
+        match parse("Amount", input) {
+            Err(Error::Validation(message)) => message,
+            other => panic!(
+                "expected a validation error for {:?}, got {:?}",
+                input, other
+            ),
+        }
+    }
+
+    #[test]
+    fn parse_rejects_thousands_separators() {
+        assert_eq!(
+            parse_error("1,000.00"),
+            "Amount must not contain thousands separators"
+        );
+        assert_eq!(
+            parse_error("-1,000"),
+            "Amount must not contain thousands separators"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_currency_symbols_and_exponents() {
+        for input in ["$5", "5 USD", "€5", "1e3", "12a"] {
+            assert_eq!(
+                parse_error(input),
+                "Amount must be a plain number without currency symbols"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_rejects_more_than_two_decimal_places() {
+        assert_eq!(
+            parse_error("1.234"),
+            "Amount cannot have more than 2 decimal places"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_empty_and_malformed_numbers() {
+        assert_eq!(parse_error("  "), "Amount is required");
+        for input in [".", "-", "1.2.3", "--1"] {
+            assert!(parse("Amount", input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn every_supported_currency_fits_the_stored_scale() {
+        for currency in SUPPORTED_CURRENCIES {
+            assert!(
+                currency.decimal_places <= MONEY_SCALE,
+                "{} needs {} decimal places",
+                currency.code,
+                currency.decimal_places
+            );
+
+            // The smallest unit of each currency parses and survives rounding
+            let smallest = Decimal::new(1, currency.decimal_places);
+            let typed = format(smallest);
+            assert_eq!(round(parse("Amount", &typed).unwrap()), smallest);
+        }
+    }
+
+    #[test]
+    fn whole_amounts_parse_for_currencies_without_minor_units() {
+        let yen = SUPPORTED_CURRENCIES
+            .iter()
+            .find(|currency| currency.decimal_places == 0)
+            .unwrap();
+        assert_eq!(yen.code, "JPY");
+        assert_eq!(parse("Amount", "1500").unwrap(), dec("1500"));
+        assert_eq!(format(dec("1500")), "1500.00");
+    }
+}
@@ -0,0 +1,102 @@
+use crate::models::account::{Account, AccountCursor, NewAccount};
+use crate::repositories::accounts::AccountRepository;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// The account operations the command layer's validation relies on, so that
+/// it can run against `AccountRepository` or an in-memory store
+#[async_trait]
+pub trait AccountStore: Send + Sync {
+    async fn find_all(&self) -> Result<Vec<Account>, sqlx::Error>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Account>, sqlx::Error>;
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<Account>, sqlx::Error>;
+
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Account>, sqlx::Error>;
+
+    /// Finds up to `limit` accounts ordered by code and id, starting after `after`
+    async fn find_after_cursor(
+        &self,
+        after: Option<&AccountCursor>,
+        limit: i64,
+    ) -> Result<Vec<Account>, sqlx::Error>;
+
+    /// Finds inactive accounts that still carry a non-zero balance
+    async fn find_inactive_nonzero(&self) -> Result<Vec<Account>, sqlx::Error>;
+
+    /// Checks whether making `parent_id` the parent of `account_id` would create a cycle
+    async fn would_create_cycle(
+        &self,
+        account_id: Uuid,
+        parent_id: Uuid,
+    ) -> Result<bool, sqlx::Error>;
+
+    /// Creates an account, recording `user_id` as its creator
+    async fn create(
+        &self,
+        new_account: NewAccount,
+        user_id: Option<Uuid>,
+    ) -> crate::Result<Account>;
+
+    /// Saves an account if the stored version still matches `account.version`
+    async fn update(&self, account: &mut Account, user_id: Option<Uuid>) -> crate::Result<()>;
+
+    /// Soft-deletes an account
+    async fn delete(&self, id: Uuid) -> crate::Result<()>;
+}
+
+#[async_trait]
+impl AccountStore for AccountRepository<'_> {
+    async fn find_all(&self) -> Result<Vec<Account>, sqlx::Error> {
+        AccountRepository::find_all(self).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Account>, sqlx::Error> {
+        AccountRepository::find_by_id(self, id).await
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<Account>, sqlx::Error> {
+        AccountRepository::find_by_code(self, code).await
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Account>, sqlx::Error> {
+        AccountRepository::find_children(self, parent_id).await
+    }
+
+    async fn find_after_cursor(
+        &self,
+        after: Option<&AccountCursor>,
+        limit: i64,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        AccountRepository::find_after_cursor(self, after, limit).await
+    }
+
+    async fn find_inactive_nonzero(&self) -> Result<Vec<Account>, sqlx::Error> {
+        AccountRepository::find_inactive_nonzero(self).await
+    }
+
+    async fn would_create_cycle(
+        &self,
+        account_id: Uuid,
+        parent_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        AccountRepository::would_create_cycle(self, account_id, parent_id).await
+    }
+
+    async fn create(
+        &self,
+        new_account: NewAccount,
+        user_id: Option<Uuid>,
+    ) -> crate::Result<Account> {
+        AccountRepository::create(self, new_account, user_id).await
+    }
+
+    async fn update(&self, account: &mut Account, user_id: Option<Uuid>) -> crate::Result<()> {
+        AccountRepository::update(self, account, user_id).await
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<()> {
+        AccountRepository::delete(self, id).await
+    }
+}
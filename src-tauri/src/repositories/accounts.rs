@@ -1,7 +1,42 @@
-use crate::models::account::{Account, AccountDto, NewAccount};
-use sqlx::postgres::PgPool;
+use crate::error::{not_found, Error};
+use crate::models::account::{
+    Account, AccountActivityStats, AccountCategory, AccountCursor, AccountDeleteImpact, AccountDto,
+    AccountMatchKind, AccountSortField, AccountType, ActivityStatsSortField, BalanceCorrection,
+    NewAccount, SortDirection,
+};
+use crate::models::activity::NewActivity;
+use crate::models::journal::{JournalEntry, NewJournalEntry, NewJournalLine};
+use crate::money;
+use crate::repositories::activity::ActivityRepository;
+use crate::repositories::code_history::CodeHistoryRepository;
+use crate::repositories::journal::JournalRepository;
+use crate::repositories::periods::PeriodRepository;
+use crate::repositories::retry;
+use crate::repositories::snapshots::SnapshotRepository;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgArguments, PgConnection, PgPool};
+use sqlx::query::Query;
+use sqlx::Postgres;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+/// An account row together with the summed balance of its whole subtree
+#[derive(sqlx::FromRow)]
+struct RolledUpAccountRow {
+    #[sqlx(flatten)]
+    account: AccountDto,
+    rolled_up_balance: Decimal,
+}
+
+/// An account row together with how strongly it matched a search
+#[derive(sqlx::FromRow)]
+struct SearchMatchRow {
+    #[sqlx(flatten)]
+    account: AccountDto,
+    match_rank: i32,
+}
+
 pub struct AccountRepository<'a> {
     pool: &'a PgPool,
 }
@@ -11,43 +46,397 @@ impl<'a> AccountRepository<'a> {
         Self { pool }
     }
 
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn find_all(&self) -> Result<Vec<Account>, sqlx::Error> {
-        let dtos = sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts ORDER BY code")
+        let dtos = retry::read(|| {
+            sqlx::query_as::<_, AccountDto>(
+                "SELECT * FROM accounts WHERE deleted_at IS NULL ORDER BY code",
+            )
             .fetch_all(self.pool)
-            .await?;
+        })
+        .await?;
 
         Ok(dtos.into_iter().map(Account::from).collect())
     }
 
+    /// Finds accounts changed after `since`, oldest change first. Soft-deleted
+    /// accounts are included so that a client cache can evict them.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_modified_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            "SELECT * FROM accounts WHERE updated_at > $1 ORDER BY updated_at, id",
+        )
+        .bind(since)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_paginated(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = retry::read(|| {
+            sqlx::query_as::<_, AccountDto>(
+                "SELECT * FROM accounts WHERE deleted_at IS NULL ORDER BY code LIMIT $1 OFFSET $2",
+            )
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(self.pool)
+        })
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn count(&self) -> Result<i64, sqlx::Error> {
+        retry::read(|| {
+            sqlx::query_scalar("SELECT COUNT(*) FROM accounts WHERE deleted_at IS NULL")
+                .fetch_one(self.pool)
+        })
+        .await
+    }
+
+    /// Case-insensitive substring match on code or name, most relevant first:
+    /// an exact code, then codes starting with the query, then names starting
+    /// with it, then any other match, each tier ordered by code. An empty query
+    /// returns all accounts without a match kind.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn search(
+        &self,
+        query: &str,
+    ) -> Result<Vec<(Account, Option<AccountMatchKind>)>, sqlx::Error> {
+        let query = query.trim();
+        if query.is_empty() {
+            let accounts = self.find_all().await?;
+            return Ok(accounts
+                .into_iter()
+                .map(|account| (account, None))
+                .collect());
+        }
+
+        // Match the query literally rather than as a LIKE pattern
+        let escaped = query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+
+        let rows = sqlx::query_as::<_, SearchMatchRow>(
+            r#"
+            SELECT *,
+                CASE
+                    WHEN LOWER(code) = LOWER($1) THEN 0
+                    WHEN code ILIKE $2 THEN 1
+                    WHEN name ILIKE $2 THEN 2
+                    ELSE 3
+                END AS match_rank
+            FROM accounts
+            WHERE deleted_at IS NULL AND (code ILIKE $3 OR name ILIKE $3)
+            ORDER BY match_rank, code
+            "#,
+        )
+        .bind(query)
+        .bind(format!("{}%", escaped))
+        .bind(format!("%{}%", escaped))
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let kind = AccountMatchKind::from_rank(row.match_rank);
+                (Account::from(row.account), Some(kind))
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn find_by_id(&self, id: Uuid) -> Result<Option<Account>, sqlx::Error> {
-        let dto = sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts WHERE id = $1")
+        let dto = retry::read(|| {
+            sqlx::query_as::<_, AccountDto>(
+                "SELECT * FROM accounts WHERE id = $1 AND deleted_at IS NULL",
+            )
             .bind(id)
             .fetch_optional(self.pool)
-            .await?;
+        })
+        .await?;
 
         Ok(dto.map(Account::from))
     }
 
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = retry::read(|| {
+            sqlx::query_as::<_, AccountDto>(
+                "SELECT * FROM accounts WHERE id = ANY($1) AND deleted_at IS NULL ORDER BY code",
+            )
+            .bind(ids)
+            .fetch_all(self.pool)
+        })
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn find_by_code(&self, code: &str) -> Result<Option<Account>, sqlx::Error> {
-        let dto = sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts WHERE code = $1")
-            .bind(code)
-            .fetch_optional(self.pool)
+        let dto = retry::read(|| {
+            sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts WHERE code = $1")
+                .bind(code)
+                .fetch_optional(self.pool)
+        })
+        .await?;
+
+        Ok(dto.map(Account::from))
+    }
+
+    /// Creates an account, recording `user_id` as its creator
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn create(
+        &self,
+        new_account: NewAccount,
+        user_id: Option<Uuid>,
+    ) -> crate::Result<Account> {
+        if self.find_by_code(&new_account.code).await?.is_some() {
+            return Err(Error::Conflict(format!(
+                "Account code '{}' is already in use",
+                new_account.code
+            )));
+        }
+
+        let mut account = Account::new(new_account);
+        account.created_by = user_id;
+        account.updated_by = user_id;
+
+        Self::insert_query(AccountDto::from(account.clone()))
+            .execute(self.pool)
             .await?;
 
+        Ok(account)
+    }
+
+    /// Finds a live account of the given type by its exact name and currency
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_name(
+        &self,
+        account_type: AccountType,
+        name: &str,
+        currency: &str,
+    ) -> Result<Option<Account>, sqlx::Error> {
+        let dto = sqlx::query_as::<_, AccountDto>(
+            r#"
+            SELECT * FROM accounts
+            WHERE account_type = $1 AND name = $2 AND currency = $3 AND deleted_at IS NULL
+            ORDER BY code
+            LIMIT 1
+            "#,
+        )
+        .bind(account_type.to_string())
+        .bind(name)
+        .bind(currency)
+        .fetch_optional(self.pool)
+        .await?;
+
         Ok(dto.map(Account::from))
     }
 
-    pub async fn create(&self, new_account: NewAccount) -> Result<Account, sqlx::Error> {
-        let account = Account::new(new_account);
-        let dto = AccountDto::from(account.clone());
+    /// Creates an account together with a posted journal entry that gives it
+    /// its opening balance, offset against `equity_account_id`. A positive
+    /// `amount` lands on the account's normal side. An equity account passed
+    /// as `new_equity` is inserted first. Either all are created or none is.
+    #[tracing::instrument(level = "debug", skip(self, new_equity), err)]
+    pub async fn create_with_opening_balance(
+        &self,
+        new_account: NewAccount,
+        user_id: Option<Uuid>,
+        equity_account_id: Uuid,
+        new_equity: Option<&Account>,
+        amount: Decimal,
+        entry_date: NaiveDate,
+    ) -> crate::Result<(Account, JournalEntry)> {
+        if self.find_by_code(&new_account.code).await?.is_some() {
+            return Err(Error::Conflict(format!(
+                "Account code '{}' is already in use",
+                new_account.code
+            )));
+        }
+
+        let mut account = Account::new(new_account);
+        account.created_by = user_id;
+        account.updated_by = user_id;
+
+        // Debit-normal accounts are debited, credit-normal ones credited; a
+        // negative amount goes on the other side
+        let amount = money::round(amount);
+        let (debit, credit) = if account.is_debit_normal() == amount.is_sign_positive() {
+            (amount.abs(), Decimal::ZERO)
+        } else {
+            (Decimal::ZERO, amount.abs())
+        };
+
+        let entry = NewJournalEntry {
+            entry_date,
+            description: format!("Opening balance for {} {}", account.code, account.name),
+            reference: None,
+            exchange_rate: None,
+            lines: vec![
+                NewJournalLine {
+                    account_id: account.id,
+                    description: None,
+                    debit,
+                    credit,
+                },
+                NewJournalLine {
+                    account_id: equity_account_id,
+                    description: None,
+                    debit: credit,
+                    credit: debit,
+                },
+            ],
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        if let Some(equity) = new_equity {
+            Self::insert_query(AccountDto::from(equity.clone()))
+                .execute(&mut *tx)
+                .await?;
+        }
+        Self::insert_query(AccountDto::from(account.clone()))
+            .execute(&mut *tx)
+            .await?;
+        let entry = JournalRepository::create_in_tx(&mut tx, &entry, true).await?;
+
+        // Posting has moved the balance off the zero it was inserted with
+        let dto = sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts WHERE id = $1")
+            .bind(account.id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok((Account::from(dto), entry))
+    }
 
+    /// Creates a batch of accounts in a single transaction. Codes must be unique
+    /// within the batch and against existing rows; nothing is inserted otherwise.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn create_many(
+        &self,
+        new_accounts: Vec<NewAccount>,
+        user_id: Option<Uuid>,
+    ) -> crate::Result<Vec<Account>> {
+        let codes: Vec<String> = new_accounts.iter().map(|a| a.code.clone()).collect();
+        self.check_codes_available(&codes).await?;
+
+        let accounts: Vec<Account> = new_accounts
+            .into_iter()
+            .map(|new_account| {
+                let mut account = Account::new(new_account);
+                account.created_by = user_id;
+                account.updated_by = user_id;
+                account
+            })
+            .collect();
+
+        self.insert_many(&accounts).await?;
+
+        Ok(accounts)
+    }
+
+    /// Checks that a batch of codes is free to use: each appears once in the
+    /// batch and none is held by an existing account, deleted ones included
+    #[tracing::instrument(level = "debug", skip(self, codes), err)]
+    pub async fn check_codes_available(&self, codes: &[String]) -> crate::Result<()> {
+        let mut seen = HashSet::new();
+        for code in codes {
+            if !seen.insert(code.as_str()) {
+                return Err(Error::Conflict(format!(
+                    "Account code '{}' appears more than once in the batch",
+                    code
+                )));
+            }
+        }
+
+        let taken: Option<String> = sqlx::query_scalar(
+            "SELECT code FROM accounts WHERE code = ANY($1) ORDER BY code LIMIT 1",
+        )
+        .bind(codes)
+        .fetch_optional(self.pool)
+        .await?;
+
+        if let Some(code) = taken {
+            return Err(Error::Conflict(format!(
+                "Account code '{}' is already in use",
+                code
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Inserts several fully-built accounts in a single transaction
+    #[tracing::instrument(level = "debug", skip(self, accounts), err)]
+    pub async fn insert_many(&self, accounts: &[Account]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        for account in accounts {
+            Self::insert_query(AccountDto::from(account.clone()))
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Inserts accounts only if the table has never held one, counting deleted
+    /// accounts too. The table is locked so a concurrent insert cannot slip in
+    /// between the check and the insert.
+    #[tracing::instrument(level = "debug", skip(self, accounts), err)]
+    pub async fn insert_into_empty(&self, accounts: &[Account]) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("LOCK TABLE accounts IN EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await?;
+
+        let has_accounts: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM accounts)")
+            .fetch_one(&mut *tx)
+            .await?;
+        if has_accounts {
+            return Err(Error::Conflict(
+                "The chart of accounts is not empty".to_string(),
+            ));
+        }
+
+        for account in accounts {
+            Self::insert_query(AccountDto::from(account.clone()))
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    fn insert_query(dto: AccountDto) -> Query<'static, Postgres, PgArguments> {
         sqlx::query(
             r#"
             INSERT INTO accounts
                 (id, code, name, description, account_type, category, subcategory, 
-                is_active, parent_id, balance, created_at, updated_at)
+                is_active, parent_id, balance, currency, version, created_at, updated_at, created_by, updated_by)
             VALUES
-                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             "#,
         )
         .bind(dto.id)
@@ -60,18 +449,41 @@ impl<'a> AccountRepository<'a> {
         .bind(dto.is_active)
         .bind(dto.parent_id)
         .bind(dto.balance)
+        .bind(dto.currency)
+        .bind(dto.version)
         .bind(dto.created_at)
         .bind(dto.updated_at)
-        .execute(self.pool)
-        .await?;
-
-        Ok(account)
+        .bind(dto.created_by)
+        .bind(dto.updated_by)
     }
 
-    pub async fn update(&self, account: &Account) -> Result<(), sqlx::Error> {
+    /// Saves an account, stamping `user_id` as its last editor. The save only
+    /// applies if the stored version still matches `account.version`. A changed
+    /// code is recorded in the code history.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn update(&self, account: &mut Account, user_id: Option<Uuid>) -> crate::Result<()> {
+        if let Some(existing) = self.find_by_code(&account.code).await? {
+            if existing.id != account.id {
+                return Err(Error::Conflict(format!(
+                    "Account code '{}' is already in use",
+                    account.code
+                )));
+            }
+        }
+
+        account.updated_by = user_id;
         let dto = AccountDto::from(account.clone());
 
-        sqlx::query(
+        let mut tx = self.pool.begin().await?;
+
+        // The stored code, locked so the history records what was replaced
+        let old_code: Option<String> =
+            sqlx::query_scalar("SELECT code FROM accounts WHERE id = $1 FOR UPDATE")
+                .bind(account.id)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+        let result = sqlx::query(
             r#"
             UPDATE accounts
             SET 
@@ -84,8 +496,11 @@ impl<'a> AccountRepository<'a> {
                 is_active = $8,
                 parent_id = $9,
                 balance = $10,
-                updated_at = $11
-            WHERE id = $1
+                currency = $11,
+                updated_at = $12,
+                updated_by = $13,
+                version = version + 1
+            WHERE id = $1 AND version = $14
             "#,
         )
         .bind(dto.id)
@@ -98,25 +513,228 @@ impl<'a> AccountRepository<'a> {
         .bind(dto.is_active)
         .bind(dto.parent_id)
         .bind(dto.balance)
+        .bind(dto.currency)
         .bind(dto.updated_at)
-        .execute(self.pool)
+        .bind(dto.updated_by)
+        .bind(dto.version)
+        .execute(&mut *tx)
         .await?;
 
+        // Someone else saved the account since this copy was loaded
+        if result.rows_affected() == 0 {
+            return Err(Error::Conflict(
+                "Account was modified by someone else".to_string(),
+            ));
+        }
+
+        // Record renumbering so references to the old code can still be resolved
+        let replaced_code = old_code.filter(|old_code| *old_code != account.code);
+        if let Some(old_code) = &replaced_code {
+            CodeHistoryRepository::record_in_tx(
+                &mut tx,
+                account.id,
+                old_code,
+                &account.code,
+                user_id,
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        if let Some(old_code) = replaced_code {
+            tracing::info!(
+                account_id = %account.id,
+                old_code = %old_code,
+                new_code = %account.code,
+                "Account code changed"
+            );
+        }
+
+        account.version += 1;
+
         Ok(())
     }
 
-    pub async fn delete(&self, id: Uuid) -> Result<(), sqlx::Error> {
-        sqlx::query("DELETE FROM accounts WHERE id = $1")
-            .bind(id)
-            .execute(self.pool)
+    /// Saves the category and subcategory of every given account, together
+    /// with `activities`, in a single transaction. Nothing is saved if any
+    /// category does not fit the stored type of its account.
+    #[tracing::instrument(level = "debug", skip(self, accounts, activities), err)]
+    pub async fn update_category(
+        &self,
+        accounts: &[Account],
+        user_id: Option<Uuid>,
+        activities: &[NewActivity],
+    ) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        for account in accounts {
+            let account_type: AccountType =
+                sqlx::query_scalar("SELECT account_type FROM accounts WHERE id = $1 FOR UPDATE")
+                    .bind(account.id)
+                    .fetch_optional(&mut *tx)
+                    .await?
+                    .ok_or_else(|| not_found("Account"))?;
+            account.category.check_valid_for(account_type)?;
+
+            sqlx::query(
+                r#"
+                UPDATE accounts
+                SET category = $2, subcategory = $3, updated_at = NOW(), updated_by = $4,
+                    version = version + 1
+                WHERE id = $1
+                "#,
+            )
+            .bind(account.id)
+            .bind(account.category.to_string())
+            .bind(&account.subcategory)
+            .bind(user_id)
+            .execute(&mut *tx)
             .await?;
+        }
+
+        for activity in activities {
+            ActivityRepository::record_in_tx(&mut tx, activity).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    /// Sets `is_active` on every given live account in a single statement.
+    /// Accounts already in that state are left alone; returns the ids of the
+    /// accounts that changed.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn set_active(
+        &self,
+        ids: &[Uuid],
+        active: bool,
+        user_id: Option<Uuid>,
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            UPDATE accounts
+            SET is_active = $2, updated_at = NOW(), updated_by = $3, version = version + 1
+            WHERE id = ANY($1) AND deleted_at IS NULL AND is_active <> $2
+            RETURNING id
+            "#,
+        )
+        .bind(ids)
+        .bind(active)
+        .bind(user_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Counts the children, journal lines and notes that reference an account,
+    /// or `None` when the account does not exist or is already deleted
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn delete_impact(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<AccountDeleteImpact>, sqlx::Error> {
+        sqlx::query_as::<_, AccountDeleteImpact>(
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM accounts c
+                 WHERE c.parent_id = a.id AND c.deleted_at IS NULL) AS children,
+                (SELECT COUNT(*) FROM journal_lines l
+                 JOIN journal_entries e ON e.id = l.entry_id
+                 WHERE l.account_id = a.id AND e.is_posted) AS posted_lines,
+                (SELECT COUNT(*) FROM journal_lines l
+                 JOIN journal_entries e ON e.id = l.entry_id
+                 WHERE l.account_id = a.id AND NOT e.is_posted) AS unposted_lines,
+                a.note_count::BIGINT AS notes
+            FROM accounts a
+            WHERE a.id = $1 AND a.deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    /// Soft-deletes an account by stamping `deleted_at`. Accounts with posted journal
+    /// lines or with children that are still present cannot be deleted.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn delete(&self, id: Uuid) -> crate::Result<()> {
+        let impact = self
+            .delete_impact(id)
+            .await?
+            .ok_or_else(|| not_found("Account"))?;
+
+        if let Some(reason) = impact.blocking_reasons().into_iter().next() {
+            return Err(Error::Conflict(reason));
+        }
+
+        let result = sqlx::query(
+            r#"
+            UPDATE accounts
+            SET deleted_at = NOW(), updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .execute(self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(not_found("Account"));
+        }
 
         Ok(())
     }
 
+    /// Clears `deleted_at` on a soft-deleted account
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn restore(&self, id: Uuid) -> crate::Result<Account> {
+        let parent_deleted: Option<bool> = sqlx::query_scalar(
+            r#"
+            SELECT p.deleted_at IS NOT NULL
+            FROM accounts a
+            JOIN accounts p ON p.id = a.parent_id
+            WHERE a.id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.pool)
+        .await?;
+
+        if parent_deleted == Some(true) {
+            return Err(Error::Conflict(
+                "The parent account is deleted; restore it first".to_string(),
+            ));
+        }
+
+        let dto = sqlx::query_as::<_, AccountDto>(
+            r#"
+            UPDATE accounts
+            SET deleted_at = NULL, updated_at = NOW()
+            WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.pool)
+        .await?
+        .ok_or_else(|| not_found("Deleted account"))?;
+
+        Ok(Account::from(dto))
+    }
+
+    /// Lists every account code in use, including those of deleted accounts
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_all_codes(&self) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT code FROM accounts ORDER BY code")
+            .fetch_all(self.pool)
+            .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Account>, sqlx::Error> {
         let dtos = sqlx::query_as::<_, AccountDto>(
-            "SELECT * FROM accounts WHERE parent_id = $1 ORDER BY code",
+            "SELECT * FROM accounts WHERE parent_id = $1 AND deleted_at IS NULL ORDER BY code",
         )
         .bind(parent_id)
         .fetch_all(self.pool)
@@ -125,9 +743,10 @@ impl<'a> AccountRepository<'a> {
         Ok(dtos.into_iter().map(Account::from).collect())
     }
 
+    #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn find_roots(&self) -> Result<Vec<Account>, sqlx::Error> {
         let dtos = sqlx::query_as::<_, AccountDto>(
-            "SELECT * FROM accounts WHERE parent_id IS NULL ORDER BY code",
+            "SELECT * FROM accounts WHERE parent_id IS NULL AND deleted_at IS NULL ORDER BY code",
         )
         .fetch_all(self.pool)
         .await?;
@@ -135,23 +754,484 @@ impl<'a> AccountRepository<'a> {
         Ok(dtos.into_iter().map(Account::from).collect())
     }
 
-    pub async fn update_balance(
+    /// Finds all accounts in the given order, with the code breaking ties
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_sorted(
         &self,
-        id: Uuid,
-        amount: rust_decimal::Decimal,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query(
+        field: AccountSortField,
+        direction: SortDirection,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        // Both parts come from fixed whitelists, never from user text
+        let query = format!(
+            "SELECT * FROM accounts WHERE deleted_at IS NULL ORDER BY {} {}, code",
+            field.column(),
+            direction.keyword()
+        );
+
+        let dtos = sqlx::query_as::<_, AccountDto>(&query)
+            .fetch_all(self.pool)
+            .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Counts the posted journal lines of every live account and finds the
+    /// date of its latest one. Accounts never posted to are included with a
+    /// count of zero; they sort as the least recently active.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn activity_stats(
+        &self,
+        field: ActivityStatsSortField,
+        direction: SortDirection,
+    ) -> Result<Vec<AccountActivityStats>, sqlx::Error> {
+        let nulls = match direction {
+            SortDirection::Asc => "NULLS FIRST",
+            SortDirection::Desc => "NULLS LAST",
+        };
+
+        // All parts of the ORDER BY come from fixed whitelists, never from user text
+        let query = format!(
             r#"
-            UPDATE accounts
-            SET balance = balance + $2, updated_at = NOW()
-            WHERE id = $1
+            SELECT a.id AS account_id, a.code, a.name, a.is_active, a.balance,
+                COUNT(l.id) AS line_count, MAX(e.entry_date) AS last_activity
+            FROM accounts a
+            LEFT JOIN (
+                journal_lines l
+                JOIN journal_entries e ON e.id = l.entry_id AND e.is_posted
+            ) ON l.account_id = a.id
+            WHERE a.deleted_at IS NULL
+            GROUP BY a.id
+            ORDER BY {} {} {}, a.code
+            "#,
+            field.column(),
+            direction.keyword(),
+            nulls
+        );
+
+        sqlx::query_as::<_, AccountActivityStats>(&query)
+            .fetch_all(self.pool)
+            .await
+    }
+
+    /// Finds the accounts of one type
+    pub async fn find_by_type(
+        &self,
+        account_type: AccountType,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        self.find_filtered(Some(account_type), None).await
+    }
+
+    /// Finds the accounts in one category
+    pub async fn find_by_category(
+        &self,
+        category: AccountCategory,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        self.find_filtered(None, Some(category)).await
+    }
+
+    /// Finds accounts matching every given filter; no filters returns all accounts
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_filtered(
+        &self,
+        account_type: Option<AccountType>,
+        category: Option<AccountCategory>,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            r#"
+            SELECT * FROM accounts
+            WHERE deleted_at IS NULL
+                AND ($1::TEXT IS NULL OR account_type = $1)
+                AND ($2::TEXT IS NULL OR category = $2)
+            ORDER BY code
+            "#,
+        )
+        .bind(account_type.map(|t| t.to_string()))
+        .bind(category.map(|c| c.to_string()))
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Sums the balances of all accounts of a type
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_balance_by_type(
+        &self,
+        account_type: AccountType,
+    ) -> Result<Decimal, sqlx::Error> {
+        let total: Decimal = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(balance), 0)
+            FROM accounts
+            WHERE account_type = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(account_type.to_string())
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(total)
+    }
+
+    /// Sums the balances of accounts in a category, optionally restricted to a
+    /// subcategory. With `as_of` the balances are rebuilt from the posted lines
+    /// dated on or before that day instead of read from the current balances.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_balance_by_category(
+        &self,
+        category: AccountCategory,
+        subcategory: Option<&str>,
+        as_of: Option<NaiveDate>,
+    ) -> Result<Decimal, sqlx::Error> {
+        let total: Decimal = match as_of {
+            None => {
+                sqlx::query_scalar(
+                    r#"
+                    SELECT COALESCE(SUM(balance), 0)
+                    FROM accounts
+                    WHERE category = $1
+                        AND deleted_at IS NULL
+                        AND ($2::TEXT IS NULL OR LOWER(subcategory) = LOWER($2))
+                    "#,
+                )
+                .bind(category.to_string())
+                .bind(subcategory)
+                .fetch_one(self.pool)
+                .await?
+            }
+            Some(as_of) => {
+                sqlx::query_scalar(
+                    r#"
+                    SELECT COALESCE(SUM(
+                        CASE WHEN a.account_type IN ('ASSET', 'EXPENSE')
+                            THEN l.debit - l.credit
+                            ELSE l.credit - l.debit
+                        END
+                    ), 0)
+                    FROM journal_lines l
+                    JOIN journal_entries e ON e.id = l.entry_id
+                    JOIN accounts a ON a.id = l.account_id
+                    WHERE e.is_posted AND e.entry_date <= $3
+                        AND a.category = $1
+                        AND a.deleted_at IS NULL
+                        AND ($2::TEXT IS NULL OR LOWER(a.subcategory) = LOWER($2))
+                    "#,
+                )
+                .bind(category.to_string())
+                .bind(subcategory)
+                .bind(as_of)
+                .fetch_one(self.pool)
+                .await?
+            }
+        };
+
+        Ok(total)
+    }
+
+    /// Finds inactive accounts that still carry a non-zero balance
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_inactive_nonzero(&self) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            "SELECT * FROM accounts WHERE is_active = FALSE AND balance <> 0 AND deleted_at IS NULL ORDER BY code",
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Finds up to `limit` accounts ordered by code, starting after `after_code`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_after_code(
+        &self,
+        after_code: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            "SELECT * FROM accounts WHERE deleted_at IS NULL AND ($1::TEXT IS NULL OR code > $1) ORDER BY code LIMIT $2",
+        )
+        .bind(after_code)
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Finds up to `limit` accounts ordered by code and id, starting after `after`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_after_cursor(
+        &self,
+        after: Option<&AccountCursor>,
+        limit: i64,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            r#"
+            SELECT * FROM accounts
+            WHERE deleted_at IS NULL AND ($1::TEXT IS NULL OR (code, id) > ($1, $2::UUID))
+            ORDER BY code, id
+            LIMIT $3
+            "#,
+        )
+        .bind(after.map(|cursor| cursor.code.as_str()))
+        .bind(after.map(|cursor| cursor.id))
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Checks whether making `parent_id` the parent of `account_id` would create a cycle,
+    /// by walking up the parent chain from the proposed parent
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn would_create_cycle(
+        &self,
+        account_id: Uuid,
+        parent_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let cycle: bool = sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT id, parent_id FROM accounts WHERE id = $2
+                UNION
+                SELECT a.id, a.parent_id FROM accounts a JOIN ancestors an ON a.id = an.parent_id
+            )
+            SELECT EXISTS (SELECT 1 FROM ancestors WHERE id = $1)
+            "#,
+        )
+        .bind(account_id)
+        .bind(parent_id)
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(cycle)
+    }
+
+    /// Finds the chain of accounts from the root down to `account_id`, or an
+    /// empty list when the account does not exist
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_path(&self, account_id: Uuid) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            r#"
+            WITH RECURSIVE path AS (
+                SELECT a.*, 0 AS distance FROM accounts a
+                WHERE a.id = $1 AND a.deleted_at IS NULL
+                UNION ALL
+                SELECT a.*, p.distance + 1 FROM accounts a JOIN path p ON a.id = p.parent_id
+            )
+            SELECT * FROM path ORDER BY distance DESC
+            "#,
+        )
+        .bind(account_id)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Fetches every account reachable from a root account in a single recursive query
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_tree(&self) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT * FROM accounts WHERE parent_id IS NULL AND deleted_at IS NULL
+                UNION ALL
+                SELECT a.* FROM accounts a JOIN tree t ON a.parent_id = t.id
+                WHERE a.deleted_at IS NULL
+            )
+            SELECT * FROM tree ORDER BY code
+            "#,
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Finds the live accounts below `id` at any depth, ordered by code. Empty
+    /// for a leaf account.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_descendants(&self, id: Uuid) -> Result<Vec<Account>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, AccountDto>(
+            r#"
+            WITH RECURSIVE descendants AS (
+                SELECT id FROM accounts WHERE parent_id = $1 AND deleted_at IS NULL
+                UNION
+                SELECT a.id FROM accounts a JOIN descendants d ON a.parent_id = d.id
+                WHERE a.deleted_at IS NULL
+            )
+            SELECT a.* FROM accounts a
+            JOIN descendants d ON d.id = a.id
+            WHERE a.id <> $1
+            ORDER BY a.code
             "#,
         )
         .bind(id)
-        .bind(amount)
-        .execute(self.pool)
+        .fetch_all(self.pool)
         .await?;
 
+        Ok(dtos.into_iter().map(Account::from).collect())
+    }
+
+    /// Fetches all accounts with `balance` replaced by the account's own balance
+    /// plus the balances of all of its descendants
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_all_with_rolled_up_balances(&self) -> Result<Vec<Account>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, RolledUpAccountRow>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id AS root_id, id, balance FROM accounts WHERE deleted_at IS NULL
+                UNION
+                SELECT s.root_id, a.id, a.balance
+                FROM accounts a JOIN subtree s ON a.parent_id = s.id
+                WHERE a.deleted_at IS NULL
+            )
+            SELECT a.*, totals.rolled_up_balance
+            FROM accounts a
+            JOIN (
+                SELECT root_id, SUM(balance) AS rolled_up_balance
+                FROM subtree
+                GROUP BY root_id
+            ) totals ON totals.root_id = a.id
+            ORDER BY a.code
+            "#,
+        )
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let mut account = Account::from(row.account);
+                account.balance = row.rolled_up_balance;
+                account
+            })
+            .collect())
+    }
+
+    /// Adjusts an account balance directly; the adjustment is dated today, so it
+    /// is refused while today's period is closed
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn update_balance(&self, id: Uuid, amount: Decimal) -> crate::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        PeriodRepository::ensure_open_in_tx(&mut tx, Utc::now().date_naive()).await?;
+        Self::update_balance_in_tx(&mut tx, id, None, amount).await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
+
+    /// Recalculates every account balance from its posted journal lines and
+    /// stores the result where it differs, returning the corrections by code.
+    /// Each entry's lines are netted and rounded per account as posting does,
+    /// so accounts that never drifted come out unchanged. The accounts are
+    /// locked in id order, the order postings lock them in, so a posting in
+    /// flight either finishes first or waits for the recalculation.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn recompute_balances(&self) -> crate::Result<Vec<BalanceCorrection>> {
+        let mut tx = self.pool.begin().await?;
+
+        let accounts: Vec<(Uuid, String, String, String, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT id, code, name, account_type, balance
+            FROM accounts
+            ORDER BY id
+            FOR NO KEY UPDATE
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let entry_totals: Vec<(Uuid, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT l.account_id, SUM(l.debit), SUM(l.credit)
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            WHERE e.is_posted
+            GROUP BY l.account_id, l.entry_id
+            "#,
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut totals: HashMap<Uuid, Vec<(Decimal, Decimal)>> = HashMap::new();
+        for (account_id, debit, credit) in entry_totals {
+            totals.entry(account_id).or_default().push((debit, credit));
+        }
+
+        let mut corrections = Vec::new();
+        for (id, code, name, account_type, balance) in accounts {
+            let account_type = AccountType::from_str(&account_type).unwrap_or(AccountType::Asset);
+            let expected: Decimal = totals
+                .get(&id)
+                .into_iter()
+                .flatten()
+                .map(|&(debit, credit)| money::round(account_type.balance_delta(debit, credit)))
+                .sum();
+            if expected == balance {
+                continue;
+            }
+
+            sqlx::query("UPDATE accounts SET balance = $2, updated_at = NOW() WHERE id = $1")
+                .bind(id)
+                .bind(expected)
+                .execute(&mut *tx)
+                .await?;
+            SnapshotRepository::record_in_tx(&mut tx, id, None, expected).await?;
+
+            corrections.push(BalanceCorrection {
+                account_id: id,
+                code,
+                name,
+                old_balance: balance,
+                new_balance: expected,
+            });
+        }
+
+        tx.commit().await?;
+
+        corrections.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(corrections)
+    }
+
+    /// Adds `amount` to an account balance inside the caller's transaction and
+    /// snapshots the result against `entry_id`. The account row is locked
+    /// first, so concurrent updates of one account queue up behind each other
+    /// until the holder commits. `NO KEY UPDATE` is the lock a plain balance
+    /// update takes; unlike `FOR UPDATE` it does not wait on the key-share locks
+    /// other transactions hold while inserting journal lines for the account,
+    /// which would deadlock concurrent postings. Returns the new balance, or
+    /// `None` if the account does not exist.
+    #[tracing::instrument(level = "debug", skip(conn), err)]
+    pub(crate) async fn update_balance_in_tx(
+        conn: &mut PgConnection,
+        id: Uuid,
+        entry_id: Option<Uuid>,
+        amount: Decimal,
+    ) -> Result<Option<Decimal>, sqlx::Error> {
+        let current: Option<Decimal> =
+            sqlx::query_scalar("SELECT balance FROM accounts WHERE id = $1 FOR NO KEY UPDATE")
+                .bind(id)
+                .fetch_optional(&mut *conn)
+                .await?;
+
+        let Some(current) = current else {
+            return Ok(None);
+        };
+
+        let balance = current + money::round(amount);
+        sqlx::query("UPDATE accounts SET balance = $2, updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .bind(balance)
+            .execute(&mut *conn)
+            .await?;
+
+        SnapshotRepository::record_in_tx(&mut *conn, id, entry_id, balance).await?;
+
+        Ok(Some(balance))
+    }
 }
@@ -0,0 +1,123 @@
+use crate::models::activity::{ActivityFilter, ActivityLog, ActivityLogDto, NewActivity};
+use chrono::Utc;
+use sqlx::postgres::{PgConnection, PgPool};
+use sqlx::types::Json;
+use uuid::Uuid;
+
+pub struct ActivityRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> ActivityRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Appends an entry to the activity log
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn record(&self, activity: NewActivity) -> Result<(), sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        Self::record_in_tx(&mut conn, &activity).await
+    }
+
+    /// Appends an entry inside the caller's transaction, so it is only kept
+    /// if the change it describes is committed
+    pub(crate) async fn record_in_tx(
+        conn: &mut PgConnection,
+        activity: &NewActivity,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO activity_log
+                (id, action, entity_type, entity_id, description, user_id, created_at, details)
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(activity.action.to_string())
+        .bind(activity.action.entity_type())
+        .bind(activity.entity_id)
+        .bind(&activity.description)
+        .bind(activity.user_id)
+        .bind(Utc::now())
+        .bind((!activity.details.is_empty()).then_some(Json(&activity.details)))
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Finds the most recent activity, newest first
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_recent(&self, limit: i64) -> Result<Vec<ActivityLog>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, ActivityLogDto>(
+            r#"
+            SELECT a.*, u.username
+            FROM activity_log a
+            LEFT JOIN users u ON u.id = a.user_id
+            ORDER BY a.created_at DESC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(ActivityLog::from).collect())
+    }
+
+    /// Finds one page of activity matching `filter`, newest first
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_filtered(
+        &self,
+        filter: &ActivityFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<ActivityLog>, sqlx::Error> {
+        let dtos = sqlx::query_as::<_, ActivityLogDto>(
+            r#"
+            SELECT a.*, u.username
+            FROM activity_log a
+            LEFT JOIN users u ON u.id = a.user_id
+            WHERE ($1::TEXT IS NULL OR a.entity_type = $1)
+              AND ($2::UUID IS NULL OR a.user_id = $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR a.created_at >= $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR a.created_at < $4)
+            ORDER BY a.created_at DESC, a.id DESC
+            LIMIT $5 OFFSET $6
+            "#,
+        )
+        .bind(filter.entity_type.as_deref())
+        .bind(filter.user_id)
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(self.pool)
+        .await?;
+
+        Ok(dtos.into_iter().map(ActivityLog::from).collect())
+    }
+
+    /// Counts the activity matching `filter`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn count_filtered(&self, filter: &ActivityFilter) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM activity_log a
+            WHERE ($1::TEXT IS NULL OR a.entity_type = $1)
+              AND ($2::UUID IS NULL OR a.user_id = $2)
+              AND ($3::TIMESTAMPTZ IS NULL OR a.created_at >= $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR a.created_at < $4)
+            "#,
+        )
+        .bind(filter.entity_type.as_deref())
+        .bind(filter.user_id)
+        .bind(filter.from)
+        .bind(filter.to)
+        .fetch_one(self.pool)
+        .await
+    }
+}
@@ -0,0 +1,189 @@
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::error::Error;
+use crate::models::account::AccountDto;
+use crate::models::backup::BackupRecord;
+
+pub struct BackupRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> BackupRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Reads every account row as stored, deleted ones included
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn dump_accounts(&self) -> Result<Vec<AccountDto>, sqlx::Error> {
+        sqlx::query_as::<_, AccountDto>("SELECT * FROM accounts ORDER BY code")
+            .fetch_all(self.pool)
+            .await
+    }
+
+    /// Version of the newest successfully applied migration
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn schema_version(&self) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar("SELECT MAX(version) FROM _sqlx_migrations WHERE success")
+            .fetch_one(self.pool)
+            .await
+    }
+
+    /// Replaces every account row with `accounts` in one transaction and
+    /// returns the row counts before and after. Rows are matched by id, so
+    /// journal lines, notes and balance history of restored accounts stay
+    /// attached; the restore is refused if any of them belong to an account
+    /// the backup does not contain.
+    #[tracing::instrument(level = "debug", skip(self, accounts), fields(count = accounts.len()), err)]
+    pub async fn restore_accounts(&self, accounts: &[AccountDto]) -> crate::Result<(i64, i64)> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("LOCK TABLE accounts IN EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await?;
+
+        let before: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM accounts")
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let ids: Vec<Uuid> = accounts.iter().map(|account| account.id).collect();
+        let referenced: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT a.code FROM accounts a
+            WHERE a.id <> ALL($1)
+                AND (EXISTS(SELECT 1 FROM journal_lines l WHERE l.account_id = a.id)
+                    OR EXISTS(SELECT 1 FROM account_notes n WHERE n.account_id = a.id)
+                    OR EXISTS(SELECT 1 FROM balance_snapshots s WHERE s.account_id = a.id))
+            ORDER BY a.code
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if !referenced.is_empty() {
+            return Err(Error::Conflict(format!(
+                "Accounts missing from the backup still have journal lines, notes or balance history: {}",
+                referenced.join(", ")
+            )));
+        }
+
+        // Park every code on the row's id and unlink parents, so rows can be
+        // replaced in any order without tripping the unique code or parent
+        // constraints
+        sqlx::query("UPDATE accounts SET code = id::TEXT, parent_id = NULL")
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM accounts WHERE id <> ALL($1)")
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await?;
+
+        for account in accounts {
+            sqlx::query(
+                r#"
+                INSERT INTO accounts
+                    (id, code, name, description, account_type, category, subcategory, is_active,
+                    balance, currency, version, created_at, updated_at, deleted_at, created_by, updated_by)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                ON CONFLICT (id) DO UPDATE SET
+                    code = EXCLUDED.code,
+                    name = EXCLUDED.name,
+                    description = EXCLUDED.description,
+                    account_type = EXCLUDED.account_type,
+                    category = EXCLUDED.category,
+                    subcategory = EXCLUDED.subcategory,
+                    is_active = EXCLUDED.is_active,
+                    balance = EXCLUDED.balance,
+                    currency = EXCLUDED.currency,
+                    version = EXCLUDED.version,
+                    created_at = EXCLUDED.created_at,
+                    updated_at = EXCLUDED.updated_at,
+                    deleted_at = EXCLUDED.deleted_at,
+                    created_by = EXCLUDED.created_by,
+                    updated_by = EXCLUDED.updated_by
+                "#,
+            )
+            .bind(account.id)
+            .bind(&account.code)
+            .bind(&account.name)
+            .bind(&account.description)
+            .bind(&account.account_type)
+            .bind(&account.category)
+            .bind(&account.subcategory)
+            .bind(account.is_active)
+            .bind(account.balance)
+            .bind(&account.currency)
+            .bind(account.version)
+            .bind(account.created_at)
+            .bind(account.updated_at)
+            .bind(account.deleted_at)
+            .bind(account.created_by)
+            .bind(account.updated_by)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Relink parents now that every row exists
+        let (child_ids, parent_ids): (Vec<Uuid>, Vec<Uuid>) = accounts
+            .iter()
+            .filter_map(|account| account.parent_id.map(|parent_id| (account.id, parent_id)))
+            .unzip();
+        sqlx::query(
+            r#"
+            UPDATE accounts a
+            SET parent_id = p.parent_id
+            FROM UNNEST($1::UUID[], $2::UUID[]) AS p(id, parent_id)
+            WHERE a.id = p.id
+            "#,
+        )
+        .bind(&child_ids)
+        .bind(&parent_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        let after: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM accounts")
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok((before, after))
+    }
+
+    /// Records a backup file once it has been written
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn record(
+        &self,
+        file_path: &str,
+        size_bytes: i64,
+        account_count: i32,
+        created_by: Option<Uuid>,
+    ) -> Result<BackupRecord, sqlx::Error> {
+        sqlx::query_as::<_, BackupRecord>(
+            r#"
+            INSERT INTO backups (id, file_path, size_bytes, account_count, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5, NOW())
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(file_path)
+        .bind(size_bytes)
+        .bind(account_count)
+        .bind(created_by)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Finds the most recent backup
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_latest(&self) -> Result<Option<BackupRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BackupRecord>("SELECT * FROM backups ORDER BY created_at DESC LIMIT 1")
+            .fetch_optional(self.pool)
+            .await
+    }
+}
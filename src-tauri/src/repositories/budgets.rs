@@ -0,0 +1,55 @@
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::models::budget::Budget;
+
+pub struct BudgetRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> BudgetRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Sets the budget of an account for a period, replacing any earlier amount
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn upsert(
+        &self,
+        account_id: Uuid,
+        period_id: Uuid,
+        amount: Decimal,
+        user_id: Option<Uuid>,
+    ) -> Result<Budget, sqlx::Error> {
+        sqlx::query_as::<_, Budget>(
+            r#"
+            INSERT INTO budgets
+                (id, account_id, period_id, amount, created_at, updated_at, created_by, updated_by)
+            VALUES
+                ($1, $2, $3, $4, NOW(), NOW(), $5, $5)
+            ON CONFLICT (account_id, period_id) DO UPDATE SET
+                amount = EXCLUDED.amount,
+                updated_at = NOW(),
+                updated_by = EXCLUDED.updated_by
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(period_id)
+        .bind(amount)
+        .bind(user_id)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Finds every budget set for a period
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_period(&self, period_id: Uuid) -> Result<Vec<Budget>, sqlx::Error> {
+        sqlx::query_as::<_, Budget>("SELECT * FROM budgets WHERE period_id = $1")
+            .bind(period_id)
+            .fetch_all(self.pool)
+            .await
+    }
+}
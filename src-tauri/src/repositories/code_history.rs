@@ -0,0 +1,57 @@
+use sqlx::postgres::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::models::code_history::CodeChange;
+
+pub struct CodeHistoryRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> CodeHistoryRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Finds the code changes of an account, newest first
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_account(&self, account_id: Uuid) -> Result<Vec<CodeChange>, sqlx::Error> {
+        sqlx::query_as::<_, CodeChange>(
+            r#"
+            SELECT h.*, u.username
+            FROM code_history h
+            LEFT JOIN users u ON u.id = h.changed_by
+            WHERE h.account_id = $1
+            ORDER BY h.changed_at DESC, h.id DESC
+            "#,
+        )
+        .bind(account_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Records a code change inside the caller's transaction, so it is only
+    /// kept if the change itself is committed
+    pub(crate) async fn record_in_tx(
+        conn: &mut PgConnection,
+        account_id: Uuid,
+        old_code: &str,
+        new_code: &str,
+        user_id: Option<Uuid>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO code_history (id, account_id, old_code, new_code, changed_at, changed_by)
+            VALUES ($1, $2, $3, $4, NOW(), $5)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(old_code)
+        .bind(new_code)
+        .bind(user_id)
+        .execute(conn)
+        .await?;
+
+        Ok(())
+    }
+}
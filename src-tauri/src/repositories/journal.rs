@@ -0,0 +1,428 @@
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgConnection, PgPool};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::error::{not_found, validation_error, Error};
+use crate::models::account::{AccountCategory, AccountType};
+use crate::models::journal::{
+    AccountActivity, JournalEntry, JournalLine, LedgerLine, NewJournalEntry,
+};
+use crate::repositories::accounts::AccountRepository;
+use crate::repositories::periods::PeriodRepository;
+use crate::repositories::retry;
+
+pub struct JournalRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> JournalRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>, sqlx::Error> {
+        retry::read(|| {
+            sqlx::query_as::<_, JournalEntry>("SELECT * FROM journal_entries WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+        })
+        .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_lines(&self, entry_id: Uuid) -> Result<Vec<JournalLine>, sqlx::Error> {
+        sqlx::query_as::<_, JournalLine>(
+            "SELECT * FROM journal_lines WHERE entry_id = $1 ORDER BY line_number",
+        )
+        .bind(entry_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Finds the posted lines touching an account within an inclusive date range,
+    /// in posting order
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_ledger_lines(
+        &self,
+        account_id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<LedgerLine>, sqlx::Error> {
+        sqlx::query_as::<_, LedgerLine>(
+            r#"
+            SELECT e.id AS entry_id, e.entry_date,
+                COALESCE(l.description, e.description) AS description,
+                e.reference, l.debit, l.credit
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            WHERE l.account_id = $1
+                AND e.is_posted
+                AND ($2::DATE IS NULL OR e.entry_date >= $2)
+                AND ($3::DATE IS NULL OR e.entry_date <= $3)
+            ORDER BY e.entry_date, e.posted_at, e.id, l.line_number
+            "#,
+        )
+        .bind(account_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Totals the posted debits and credits of an account dated before `date`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_lines_before(
+        &self,
+        account_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<(Decimal, Decimal), sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT COALESCE(SUM(l.debit), 0), COALESCE(SUM(l.credit), 0)
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            WHERE l.account_id = $1 AND e.is_posted AND e.entry_date < $2
+            "#,
+        )
+        .bind(account_id)
+        .bind(date)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Totals the posted debits and credits per account for entries dated within
+    /// an inclusive range. Accounts without activity are left out.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_activity_by_account(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<AccountActivity>, sqlx::Error> {
+        sqlx::query_as::<_, AccountActivity>(
+            r#"
+            SELECT l.account_id, SUM(l.debit) AS debit, SUM(l.credit) AS credit
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            WHERE e.is_posted AND e.entry_date BETWEEN $1 AND $2
+            GROUP BY l.account_id
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Totals the posted debits and credits per account for entries dated on
+    /// or before `date`. Accounts without activity are left out.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_activity_through(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<AccountActivity>, sqlx::Error> {
+        sqlx::query_as::<_, AccountActivity>(
+            r#"
+            SELECT l.account_id, SUM(l.debit) AS debit, SUM(l.credit) AS credit
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            WHERE e.is_posted AND e.entry_date <= $1
+            GROUP BY l.account_id
+            "#,
+        )
+        .bind(date)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    /// Totals the posted debits and credits dated after `date` across the live
+    /// accounts of a category, optionally narrowed to one subcategory
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn sum_category_activity_after(
+        &self,
+        category: AccountCategory,
+        subcategory: Option<&str>,
+        date: NaiveDate,
+    ) -> Result<(Decimal, Decimal), sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT COALESCE(SUM(l.debit), 0), COALESCE(SUM(l.credit), 0)
+            FROM journal_lines l
+            JOIN journal_entries e ON e.id = l.entry_id
+            JOIN accounts a ON a.id = l.account_id
+            WHERE e.is_posted AND e.entry_date > $3
+                AND a.category = $1
+                AND a.deleted_at IS NULL
+                AND ($2::TEXT IS NULL OR LOWER(a.subcategory) = LOWER($2))
+            "#,
+        )
+        .bind(category.to_string())
+        .bind(subcategory)
+        .bind(date)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Creates a balanced journal entry with its lines, optionally posting it
+    /// in the same transaction
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn create(
+        &self,
+        new_entry: NewJournalEntry,
+        post: bool,
+    ) -> crate::Result<JournalEntry> {
+        let mut tx = self.pool.begin().await?;
+        let entry = Self::create_in_tx(&mut tx, &new_entry, post).await?;
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Creates a journal entry inside the caller's transaction, so it can be
+    /// committed together with other changes
+    pub(crate) async fn create_in_tx(
+        conn: &mut PgConnection,
+        new_entry: &NewJournalEntry,
+        post: bool,
+    ) -> crate::Result<JournalEntry> {
+        if !new_entry.is_balanced() {
+            return Err(validation_error(
+                "Journal entry debits and credits must balance",
+            ));
+        }
+
+        if matches!(new_entry.exchange_rate, Some(rate) if rate <= Decimal::ZERO) {
+            return Err(validation_error("Exchange rate must be positive"));
+        }
+
+        let id = Self::insert_in_tx(&mut *conn, new_entry, None).await?;
+
+        if post {
+            Self::post_in_tx(&mut *conn, id).await?;
+        }
+
+        Self::fetch_in_tx(&mut *conn, id).await
+    }
+
+    /// Posts a draft journal entry, applying its lines to the account balances
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn post(&self, id: Uuid) -> crate::Result<JournalEntry> {
+        let mut tx = self.pool.begin().await?;
+
+        Self::post_in_tx(&mut tx, id).await?;
+        let entry = Self::fetch_in_tx(&mut tx, id).await?;
+
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Creates and posts an entry that reverses a posted entry line for line.
+    /// The original is left untouched and may only be reversed once.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn reverse(
+        &self,
+        id: Uuid,
+        reversal_date: NaiveDate,
+        description: Option<String>,
+    ) -> crate::Result<JournalEntry> {
+        let mut tx = self.pool.begin().await?;
+
+        let original = sqlx::query_as::<_, JournalEntry>(
+            "SELECT * FROM journal_entries WHERE id = $1 FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| not_found("Journal entry"))?;
+
+        if !original.is_posted {
+            return Err(validation_error(
+                "Only posted journal entries can be reversed",
+            ));
+        }
+
+        let already_reversed: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM journal_entries WHERE reverses_entry_id = $1)",
+        )
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if already_reversed {
+            return Err(Error::Conflict(
+                "Journal entry has already been reversed".to_string(),
+            ));
+        }
+
+        let lines = sqlx::query_as::<_, JournalLine>(
+            "SELECT * FROM journal_lines WHERE entry_id = $1 ORDER BY line_number",
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let description = description.unwrap_or_else(|| {
+            format!(
+                "Reversal of {}",
+                original
+                    .reference
+                    .as_deref()
+                    .unwrap_or(&original.description)
+            )
+        });
+
+        let reversal = NewJournalEntry {
+            entry_date: reversal_date,
+            description,
+            reference: None,
+            exchange_rate: original.exchange_rate,
+            lines: lines.iter().map(JournalLine::reversed).collect(),
+        };
+
+        let reversal_id = Self::insert_in_tx(&mut tx, &reversal, Some(id)).await?;
+        Self::post_in_tx(&mut tx, reversal_id).await?;
+        let entry = Self::fetch_in_tx(&mut tx, reversal_id).await?;
+
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    async fn insert_in_tx(
+        conn: &mut PgConnection,
+        new_entry: &NewJournalEntry,
+        reverses_entry_id: Option<Uuid>,
+    ) -> crate::Result<Uuid> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO journal_entries
+                (id, entry_date, description, reference, exchange_rate,
+                is_posted, posted_at, reverses_entry_id, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, $4, $5, FALSE, NULL, $6, $7, $7)
+            "#,
+        )
+        .bind(id)
+        .bind(new_entry.entry_date)
+        .bind(&new_entry.description)
+        .bind(&new_entry.reference)
+        .bind(new_entry.exchange_rate)
+        .bind(reverses_entry_id)
+        .bind(now)
+        .execute(&mut *conn)
+        .await?;
+
+        for (index, line) in new_entry.lines.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO journal_lines
+                    (id, entry_id, account_id, line_number, description, debit, credit)
+                VALUES
+                    ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(id)
+            .bind(line.account_id)
+            .bind(index as i32 + 1)
+            .bind(&line.description)
+            .bind(line.debit)
+            .bind(line.credit)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(id)
+    }
+
+    async fn fetch_in_tx(conn: &mut PgConnection, id: Uuid) -> crate::Result<JournalEntry> {
+        sqlx::query_as::<_, JournalEntry>("SELECT * FROM journal_entries WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or_else(|| not_found("Journal entry"))
+    }
+
+    async fn post_in_tx(conn: &mut PgConnection, id: Uuid) -> crate::Result<()> {
+        let entry = sqlx::query_as::<_, JournalEntry>(
+            "SELECT * FROM journal_entries WHERE id = $1 FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or_else(|| not_found("Journal entry"))?;
+
+        if entry.is_posted {
+            return Err(Error::Conflict(
+                "Journal entry is already posted".to_string(),
+            ));
+        }
+
+        PeriodRepository::ensure_open_in_tx(&mut *conn, entry.entry_date).await?;
+
+        // Accounts in different currencies may only be mixed at a stated rate
+        let currencies: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT a.currency
+            FROM journal_lines l
+            JOIN accounts a ON a.id = l.account_id
+            WHERE l.entry_id = $1
+            ORDER BY a.currency
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        if currencies.len() > 1 && entry.exchange_rate.is_none() {
+            return Err(Error::Validation(format!(
+                "Journal entry mixes currencies ({}) without an exchange rate",
+                currencies.join(", ")
+            )));
+        }
+
+        let lines: Vec<(Uuid, String, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT l.account_id, a.account_type, l.debit, l.credit
+            FROM journal_lines l
+            JOIN accounts a ON a.id = l.account_id
+            WHERE l.entry_id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        // Net the lines per account; a sorted map keeps row locks in a stable order
+        let mut deltas: BTreeMap<Uuid, Decimal> = BTreeMap::new();
+        for (account_id, account_type, debit, credit) in lines {
+            let account_type = AccountType::from_str(&account_type).unwrap_or(AccountType::Asset);
+            *deltas.entry(account_id).or_default() += account_type.balance_delta(debit, credit);
+        }
+
+        for (account_id, delta) in deltas {
+            AccountRepository::update_balance_in_tx(&mut *conn, account_id, Some(id), delta)
+                .await?
+                .ok_or_else(|| not_found("Account"))?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE journal_entries
+            SET is_posted = TRUE, posted_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::error::{not_found, Error};
+use crate::models::account::{Account, AccountCursor, NewAccount};
+use crate::repositories::account_store::AccountStore;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// An `AccountStore` kept in memory, for testing command logic without a
+/// database. Mirrors the repository's code uniqueness, version and delete rules.
+#[derive(Default)]
+pub struct InMemoryAccountStore {
+    accounts: Mutex<Vec<Account>>,
+}
+
+impl InMemoryAccountStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_accounts(accounts: Vec<Account>) -> Self {
+        Self {
+            accounts: Mutex::new(accounts),
+        }
+    }
+
+    fn live(&self) -> Vec<Account> {
+        let accounts = self.accounts.lock().unwrap();
+        accounts
+            .iter()
+            .filter(|account| account.deleted_at.is_none())
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AccountStore for InMemoryAccountStore {
+    async fn find_all(&self) -> Result<Vec<Account>, sqlx::Error> {
+        let mut accounts = self.live();
+        accounts.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(accounts)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Account>, sqlx::Error> {
+        Ok(self.live().into_iter().find(|account| account.id == id))
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<Account>, sqlx::Error> {
+        Ok(self.live().into_iter().find(|account| account.code == code))
+    }
+
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Account>, sqlx::Error> {
+        let mut children: Vec<Account> = self
+            .live()
+            .into_iter()
+            .filter(|account| account.parent_id == Some(parent_id))
+            .collect();
+        children.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(children)
+    }
+
+    async fn find_after_cursor(
+        &self,
+        after: Option<&AccountCursor>,
+        limit: i64,
+    ) -> Result<Vec<Account>, sqlx::Error> {
+        let mut accounts: Vec<Account> = self
+            .live()
+            .into_iter()
+            .filter(|account| match after {
+                Some(cursor) => (&account.code, account.id) > (&cursor.code, cursor.id),
+                None => true,
+            })
+            .collect();
+        accounts.sort_by(|a, b| (&a.code, a.id).cmp(&(&b.code, b.id)));
+        accounts.truncate(limit as usize);
+        Ok(accounts)
+    }
+
+    async fn find_inactive_nonzero(&self) -> Result<Vec<Account>, sqlx::Error> {
+        let mut accounts: Vec<Account> = self
+            .live()
+            .into_iter()
+            .filter(|account| !account.is_active && !account.balance.is_zero())
+            .collect();
+        accounts.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(accounts)
+    }
+
+    async fn would_create_cycle(
+        &self,
+        account_id: Uuid,
+        parent_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let accounts = self.accounts.lock().unwrap();
+        let mut current = Some(parent_id);
+        let mut steps = 0;
+        while let Some(id) = current {
+            if id == account_id {
+                return Ok(true);
+            }
+            // A cycle already stored above the parent would otherwise loop forever
+            steps += 1;
+            if steps > accounts.len() {
+                break;
+            }
+            current = accounts
+                .iter()
+                .find(|account| account.id == id)
+                .and_then(|account| account.parent_id);
+        }
+        Ok(false)
+    }
+
+    async fn create(
+        &self,
+        new_account: NewAccount,
+        user_id: Option<Uuid>,
+    ) -> crate::Result<Account> {
+        if self.find_by_code(&new_account.code).await?.is_some() {
+            return Err(Error::Conflict(format!(
+                "Account code '{}' is already in use",
+                new_account.code
+            )));
+        }
+
+        let mut account = Account::new(new_account);
+        account.created_by = user_id;
+        account.updated_by = user_id;
+        self.accounts.lock().unwrap().push(account.clone());
+
+        Ok(account)
+    }
+
+    async fn update(&self, account: &mut Account, user_id: Option<Uuid>) -> crate::Result<()> {
+        if let Some(existing) = self.find_by_code(&account.code).await? {
+            if existing.id != account.id {
+                return Err(Error::Conflict(format!(
+                    "Account code '{}' is already in use",
+                    account.code
+                )));
+            }
+        }
+
+        let mut accounts = self.accounts.lock().unwrap();
+        let stored = match accounts
+            .iter_mut()
+            .find(|stored| stored.id == account.id && stored.deleted_at.is_none())
+        {
+            Some(stored) if stored.version == account.version => stored,
+            _ => {
+                return Err(Error::Conflict(
+                    "Account was modified by someone else".to_string(),
+                ))
+            }
+        };
+
+        account.updated_by = user_id;
+        account.version += 1;
+        *stored = account.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> crate::Result<()> {
+        let mut accounts = self.accounts.lock().unwrap();
+        let has_children = accounts
+            .iter()
+            .any(|account| account.parent_id == Some(id) && account.deleted_at.is_none());
+        if has_children {
+            return Err(Error::Conflict(
+                "Account has child accounts and cannot be deleted".to_string(),
+            ));
+        }
+
+        match accounts
+            .iter_mut()
+            .find(|account| account.id == id && account.deleted_at.is_none())
+        {
+            Some(account) => {
+                account.deleted_at = Some(Utc::now());
+                account.updated_at = Utc::now();
+                Ok(())
+            }
+            None => Err(not_found("Account")),
+        }
+    }
+}
@@ -1 +1,15 @@
+pub mod account_store;
 pub mod accounts;
+pub mod activity;
+pub mod backups;
+pub mod budgets;
+pub mod code_history;
+pub mod journal;
+#[cfg(test)]
+pub mod memory;
+pub mod notes;
+pub mod periods;
+pub mod retry;
+pub mod snapshots;
+pub mod summary;
+pub mod users;
@@ -0,0 +1,79 @@
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use crate::error::not_found;
+use crate::models::note::AccountNote;
+
+pub struct NoteRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> NoteRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Appends a note to an account and bumps its note count. Fails with
+    /// `NotFound` when the account does not exist or is deleted.
+    #[tracing::instrument(level = "debug", skip(self, body), err)]
+    pub async fn add_note(
+        &self,
+        account_id: Uuid,
+        body: &str,
+        author_id: Option<Uuid>,
+    ) -> crate::Result<AccountNote> {
+        let mut tx = self.pool.begin().await?;
+
+        // Notes are not edits, so the account's version is left alone
+        let updated = sqlx::query(
+            "UPDATE accounts SET note_count = note_count + 1 WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(account_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if updated.rows_affected() == 0 {
+            return Err(not_found("Account"));
+        }
+
+        let note = sqlx::query_as::<_, AccountNote>(
+            r#"
+            WITH inserted AS (
+                INSERT INTO account_notes (id, account_id, body, author_id)
+                VALUES ($1, $2, $3, $4)
+                RETURNING *
+            )
+            SELECT n.*, u.username AS author
+            FROM inserted n
+            LEFT JOIN users u ON u.id = n.author_id
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(body)
+        .bind(author_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(note)
+    }
+
+    /// Finds the notes on an account, newest first
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn list_notes(&self, account_id: Uuid) -> Result<Vec<AccountNote>, sqlx::Error> {
+        sqlx::query_as::<_, AccountNote>(
+            r#"
+            SELECT n.*, u.username AS author
+            FROM account_notes n
+            LEFT JOIN users u ON u.id = n.author_id
+            WHERE n.account_id = $1
+            ORDER BY n.created_at DESC, n.id
+            "#,
+        )
+        .bind(account_id)
+        .fetch_all(self.pool)
+        .await
+    }
+}
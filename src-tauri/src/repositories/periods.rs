@@ -0,0 +1,223 @@
+use crate::error::{not_found, validation_error, Error};
+use crate::models::period::{FiscalPeriod, NewFiscalPeriod};
+use crate::repositories::retry;
+use chrono::{NaiveDate, Utc};
+use sqlx::postgres::{PgConnection, PgPool};
+use uuid::Uuid;
+
+pub struct PeriodRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> PeriodRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<FiscalPeriod>, sqlx::Error> {
+        retry::read(|| {
+            sqlx::query_as::<_, FiscalPeriod>("SELECT * FROM fiscal_periods WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+        })
+        .await
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_all(&self) -> Result<Vec<FiscalPeriod>, sqlx::Error> {
+        sqlx::query_as::<_, FiscalPeriod>("SELECT * FROM fiscal_periods ORDER BY start_date")
+            .fetch_all(self.pool)
+            .await
+    }
+
+    /// Finds the period containing `date`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_date(&self, date: NaiveDate) -> Result<Option<FiscalPeriod>, sqlx::Error> {
+        sqlx::query_as::<_, FiscalPeriod>(
+            "SELECT * FROM fiscal_periods WHERE $1 BETWEEN start_date AND end_date",
+        )
+        .bind(date)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    /// Finds the period containing today's date
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_current(&self) -> Result<Option<FiscalPeriod>, sqlx::Error> {
+        self.find_by_date(Utc::now().date_naive()).await
+    }
+
+    /// Fails with a conflict if `date` falls inside a closed period
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn ensure_open(&self, date: NaiveDate) -> crate::Result<()> {
+        let mut conn = self.pool.acquire().await?;
+        Self::ensure_open_in_tx(&mut conn, date).await
+    }
+
+    #[tracing::instrument(level = "debug", skip(conn), err)]
+    pub(crate) async fn ensure_open_in_tx(
+        conn: &mut PgConnection,
+        date: NaiveDate,
+    ) -> crate::Result<()> {
+        let period_closed: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM fiscal_periods
+                WHERE is_closed AND $1 BETWEEN start_date AND end_date
+            )
+            "#,
+        )
+        .bind(date)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if period_closed {
+            return Err(Error::Conflict("Period is closed".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether an account has posted lines dated inside a closed period
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn has_closed_activity(&self, account_id: Uuid) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM journal_lines l
+                JOIN journal_entries e ON e.id = l.entry_id
+                JOIN fiscal_periods p
+                    ON p.is_closed AND e.entry_date BETWEEN p.start_date AND p.end_date
+                WHERE l.account_id = $1 AND e.is_posted
+            )
+            "#,
+        )
+        .bind(account_id)
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Creates a period, rejecting inverted ranges and overlaps with existing periods
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn create(&self, new_period: NewFiscalPeriod) -> crate::Result<FiscalPeriod> {
+        if new_period.start_date > new_period.end_date {
+            return Err(validation_error(
+                "Period start date must not be after its end date",
+            ));
+        }
+
+        let name_taken: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM fiscal_periods WHERE name = $1)")
+                .bind(&new_period.name)
+                .fetch_one(self.pool)
+                .await?;
+
+        if name_taken {
+            return Err(Error::Conflict(format!(
+                "Period name '{}' is already in use",
+                new_period.name
+            )));
+        }
+
+        let overlapping: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT name FROM fiscal_periods
+            WHERE start_date <= $2 AND end_date >= $1
+            LIMIT 1
+            "#,
+        )
+        .bind(new_period.start_date)
+        .bind(new_period.end_date)
+        .fetch_optional(self.pool)
+        .await?;
+
+        if let Some(name) = overlapping {
+            return Err(Error::Conflict(format!(
+                "Period overlaps existing period '{}'",
+                name
+            )));
+        }
+
+        let now = Utc::now();
+        let period = sqlx::query_as::<_, FiscalPeriod>(
+            r#"
+            INSERT INTO fiscal_periods
+                (id, name, start_date, end_date, is_closed, closed_at, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, $4, FALSE, NULL, $5, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&new_period.name)
+        .bind(new_period.start_date)
+        .bind(new_period.end_date)
+        .bind(now)
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(period)
+    }
+
+    /// Marks a period as closed
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn close_period(&self, id: Uuid) -> crate::Result<FiscalPeriod> {
+        let period = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| not_found("Fiscal period"))?;
+
+        if period.is_closed {
+            return Err(Error::Conflict(format!(
+                "Period '{}' is already closed",
+                period.name
+            )));
+        }
+
+        let period = sqlx::query_as::<_, FiscalPeriod>(
+            r#"
+            UPDATE fiscal_periods
+            SET is_closed = TRUE, closed_at = NOW(), updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(period)
+    }
+
+    /// Reopens a closed period so entries can be posted into it again
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn reopen_period(&self, id: Uuid) -> crate::Result<FiscalPeriod> {
+        let period = self
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| not_found("Fiscal period"))?;
+
+        if !period.is_closed {
+            return Err(Error::Conflict(format!(
+                "Period '{}' is not closed",
+                period.name
+            )));
+        }
+
+        let period = sqlx::query_as::<_, FiscalPeriod>(
+            r#"
+            UPDATE fiscal_periods
+            SET is_closed = FALSE, closed_at = NULL, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .fetch_one(self.pool)
+        .await?;
+
+        Ok(period)
+    }
+}
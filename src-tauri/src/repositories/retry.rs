@@ -0,0 +1,169 @@
+use std::future::Future;
+use std::io::ErrorKind;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::config::DatabaseConfig;
+
+/// How read queries are retried after a transient failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first; 1 disables retries
+    pub attempts: u32,
+    /// Delay before the first retry, doubled after each failed attempt
+    pub initial_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            initial_delay: Duration::from_millis(50),
+        }
+    }
+}
+
+impl From<&DatabaseConfig> for RetryPolicy {
+    fn from(config: &DatabaseConfig) -> Self {
+        Self {
+            attempts: config.read_attempts,
+            initial_delay: Duration::from_millis(config.read_retry_delay_ms),
+        }
+    }
+}
+
+static READ_POLICY: OnceLock<RetryPolicy> = OnceLock::new();
+
+/// Sets the policy repository reads are retried with. Only the first call
+/// takes effect; until then the default policy applies.
+pub fn set_read_policy(policy: RetryPolicy) {
+    if READ_POLICY.set(policy).is_err() {
+        tracing::debug!("Read retry policy already set, keeping the first one");
+    }
+}
+
+fn read_policy() -> RetryPolicy {
+    READ_POLICY.get().copied().unwrap_or_default()
+}
+
+/// Whether an error may clear up by itself: the pool had no free connection
+/// in time, or the connection to the server was lost mid-query
+pub fn is_transient(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::PoolTimedOut => true,
+        sqlx::Error::Io(io) => matches!(
+            io.kind(),
+            ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::UnexpectedEof
+        ),
+        _ => false,
+    }
+}
+
+/// Runs `op` until it succeeds, fails with an error that is not transient, or
+/// `policy.attempts` are used up, waiting with exponential backoff in between.
+/// The last error is returned unchanged.
+pub async fn with_retry<T, F, Fut>(policy: RetryPolicy, mut op: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        let err = match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+
+        if attempt >= policy.attempts || !is_transient(&err) {
+            return Err(err);
+        }
+
+        let delay = policy
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1));
+        tracing::warn!(
+            attempt,
+            max_attempts = policy.attempts,
+            error = %err,
+            "Database read failed, retrying in {:?}",
+            delay
+        );
+
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Runs a read-only query with the configured retry policy. Writes must not
+/// go through here: one that timed out may still have been applied, and
+/// running it again could apply it twice.
+pub(crate) async fn read<T, F, Fut>(op: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    with_retry(read_policy(), op).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn policy(attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            attempts,
+            initial_delay: Duration::from_millis(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried_until_success() {
+        let calls = Cell::new(0);
+        let result = with_retry(policy(3), || {
+            calls.set(calls.get() + 1);
+            let call = calls.get();
+            async move {
+                if call < 3 {
+                    Err(sqlx::Error::PoolTimedOut)
+                } else {
+                    Ok(call)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_returned_after_one_attempt() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = with_retry(policy(3), || {
+            calls.set(calls.get() + 1);
+            async { Err(sqlx::Error::RowNotFound) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn the_last_transient_error_is_returned_once_attempts_run_out() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = with_retry(policy(2), || {
+            calls.set(calls.get() + 1);
+            async { Err(sqlx::Error::PoolTimedOut) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(sqlx::Error::PoolTimedOut)));
+        assert_eq!(calls.get(), 2);
+    }
+}
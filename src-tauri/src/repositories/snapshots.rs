@@ -0,0 +1,60 @@
+use crate::models::snapshot::BalanceSnapshot;
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgConnection, PgPool};
+use uuid::Uuid;
+
+pub struct SnapshotRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> SnapshotRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Finds the latest snapshot of an account recorded on or before `date`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_as_of(
+        &self,
+        account_id: Uuid,
+        date: NaiveDate,
+    ) -> Result<Option<BalanceSnapshot>, sqlx::Error> {
+        sqlx::query_as::<_, BalanceSnapshot>(
+            r#"
+            SELECT * FROM balance_snapshots
+            WHERE account_id = $1 AND recorded_at < ($2::DATE + 1)
+            ORDER BY recorded_at DESC, id DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(account_id)
+        .bind(date)
+        .fetch_optional(self.pool)
+        .await
+    }
+
+    /// Records the balance of an account inside the caller's transaction
+    pub(crate) async fn record_in_tx(
+        conn: &mut PgConnection,
+        account_id: Uuid,
+        entry_id: Option<Uuid>,
+        balance: Decimal,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO balance_snapshots (id, account_id, entry_id, balance, recorded_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(account_id)
+        .bind(entry_id)
+        .bind(balance)
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use sqlx::postgres::PgPool;
+
+use crate::models::summary::FinancialSummary;
+
+pub struct SummaryRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> SummaryRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Reads the cached totals; they are as old as `refreshed_at`
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find(&self) -> Result<FinancialSummary, sqlx::Error> {
+        sqlx::query_as::<_, FinancialSummary>(
+            r#"
+            SELECT total_assets, total_liabilities, total_equity, total_revenue,
+                total_expenses, refreshed_at
+            FROM financial_summary
+            "#,
+        )
+        .fetch_one(self.pool)
+        .await
+    }
+
+    /// Recomputes the cached totals. Readers keep seeing the previous totals
+    /// until the refresh completes.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn refresh(&self) -> Result<FinancialSummary, sqlx::Error> {
+        sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY financial_summary")
+            .execute(self.pool)
+            .await?;
+
+        self.find().await
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::Error;
+use crate::models::user::{NewUser, User, UserDto};
+use crate::repositories::retry;
+use chrono::Utc;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+pub struct UserRepository<'a> {
+    pool: &'a PgPool,
+}
+
+impl<'a> UserRepository<'a> {
+    pub fn new(pool: &'a PgPool) -> Self {
+        Self { pool }
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, sqlx::Error> {
+        let dto = retry::read(|| {
+            sqlx::query_as::<_, UserDto>("SELECT * FROM users WHERE id = $1")
+                .bind(id)
+                .fetch_optional(self.pool)
+        })
+        .await?;
+
+        Ok(dto.map(User::from))
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn count(&self) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM users")
+            .fetch_one(self.pool)
+            .await
+    }
+
+    /// Looks a user up by username, ignoring case
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn find_by_username(&self, username: &str) -> Result<Option<User>, sqlx::Error> {
+        let dto =
+            sqlx::query_as::<_, UserDto>("SELECT * FROM users WHERE LOWER(username) = LOWER($1)")
+                .bind(username)
+                .fetch_optional(self.pool)
+                .await?;
+
+        Ok(dto.map(User::from))
+    }
+
+    /// Creates a user, hashing the password with bcrypt at `hash_cost`
+    #[tracing::instrument(level = "debug", skip(self, new_user), err)]
+    pub async fn create(&self, new_user: NewUser, hash_cost: u32) -> crate::Result<User> {
+        let taken: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM users WHERE LOWER(username) = LOWER($1) OR LOWER(email) = LOWER($2))",
+        )
+        .bind(&new_user.username)
+        .bind(&new_user.email)
+        .fetch_one(self.pool)
+        .await?;
+
+        if taken {
+            return Err(Error::Conflict(
+                "Username or email is already in use".to_string(),
+            ));
+        }
+
+        let password_hash = bcrypt::hash(&new_user.password, hash_cost)
+            .map_err(|e| Error::Unknown(format!("Failed to hash password: {}", e)))?;
+
+        let now = Utc::now();
+        let user = User {
+            id: Uuid::new_v4(),
+            username: new_user.username,
+            email: new_user.email,
+            password_hash,
+            role: new_user.role,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO users
+                (id, username, email, password_hash, role, is_active, created_at, updated_at)
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(user.id)
+        .bind(&user.username)
+        .bind(&user.email)
+        .bind(&user.password_hash)
+        .bind(user.role.to_string())
+        .bind(user.is_active)
+        .bind(user.created_at)
+        .bind(user.updated_at)
+        .execute(self.pool)
+        .await?;
+
+        Ok(user)
+    }
+}
@@ -0,0 +1,160 @@
+// src-tauri/services/auth.rs
+
+use chrono::{Duration, Utc};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::config::SecurityConfig;
+use crate::error::{Error, Result};
+use crate::models::user::UserRole;
+
+/// Claims carried by a session token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Claims {
+    /// Id of the authenticated user
+    pub sub: Uuid,
+    /// Role of the user when the token was issued
+    pub role: UserRole,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+/// Issues a session token for `user_id`, signed with the configured secret and
+/// expiring after `token_expiry_hours`
+pub fn generate_token(config: &SecurityConfig, user_id: Uuid, role: UserRole) -> Result<String> {
+    let now = Utc::now();
+    let claims = Claims {
+        sub: user_id,
+        role,
+        iat: now.timestamp(),
+        exp: (now + Duration::hours(config.token_expiry_hours as i64)).timestamp(),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+    )
+    .map_err(|e| Error::Unknown(format!("Failed to sign session token: {}", e)))
+}
+
+/// Verifies a session token's signature and expiry and returns its claims
+pub fn verify_token(config: &SecurityConfig, token: &str) -> Result<Claims> {
+    let mut validation = Validation::default();
+    validation.leeway = 0;
+
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(config.jwt_secret.as_bytes()),
+        &validation,
+    )
+    .map(|data| data.claims)
+    .map_err(|e| match e.kind() {
+        ErrorKind::ExpiredSignature => Error::Auth("Session has expired".to_string()),
+        _ => Error::Auth("Invalid session token".to_string()),
+    })
+}
+
+/// Verifies a session token and checks that its role is at least `required`
+pub fn require_role(config: &SecurityConfig, token: &str, required: UserRole) -> Result<Claims> {
+    let claims = verify_token(config, token)?;
+
+    if !claims.role.satisfies(required) {
+        return Err(Error::Auth(format!(
+            "This action requires the {} role",
+            required
+        )));
+    }
+
+    Ok(claims)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(secret: &str) -> SecurityConfig {
+        SecurityConfig {
+            jwt_secret: secret.to_string(),
+            token_expiry_hours: 1,
+            hash_cost: 4,
+        }
+    }
+
+    fn auth_message(result: Result<Claims>) -> String {
+        match result {
+            Err(Error::Auth(message)) => message,
+            other => panic!("expected an auth error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn token_round_trips_user_and_role() {
+        let config = config("secret");
+        let user_id = Uuid::new_v4();
+        let token = generate_token(&config, user_id, UserRole::Accountant).unwrap();
+
+        let claims = require_role(&config, &token, UserRole::Accountant).unwrap();
+        assert_eq!(claims.sub, user_id);
+        assert_eq!(claims.role, UserRole::Accountant);
+        assert!(claims.exp > claims.iat);
+    }
+
+    #[test]
+    fn expired_token_is_rejected() {
+        let config = config("secret");
+        let issued = Utc::now() - Duration::hours(2);
+        let claims = Claims {
+            sub: Uuid::new_v4(),
+            role: UserRole::Admin,
+            iat: issued.timestamp(),
+            exp: (issued + Duration::hours(1)).timestamp(),
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(config.jwt_secret.as_bytes()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            auth_message(require_role(&config, &token, UserRole::Viewer)),
+            "Session has expired"
+        );
+    }
+
+    #[test]
+    fn token_signed_with_another_secret_is_rejected() {
+        let token = generate_token(&config("other"), Uuid::new_v4(), UserRole::Admin).unwrap();
+
+        assert_eq!(
+            auth_message(require_role(&config("secret"), &token, UserRole::Viewer)),
+            "Invalid session token"
+        );
+        assert_eq!(
+            auth_message(require_role(
+                &config("secret"),
+                "not-a-token",
+                UserRole::Viewer
+            )),
+            "Invalid session token"
+        );
+    }
+
+    #[test]
+    fn lower_role_is_refused() {
+        let config = config("secret");
+        let token = generate_token(&config, Uuid::new_v4(), UserRole::Viewer).unwrap();
+
+        assert_eq!(
+            auth_message(require_role(&config, &token, UserRole::Accountant)),
+            "This action requires the ACCOUNTANT role"
+        );
+        require_role(&config, &token, UserRole::Viewer).unwrap();
+
+        let admin = generate_token(&config, Uuid::new_v4(), UserRole::Admin).unwrap();
+        require_role(&config, &admin, UserRole::Accountant).unwrap();
+    }
+}
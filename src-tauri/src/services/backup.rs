@@ -0,0 +1,97 @@
+// src-tauri/services/backup.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+use crate::error::{Error, Result};
+use crate::models::account::AccountDto;
+
+/// Marks a file as a backup written by this application
+pub const BACKUP_FORMAT: &str = "erp-backup";
+
+/// Version of the backup file layout, bumped whenever its fields change
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Identifying fields at the top of every backup file, read before the rest so
+/// that files of another format version are rejected by version rather than
+/// failing to parse
+#[derive(Debug, Deserialize)]
+struct BackupHeader {
+    format: String,
+    format_version: u32,
+}
+
+/// Contents of a backup file: a header identifying the file and the schema it
+/// was taken from, followed by the table rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupFile {
+    pub format: String,
+    pub format_version: u32,
+    /// Newest migration applied to the database the backup was taken from
+    pub schema_version: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub accounts: Vec<AccountDto>,
+}
+
+impl BackupFile {
+    pub fn new(schema_version: Option<i64>, accounts: Vec<AccountDto>) -> Self {
+        Self {
+            format: BACKUP_FORMAT.to_string(),
+            format_version: BACKUP_FORMAT_VERSION,
+            schema_version,
+            created_at: Utc::now(),
+            accounts,
+        }
+    }
+
+    /// File name for the backup, e.g. "backup-20250319-140501-123.json"
+    pub fn file_name(&self) -> String {
+        format!(
+            "backup-{}.json",
+            self.created_at.format("%Y%m%d-%H%M%S-%3f")
+        )
+    }
+}
+
+/// Writes a backup into `dir` and returns its path and size in bytes. An
+/// existing file is never overwritten.
+pub async fn write(dir: &Path, backup: &BackupFile) -> Result<(PathBuf, u64)> {
+    let path = dir.join(backup.file_name());
+    let contents = serde_json::to_vec_pretty(backup)?;
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .await?;
+    file.write_all(&contents).await?;
+    file.sync_all().await?;
+
+    Ok((path, contents.len() as u64))
+}
+
+/// Reads a backup file, checking that it is one of ours and in the current
+/// format version
+pub async fn read(path: &Path) -> Result<BackupFile> {
+    let contents = tokio::fs::read(path).await?;
+
+    let header: BackupHeader = serde_json::from_slice(&contents)
+        .map_err(|_| Error::Validation(format!("{} is not a backup file", path.display())))?;
+    if header.format != BACKUP_FORMAT {
+        return Err(Error::Validation(format!(
+            "{} is not a backup file",
+            path.display()
+        )));
+    }
+    if header.format_version != BACKUP_FORMAT_VERSION {
+        return Err(Error::Validation(format!(
+            "Backup format version {} is not supported (expected {})",
+            header.format_version, BACKUP_FORMAT_VERSION
+        )));
+    }
+
+    serde_json::from_slice(&contents)
+        .map_err(|e| Error::Validation(format!("Backup file is damaged: {}", e)))
+}
@@ -0,0 +1,124 @@
+// src-tauri/services/balance_sheet.rs
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::models::account::{Account, AccountCategory, AccountType};
+use crate::money;
+
+/// A single account line of the balance sheet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceSheetLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub balance: String,
+}
+
+/// The accounts of one category with their subtotal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceSheetCategory {
+    pub category: String,
+    pub accounts: Vec<BalanceSheetLine>,
+    pub total: String,
+}
+
+/// Assets, liabilities or equity, grouped by category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceSheetSection {
+    pub account_type: String,
+    pub categories: Vec<BalanceSheetCategory>,
+    pub total: String,
+}
+
+/// Balance sheet built from the current account balances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceSheet {
+    pub assets: BalanceSheetSection,
+    pub liabilities: BalanceSheetSection,
+    pub equity: BalanceSheetSection,
+    /// Revenue less expenses not yet closed into retained earnings
+    pub current_earnings: String,
+    /// Equity accounts plus current earnings
+    pub total_equity: String,
+    pub total_liabilities_and_equity: String,
+    /// Whether assets equal liabilities plus equity
+    pub is_balanced: bool,
+}
+
+impl BalanceSheet {
+    /// Builds the balance sheet. Balances are read on each account's normal side,
+    /// and accounts with a zero balance are left out.
+    pub fn from_accounts(accounts: &[Account]) -> Self {
+        let (assets, total_assets) = build_section(accounts, AccountType::Asset);
+        let (liabilities, total_liabilities) = build_section(accounts, AccountType::Liability);
+        let (equity, equity_accounts) = build_section(accounts, AccountType::Equity);
+
+        let current_earnings =
+            sum_type(accounts, AccountType::Revenue) - sum_type(accounts, AccountType::Expense);
+        let total_equity = equity_accounts + current_earnings;
+        let total_liabilities_and_equity = total_liabilities + total_equity;
+
+        Self {
+            assets,
+            liabilities,
+            equity,
+            current_earnings: money::format(current_earnings),
+            total_equity: money::format(total_equity),
+            total_liabilities_and_equity: money::format(total_liabilities_and_equity),
+            is_balanced: money::round(total_assets) == money::round(total_liabilities_and_equity),
+        }
+    }
+}
+
+fn build_section(
+    accounts: &[Account],
+    account_type: AccountType,
+) -> (BalanceSheetSection, Decimal) {
+    let mut categories = Vec::new();
+    let mut section_total = Decimal::ZERO;
+
+    for category in AccountCategory::for_account_type(account_type) {
+        let members: Vec<&Account> = accounts
+            .iter()
+            .filter(|account| account.category == category && !account.balance.is_zero())
+            .collect();
+
+        if members.is_empty() {
+            continue;
+        }
+
+        let total: Decimal = members.iter().map(|account| account.balance).sum();
+        section_total += total;
+
+        categories.push(BalanceSheetCategory {
+            category: category.to_string(),
+            accounts: members
+                .into_iter()
+                .map(|account| BalanceSheetLine {
+                    id: account.id.to_string(),
+                    code: account.code.clone(),
+                    name: account.name.clone(),
+                    balance: money::format(account.balance),
+                })
+                .collect(),
+            total: money::format(total),
+        });
+    }
+
+    let section = BalanceSheetSection {
+        account_type: account_type.to_string(),
+        categories,
+        total: money::format(section_total),
+    };
+
+    (section, section_total)
+}
+
+fn sum_type(accounts: &[Account], account_type: AccountType) -> Decimal {
+    accounts
+        .iter()
+        .filter(|account| account.account_type == account_type)
+        .map(|account| account.balance)
+        .sum()
+}
@@ -0,0 +1,235 @@
+// src-tauri/services/budget_variance.rs
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::account::{Account, AccountType};
+use crate::models::budget::Budget;
+use crate::models::journal::AccountActivity;
+use crate::models::period::FiscalPeriod;
+use crate::money;
+
+/// Budgeted and actual amounts of one revenue or expense account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetVarianceLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub budgeted: String,
+    pub actual: String,
+    /// Actual less budgeted
+    pub variance: String,
+    /// Revenue at or above budget, or expenses at or below it
+    pub favorable: bool,
+}
+
+/// Budget against posted activity for every revenue and expense account with
+/// a budget or activity in a fiscal period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetVarianceReport {
+    pub period_id: String,
+    pub period_name: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub lines: Vec<BudgetVarianceLine>,
+}
+
+impl BudgetVarianceReport {
+    /// Builds the report from the period's budgets and per-account activity.
+    /// Actual amounts are taken on each account's normal side, so revenue and
+    /// expenses are both positive.
+    pub fn from_activity(
+        period: &FiscalPeriod,
+        accounts: &[Account],
+        budgets: &[Budget],
+        activity: &[AccountActivity],
+    ) -> Self {
+        let budgeted_by_account: HashMap<Uuid, Decimal> = budgets
+            .iter()
+            .map(|budget| (budget.account_id, budget.amount))
+            .collect();
+        let totals_by_account: HashMap<Uuid, (Decimal, Decimal)> = activity
+            .iter()
+            .map(|row| (row.account_id, (row.debit, row.credit)))
+            .collect();
+
+        let mut lines = Vec::new();
+        for account in accounts
+            .iter()
+            .filter(|account| account.account_type.is_budgetable())
+        {
+            let budgeted = budgeted_by_account.get(&account.id).copied();
+            let actual = totals_by_account
+                .get(&account.id)
+                .map(|&(debit, credit)| account.account_type.balance_delta(debit, credit))
+                .unwrap_or_default();
+            if budgeted.is_none() && actual.is_zero() {
+                continue;
+            }
+
+            let budgeted = budgeted.unwrap_or_default();
+            let variance = actual - budgeted;
+            let favorable = match account.account_type {
+                AccountType::Revenue => variance >= Decimal::ZERO,
+                _ => variance <= Decimal::ZERO,
+            };
+
+            lines.push(BudgetVarianceLine {
+                id: account.id.to_string(),
+                code: account.code.clone(),
+                name: account.name.clone(),
+                account_type: account.account_type.to_string(),
+                budgeted: money::format(budgeted),
+                actual: money::format(actual),
+                variance: money::format(variance),
+                favorable,
+            });
+        }
+
+        Self {
+            period_id: period.id.to_string(),
+            period_name: period.name.clone(),
+            period_start: period.start_date.to_string(),
+            period_end: period.end_date.to_string(),
+            lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account::{AccountCategory, NewAccount};
+    use chrono::{NaiveDate, Utc};
+
+    fn period() -> FiscalPeriod {
+        FiscalPeriod {
+            id: Uuid::new_v4(),
+            name: "March 2025".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+            is_closed: false,
+            closed_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn account(code: &str, category: AccountCategory) -> Account {
+        let account_type = AccountType::all()
+            .into_iter()
+            .find(|account_type| category.is_valid_for(*account_type))
+            .unwrap();
+        Account::new(NewAccount {
+            code: code.to_string(),
+            name: format!("Account {}", code),
+            description: None,
+            account_type,
+            category,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        })
+    }
+
+    fn budget(period: &FiscalPeriod, account: &Account, amount: Decimal) -> Budget {
+        Budget {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            period_id: period.id,
+            amount,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            created_by: None,
+            updated_by: None,
+        }
+    }
+
+    fn activity(account: &Account, debit: Decimal, credit: Decimal) -> AccountActivity {
+        AccountActivity {
+            account_id: account.id,
+            debit,
+            credit,
+        }
+    }
+
+    #[test]
+    fn expense_under_budget_is_a_favorable_variance() {
+        let period = period();
+        let rent = account("6000", AccountCategory::OperatingExpense);
+
+        let report = BudgetVarianceReport::from_activity(
+            &period,
+            std::slice::from_ref(&rent),
+            &[budget(&period, &rent, Decimal::new(1000, 0))],
+            &[activity(&rent, Decimal::new(800, 0), Decimal::ZERO)],
+        );
+
+        let line = &report.lines[0];
+        assert_eq!(line.budgeted, "1000.00");
+        assert_eq!(line.actual, "800.00");
+        assert_eq!(line.variance, "-200.00");
+        assert!(line.favorable);
+    }
+
+    #[test]
+    fn revenue_under_budget_is_unfavorable() {
+        let period = period();
+        let sales = account("4000", AccountCategory::OperatingRevenue);
+
+        let report = BudgetVarianceReport::from_activity(
+            &period,
+            std::slice::from_ref(&sales),
+            &[budget(&period, &sales, Decimal::new(1000, 0))],
+            &[activity(&sales, Decimal::ZERO, Decimal::new(800, 0))],
+        );
+
+        assert_eq!(report.lines[0].variance, "-200.00");
+        assert!(!report.lines[0].favorable);
+    }
+
+    #[test]
+    fn budget_without_activity_is_a_full_variance() {
+        let period = period();
+        let rent = account("6000", AccountCategory::OperatingExpense);
+
+        let report = BudgetVarianceReport::from_activity(
+            &period,
+            std::slice::from_ref(&rent),
+            &[budget(&period, &rent, Decimal::new(1000, 0))],
+            &[],
+        );
+
+        let line = &report.lines[0];
+        assert_eq!(line.actual, "0.00");
+        assert_eq!(line.variance, "-1000.00");
+        assert!(line.favorable);
+    }
+
+    #[test]
+    fn activity_without_budget_is_reported_against_zero() {
+        let period = period();
+        let rent = account("6000", AccountCategory::OperatingExpense);
+        let cash = account("1000", AccountCategory::CurrentAsset);
+        let idle = account("6100", AccountCategory::OperatingExpense);
+
+        let report = BudgetVarianceReport::from_activity(
+            &period,
+            &[cash.clone(), rent.clone(), idle],
+            &[],
+            &[
+                activity(&rent, Decimal::new(250, 0), Decimal::ZERO),
+                activity(&cash, Decimal::ZERO, Decimal::new(250, 0)),
+            ],
+        );
+
+        assert_eq!(report.lines.len(), 1);
+        assert_eq!(report.lines[0].code, "6000");
+        assert_eq!(report.lines[0].budgeted, "0.00");
+        assert_eq!(report.lines[0].variance, "250.00");
+        assert!(!report.lines[0].favorable);
+    }
+}
@@ -0,0 +1,74 @@
+// src-tauri/services/checksum.rs
+
+use crate::models::account::Account;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes a deterministic checksum over every (id, balance) pair, ordered by
+/// id so the result does not depend on how the accounts were loaded. Balances
+/// are normalized so `100.00` and `100` hash the same.
+pub fn balance_checksum(accounts: &[Account]) -> String {
+    let mut pairs: Vec<_> = accounts
+        .iter()
+        .map(|account| (account.id, account.balance.normalize()))
+        .collect();
+    pairs.sort_by_key(|(id, _)| *id);
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for (id, balance) in pairs {
+        for byte in format!("{}:{},", id, balance).bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account::{AccountCategory, AccountType, NewAccount};
+    use rust_decimal::Decimal;
+
+    fn account(code: &str, balance: Decimal) -> Account {
+        let mut account = Account::new(NewAccount {
+            code: code.to_string(),
+            name: code.to_string(),
+            description: None,
+            account_type: AccountType::Asset,
+            category: AccountCategory::CurrentAsset,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        });
+        account.balance = balance;
+        account
+    }
+
+    #[test]
+    fn identical_charts_share_a_checksum() {
+        let chart = vec![
+            account("1000", Decimal::new(10000, 2)),
+            account("1100", Decimal::new(-2550, 2)),
+        ];
+        let mut reloaded: Vec<Account> = chart.iter().rev().cloned().collect();
+        reloaded[0].balance = Decimal::new(-255, 1);
+
+        assert_eq!(balance_checksum(&chart), balance_checksum(&reloaded));
+    }
+
+    #[test]
+    fn changing_one_balance_changes_the_checksum() {
+        let chart = vec![
+            account("1000", Decimal::new(10000, 2)),
+            account("1100", Decimal::ZERO),
+        ];
+        let mut changed = chart.clone();
+        changed[1].balance = Decimal::new(1, 2);
+
+        assert_ne!(balance_checksum(&chart), balance_checksum(&changed));
+        assert_ne!(balance_checksum(&chart), balance_checksum(&chart[..1]));
+    }
+}
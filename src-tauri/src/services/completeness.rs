@@ -0,0 +1,202 @@
+// src-tauri/services/completeness.rs
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::account::{Account, AccountCategory, AccountType};
+
+/// A single item of the chart-of-accounts checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletenessItem {
+    pub key: String,
+    pub label: String,
+    pub satisfied: bool,
+    pub suggestion: Option<String>,
+}
+
+/// Completeness score (0-100) plus the checklist it was computed from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartCompleteness {
+    pub score: u32,
+    pub checklist: Vec<CompletenessItem>,
+}
+
+struct Check {
+    key: &'static str,
+    label: &'static str,
+    suggestion: &'static str,
+    detect: fn(&Account) -> bool,
+}
+
+fn name_contains(account: &Account, needles: &[&str]) -> bool {
+    let name = account.name.to_lowercase();
+    needles.iter().any(|needle| name.contains(needle))
+}
+
+const CHECKS: &[Check] = &[
+    Check {
+        key: "cash",
+        label: "Cash or bank account",
+        suggestion: "Add a current asset account for cash, e.g. 1000 Cash",
+        detect: |a| {
+            a.category == AccountCategory::CurrentAsset && name_contains(a, &["cash", "bank"])
+        },
+    },
+    Check {
+        key: "accounts_receivable",
+        label: "Accounts receivable",
+        suggestion: "Add a current asset account for receivables, e.g. 1100 Accounts Receivable",
+        detect: |a| {
+            a.category == AccountCategory::CurrentAsset && name_contains(a, &["receivable"])
+        },
+    },
+    Check {
+        key: "accounts_payable",
+        label: "Accounts payable",
+        suggestion: "Add a current liability account for payables, e.g. 2000 Accounts Payable",
+        detect: |a| {
+            a.category == AccountCategory::CurrentLiability && name_contains(a, &["payable"])
+        },
+    },
+    Check {
+        key: "owner_equity",
+        label: "Owner's equity",
+        suggestion: "Add an owner equity account, e.g. 3000 Owner's Capital",
+        detect: |a| a.category == AccountCategory::OwnerEquity,
+    },
+    Check {
+        key: "retained_earnings",
+        label: "Retained earnings",
+        suggestion: "Add a retained earnings account, e.g. 3900 Retained Earnings",
+        detect: |a| a.category == AccountCategory::RetainedEarnings,
+    },
+    Check {
+        key: "revenue",
+        label: "Revenue account",
+        suggestion: "Add at least one revenue account, e.g. 4000 Sales Revenue",
+        detect: |a| a.account_type == AccountType::Revenue,
+    },
+    Check {
+        key: "expense",
+        label: "Expense account",
+        suggestion: "Add at least one expense account, e.g. 6000 Operating Expenses",
+        detect: |a| a.account_type == AccountType::Expense,
+    },
+];
+
+/// Evaluates how well the active accounts cover the basics of a chart of accounts
+pub fn evaluate(accounts: &[Account]) -> ChartCompleteness {
+    let checklist: Vec<CompletenessItem> = CHECKS
+        .iter()
+        .map(|check| {
+            let satisfied = accounts
+                .iter()
+                .any(|account| account.is_active && (check.detect)(account));
+
+            CompletenessItem {
+                key: check.key.to_string(),
+                label: check.label.to_string(),
+                satisfied,
+                suggestion: (!satisfied).then(|| check.suggestion.to_string()),
+            }
+        })
+        .collect();
+
+    let satisfied = checklist.iter().filter(|item| item.satisfied).count();
+    let score = (satisfied * 100 / checklist.len()) as u32;
+
+    ChartCompleteness { score, checklist }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account::NewAccount;
+
+    fn account(code: &str, name: &str, category: AccountCategory) -> Account {
+        let account_type = AccountType::all()
+            .into_iter()
+            .find(|account_type| category.is_valid_for(*account_type))
+            .unwrap();
+        Account::new(NewAccount {
+            code: code.to_string(),
+            name: name.to_string(),
+            description: None,
+            account_type,
+            category,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        })
+    }
+
+    fn item<'a>(completeness: &'a ChartCompleteness, key: &str) -> &'a CompletenessItem {
+        completeness
+            .checklist
+            .iter()
+            .find(|item| item.key == key)
+            .unwrap()
+    }
+
+    #[test]
+    fn empty_chart_scores_zero_with_every_suggestion() {
+        let completeness = evaluate(&[]);
+
+        assert_eq!(completeness.score, 0);
+        assert_eq!(completeness.checklist.len(), CHECKS.len());
+        assert!(completeness
+            .checklist
+            .iter()
+            .all(|item| !item.satisfied && item.suggestion.is_some()));
+    }
+
+    #[test]
+    fn minimal_chart_scores_higher_than_empty_chart() {
+        let chart = [
+            account("1000", "Cash", AccountCategory::CurrentAsset),
+            account("4000", "Sales Revenue", AccountCategory::OperatingRevenue),
+            account("6000", "Rent", AccountCategory::OperatingExpense),
+        ];
+
+        let completeness = evaluate(&chart);
+
+        assert!(completeness.score > evaluate(&[]).score);
+        assert_eq!(completeness.score, 42);
+        assert!(item(&completeness, "cash").satisfied);
+        assert_eq!(item(&completeness, "cash").suggestion, None);
+        assert!(!item(&completeness, "accounts_payable").satisfied);
+    }
+
+    #[test]
+    fn full_chart_scores_one_hundred() {
+        let chart = [
+            account("1000", "Cash", AccountCategory::CurrentAsset),
+            account("1100", "Accounts Receivable", AccountCategory::CurrentAsset),
+            account(
+                "2000",
+                "Accounts Payable",
+                AccountCategory::CurrentLiability,
+            ),
+            account("3000", "Owner's Capital", AccountCategory::OwnerEquity),
+            account(
+                "3900",
+                "Retained Earnings",
+                AccountCategory::RetainedEarnings,
+            ),
+            account("4000", "Sales Revenue", AccountCategory::OperatingRevenue),
+            account("6000", "Rent", AccountCategory::OperatingExpense),
+        ];
+
+        assert_eq!(evaluate(&chart).score, 100);
+    }
+
+    #[test]
+    fn inactive_accounts_do_not_count() {
+        let mut cash = account("1000", "Cash", AccountCategory::CurrentAsset);
+        cash.is_active = false;
+
+        let completeness = evaluate(&[cash]);
+
+        assert_eq!(completeness.score, 0);
+        assert!(!item(&completeness, "cash").satisfied);
+    }
+}
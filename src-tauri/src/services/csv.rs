@@ -0,0 +1,84 @@
+// src-tauri/services/csv.rs
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::account::Account;
+use crate::money;
+
+/// Column headers of the chart-of-accounts CSV, in order. They follow the
+/// account import fields, with the parent given by code instead of id.
+pub const ACCOUNT_COLUMNS: &[&str] = &[
+    "code",
+    "name",
+    "description",
+    "account_type",
+    "category",
+    "subcategory",
+    "parent_code",
+    "currency",
+];
+
+/// Extra column appended when balances are requested
+pub const BALANCE_COLUMN: &str = "balance";
+
+/// Serializes accounts to CSV. Parent codes are resolved against the full list,
+/// so a filtered export still names parents that were filtered out.
+pub fn export_accounts(accounts: &[Account], active_only: bool, include_balances: bool) -> String {
+    let codes_by_id: HashMap<Uuid, &str> = accounts
+        .iter()
+        .map(|account| (account.id, account.code.as_str()))
+        .collect();
+
+    let mut header: Vec<&str> = ACCOUNT_COLUMNS.to_vec();
+    if include_balances {
+        header.push(BALANCE_COLUMN);
+    }
+
+    let mut output = String::new();
+    push_record(&mut output, header.iter().map(|column| column.to_string()));
+
+    for account in accounts
+        .iter()
+        .filter(|account| !active_only || account.is_active)
+    {
+        let parent_code = account
+            .parent_id
+            .and_then(|id| codes_by_id.get(&id))
+            .map(|code| code.to_string())
+            .unwrap_or_default();
+
+        let mut fields = vec![
+            account.code.clone(),
+            account.name.clone(),
+            account.description.clone().unwrap_or_default(),
+            account.account_type.to_string(),
+            account.category.to_string(),
+            account.subcategory.clone().unwrap_or_default(),
+            parent_code,
+            account.currency.clone(),
+        ];
+        if include_balances {
+            fields.push(money::format(account.balance));
+        }
+
+        push_record(&mut output, fields.into_iter());
+    }
+
+    output
+}
+
+/// Appends one CRLF-terminated record, quoting fields as RFC 4180 requires
+fn push_record(output: &mut String, fields: impl Iterator<Item = String>) {
+    let escaped: Vec<String> = fields.map(|field| escape_field(&field)).collect();
+    output.push_str(&escaped.join(","));
+    output.push_str("\r\n");
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
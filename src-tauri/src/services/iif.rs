@@ -0,0 +1,211 @@
+// src-tauri/services/iif.rs
+
+use crate::error::{validation_error, Result};
+use crate::models::account::{AccountCategory, AccountType};
+
+/// QuickBooks account types and their mapping onto our chart of accounts.
+/// Each entry lists the IIF short code, the display name used in exports,
+/// and the resulting type/category.
+#[rustfmt::skip]
+const IIF_TYPE_MAP: &[(&str, &str, AccountType, AccountCategory)] = &[
+    ("BANK",     "Bank",                    AccountType::Asset,     AccountCategory::CurrentAsset),
+    ("AR",       "Accounts Receivable",     AccountType::Asset,     AccountCategory::CurrentAsset),
+    ("OCASSET",  "Other Current Asset",     AccountType::Asset,     AccountCategory::CurrentAsset),
+    ("FIXASSET", "Fixed Asset",             AccountType::Asset,     AccountCategory::FixedAsset),
+    ("OASSET",   "Other Asset",             AccountType::Asset,     AccountCategory::OtherAsset),
+    ("AP",       "Accounts Payable",        AccountType::Liability, AccountCategory::CurrentLiability),
+    ("CCARD",    "Credit Card",             AccountType::Liability, AccountCategory::CurrentLiability),
+    ("OCLIAB",   "Other Current Liability", AccountType::Liability, AccountCategory::CurrentLiability),
+    ("LTLIAB",   "Long Term Liability",     AccountType::Liability, AccountCategory::LongTermLiability),
+    ("EQUITY",   "Equity",                  AccountType::Equity,    AccountCategory::OwnerEquity),
+    ("INC",      "Income",                  AccountType::Revenue,   AccountCategory::OperatingRevenue),
+    ("EXINC",    "Other Income",            AccountType::Revenue,   AccountCategory::NonOperatingRevenue),
+    ("COGS",     "Cost of Goods Sold",      AccountType::Expense,   AccountCategory::OperatingExpense),
+    ("EXP",      "Expense",                 AccountType::Expense,   AccountCategory::OperatingExpense),
+    ("EXEXP",    "Other Expense",           AccountType::Expense,   AccountCategory::NonOperatingExpense),
+];
+
+/// Maps a QuickBooks account type (short code or display name) to our type and category
+pub fn map_account_type(foreign_type: &str) -> Option<(AccountType, AccountCategory)> {
+    let normalized = foreign_type.trim();
+
+    IIF_TYPE_MAP
+        .iter()
+        .find(|(code, name, _, _)| {
+            code.eq_ignore_ascii_case(normalized) || name.eq_ignore_ascii_case(normalized)
+        })
+        .map(|(_, _, account_type, category)| (*account_type, *category))
+}
+
+/// A single account row read from an IIF file
+#[derive(Debug, Clone, PartialEq)]
+pub struct IifAccount {
+    /// Full QuickBooks name; sub-accounts use `Parent:Child`
+    pub name: String,
+    pub foreign_type: String,
+    pub description: Option<String>,
+    pub number: Option<String>,
+}
+
+impl IifAccount {
+    /// The name of the parent account, if this is a sub-account
+    pub fn parent_name(&self) -> Option<&str> {
+        self.name.rsplit_once(':').map(|(parent, _)| parent)
+    }
+
+    /// The account's own name without its parent prefix
+    pub fn short_name(&self) -> &str {
+        self.name
+            .rsplit_once(':')
+            .map(|(_, name)| name)
+            .unwrap_or(&self.name)
+    }
+}
+
+/// Parses the `ACCNT` rows of a tab-delimited IIF export
+pub fn parse_accounts(content: &str) -> Result<Vec<IifAccount>> {
+    let mut columns: Option<Vec<String>> = None;
+    let mut accounts = Vec::new();
+
+    for line in content.lines() {
+        let fields: Vec<&str> = line.trim_end_matches('\r').split('\t').collect();
+
+        match fields.first().map(|f| f.trim()) {
+            Some("!ACCNT") => {
+                columns = Some(
+                    fields[1..]
+                        .iter()
+                        .map(|f| f.trim().to_uppercase())
+                        .collect(),
+                );
+            }
+            Some("ACCNT") => {
+                let columns = columns
+                    .as_ref()
+                    .ok_or_else(|| validation_error("IIF ACCNT row found before !ACCNT header"))?;
+
+                let field = |column: &str| {
+                    columns
+                        .iter()
+                        .position(|c| c == column)
+                        .and_then(|i| fields.get(i + 1))
+                        .map(|value| value.trim())
+                        .filter(|value| !value.is_empty())
+                        .map(|value| value.to_string())
+                };
+
+                let name = field("NAME")
+                    .ok_or_else(|| validation_error("IIF account row is missing NAME"))?;
+                let foreign_type = field("ACCNTTYPE")
+                    .ok_or_else(|| validation_error("IIF account row is missing ACCNTTYPE"))?;
+
+                accounts.push(IifAccount {
+                    name,
+                    foreign_type,
+                    description: field("DESC"),
+                    number: field("ACCNUM"),
+                });
+            }
+            // Other record types (transactions, customers, ...) are not part of the chart
+            _ => {}
+        }
+    }
+
+    if columns.is_none() {
+        return Err(validation_error("No !ACCNT header found in IIF content"));
+    }
+
+    Ok(accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    const HEADER: &str = "!ACCNT\tNAME\tACCNTTYPE\tDESC\tACCNUM";
+
+    #[test]
+    fn account_types_map_by_code_or_display_name() {
+        assert_eq!(
+            map_account_type("BANK"),
+            Some((AccountType::Asset, AccountCategory::CurrentAsset))
+        );
+        assert_eq!(
+            map_account_type(" long term liability "),
+            Some((AccountType::Liability, AccountCategory::LongTermLiability))
+        );
+        assert_eq!(
+            map_account_type("exexp"),
+            Some((AccountType::Expense, AccountCategory::NonOperatingExpense))
+        );
+        assert_eq!(map_account_type("NONPOSTING"), None);
+    }
+
+    #[test]
+    fn every_mapping_pairs_a_category_with_its_own_type() {
+        for (code, _, account_type, category) in IIF_TYPE_MAP {
+            assert!(category.is_valid_for(*account_type), "{}", code);
+        }
+    }
+
+    #[test]
+    fn parses_account_rows_and_skips_other_records() {
+        let content = format!(
+            "{}\r\nACCNT\tChecking\tBANK\tMain account\t1010\r\n!TRNS\tDATE\r\nTRNS\t1/1/2025\r\nACCNT\tUtilities:Electric\tEXP\t\t6110\r\n",
+            HEADER
+        );
+        let accounts = parse_accounts(&content).unwrap();
+
+        assert_eq!(
+            accounts,
+            vec![
+                IifAccount {
+                    name: "Checking".to_string(),
+                    foreign_type: "BANK".to_string(),
+                    description: Some("Main account".to_string()),
+                    number: Some("1010".to_string()),
+                },
+                IifAccount {
+                    name: "Utilities:Electric".to_string(),
+                    foreign_type: "EXP".to_string(),
+                    description: None,
+                    number: Some("6110".to_string()),
+                },
+            ]
+        );
+        assert_eq!(accounts[1].parent_name(), Some("Utilities"));
+        assert_eq!(accounts[1].short_name(), "Electric");
+        assert_eq!(accounts[0].parent_name(), None);
+    }
+
+    #[test]
+    fn columns_are_found_by_header_name() {
+        let content = "!ACCNT\tACCNUM\tACCNTTYPE\tNAME\nACCNT\t4000\tINC\tSales\n";
+        let accounts = parse_accounts(content).unwrap();
+
+        assert_eq!(accounts[0].name, "Sales");
+        assert_eq!(accounts[0].number.as_deref(), Some("4000"));
+    }
+
+    #[test]
+    fn rows_missing_a_name_or_type_are_rejected() {
+        let missing_type = format!("{}\nACCNT\tChecking\t\t\t1010\n", HEADER);
+        assert!(matches!(
+            parse_accounts(&missing_type),
+            Err(Error::Validation(message)) if message.contains("ACCNTTYPE")
+        ));
+
+        let missing_name = format!("{}\nACCNT\t\tBANK\t\t1010\n", HEADER);
+        assert!(matches!(
+            parse_accounts(&missing_name),
+            Err(Error::Validation(message)) if message.contains("NAME")
+        ));
+    }
+
+    #[test]
+    fn content_without_an_account_header_is_rejected() {
+        assert!(parse_accounts("ACCNT\tChecking\tBANK\n").is_err());
+        assert!(parse_accounts("!TRNS\tDATE\nTRNS\t1/1/2025\n").is_err());
+    }
+}
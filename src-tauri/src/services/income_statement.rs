@@ -0,0 +1,142 @@
+// src-tauri/services/income_statement.rs
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::account::{Account, AccountCategory};
+use crate::models::journal::AccountActivity;
+use crate::money;
+
+/// One revenue or expense account with its activity over the period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeStatementLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub amount: String,
+}
+
+/// The accounts of one revenue or expense category with their subtotal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeStatementGroup {
+    pub category: String,
+    pub accounts: Vec<IncomeStatementLine>,
+    pub total: String,
+}
+
+/// Profit and loss over an inclusive date range, built from posted journal lines
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IncomeStatement {
+    pub period_start: String,
+    pub period_end: String,
+    pub operating_revenue: IncomeStatementGroup,
+    pub operating_expenses: IncomeStatementGroup,
+    /// Operating revenue less operating expenses. The chart has no separate
+    /// cost-of-sales category, so operating expenses stand in for it.
+    pub gross_profit: String,
+    pub non_operating_revenue: IncomeStatementGroup,
+    pub non_operating_expenses: IncomeStatementGroup,
+    pub total_revenue: String,
+    pub total_expenses: String,
+    pub net_profit: String,
+    #[serde(skip)]
+    pub totals: IncomeStatementTotals,
+}
+
+/// Unformatted totals for callers that compute further figures
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IncomeStatementTotals {
+    pub revenue: Decimal,
+    pub expenses: Decimal,
+    pub net_profit: Decimal,
+}
+
+impl IncomeStatement {
+    /// Builds the statement from per-account activity. Amounts are taken on each
+    /// account's normal side, so revenue and expenses are both positive. A range
+    /// without postings yields zeros throughout.
+    pub fn from_activity(
+        accounts: &[Account],
+        activity: &[AccountActivity],
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Self {
+        let totals_by_account: HashMap<Uuid, (Decimal, Decimal)> = activity
+            .iter()
+            .map(|row| (row.account_id, (row.debit, row.credit)))
+            .collect();
+
+        let group = |category: AccountCategory| build_group(accounts, &totals_by_account, category);
+
+        let (operating_revenue, operating_revenue_total) = group(AccountCategory::OperatingRevenue);
+        let (operating_expenses, operating_expenses_total) =
+            group(AccountCategory::OperatingExpense);
+        let (non_operating_revenue, non_operating_revenue_total) =
+            group(AccountCategory::NonOperatingRevenue);
+        let (non_operating_expenses, non_operating_expenses_total) =
+            group(AccountCategory::NonOperatingExpense);
+
+        let revenue = operating_revenue_total + non_operating_revenue_total;
+        let expenses = operating_expenses_total + non_operating_expenses_total;
+        let net_profit = revenue - expenses;
+
+        Self {
+            period_start: period_start.to_string(),
+            period_end: period_end.to_string(),
+            operating_revenue,
+            operating_expenses,
+            gross_profit: money::format(operating_revenue_total - operating_expenses_total),
+            non_operating_revenue,
+            non_operating_expenses,
+            total_revenue: money::format(revenue),
+            total_expenses: money::format(expenses),
+            net_profit: money::format(net_profit),
+            totals: IncomeStatementTotals {
+                revenue,
+                expenses,
+                net_profit,
+            },
+        }
+    }
+}
+
+fn build_group(
+    accounts: &[Account],
+    totals_by_account: &HashMap<Uuid, (Decimal, Decimal)>,
+    category: AccountCategory,
+) -> (IncomeStatementGroup, Decimal) {
+    let mut lines = Vec::new();
+    let mut total = Decimal::ZERO;
+
+    for account in accounts
+        .iter()
+        .filter(|account| account.category == category)
+    {
+        let amount = match totals_by_account.get(&account.id) {
+            Some(&(debit, credit)) => account.account_type.balance_delta(debit, credit),
+            None => continue,
+        };
+        if amount.is_zero() {
+            continue;
+        }
+
+        total += amount;
+        lines.push(IncomeStatementLine {
+            id: account.id.to_string(),
+            code: account.code.clone(),
+            name: account.name.clone(),
+            amount: money::format(amount),
+        });
+    }
+
+    let group = IncomeStatementGroup {
+        category: category.to_string(),
+        accounts: lines,
+        total: money::format(total),
+    };
+
+    (group, total)
+}
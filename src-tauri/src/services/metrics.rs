@@ -0,0 +1,96 @@
+// src-tauri/services/metrics.rs
+
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Calendar period the dashboard metrics compare against its predecessor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonPeriod {
+    Month,
+    Quarter,
+    Year,
+}
+
+/// An inclusive date range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateWindow {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl ComparisonPeriod {
+    /// Parses a period name as sent by the frontend
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "month" => Some(Self::Month),
+            "quarter" => Some(Self::Quarter),
+            "year" => Some(Self::Year),
+            _ => None,
+        }
+    }
+
+    /// Label shown next to the metrics, e.g. "Month to date"
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Month => "Month to date",
+            Self::Quarter => "Quarter to date",
+            Self::Year => "Year to date",
+        }
+    }
+
+    fn months(&self) -> u32 {
+        match self {
+            Self::Month => 1,
+            Self::Quarter => 3,
+            Self::Year => 12,
+        }
+    }
+
+    /// Start of the period containing `date`. Quarters and years are counted
+    /// from the fiscal year start, a month from 1 to 12.
+    fn start_of(&self, date: NaiveDate, fiscal_year_start_month: u32) -> NaiveDate {
+        let first_of_month = NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap_or(date);
+        let months_into_year = (date.month() + 12 - fiscal_year_start_month) % 12;
+        first_of_month
+            .checked_sub_months(Months::new(months_into_year % self.months()))
+            .unwrap_or(first_of_month)
+    }
+
+    /// The current period up to `today` and the same stretch of the prior
+    /// period, ending on the same day of the month. Where the prior month is
+    /// shorter it ends on its last day, so March 31st compares against all of
+    /// February.
+    pub fn windows(
+        &self,
+        today: NaiveDate,
+        fiscal_year_start_month: u32,
+    ) -> (DateWindow, DateWindow) {
+        let months = Months::new(self.months());
+        let start = self.start_of(today, fiscal_year_start_month);
+        let prior_start = start.checked_sub_months(months).unwrap_or(start);
+        let prior_end = today.checked_sub_months(months).unwrap_or(prior_start);
+
+        (
+            DateWindow { start, end: today },
+            DateWindow {
+                start: prior_start,
+                end: prior_end,
+            },
+        )
+    }
+}
+
+/// Percentage change from `prior` to `current`, rounded to one decimal place.
+/// Measured against the magnitude of `prior` so that a loss shrinking towards
+/// zero reads as an improvement. `None` when `prior` is zero, since no
+/// percentage describes growth from nothing.
+pub fn percent_change(current: Decimal, prior: Decimal) -> Option<f64> {
+    if prior.is_zero() {
+        return None;
+    }
+
+    ((current - prior) / prior.abs() * Decimal::ONE_HUNDRED)
+        .round_dp_with_strategy(1, RoundingStrategy::MidpointAwayFromZero)
+        .to_f64()
+}
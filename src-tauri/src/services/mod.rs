@@ -0,0 +1,13 @@
+pub mod auth;
+pub mod backup;
+pub mod balance_sheet;
+pub mod budget_variance;
+pub mod checksum;
+pub mod completeness;
+pub mod csv;
+pub mod iif;
+pub mod income_statement;
+pub mod metrics;
+pub mod numbering;
+pub mod seed;
+pub mod trial_balance;
@@ -0,0 +1,58 @@
+// src-tauri/services/numbering.rs
+
+use std::collections::HashSet;
+
+use crate::config::{ChartConfig, CodeRange};
+use crate::error::{Error, Result};
+use crate::models::account::AccountType;
+
+/// Checks that a code lies in the configured range of its account type. Does
+/// nothing unless range enforcement is switched on.
+pub fn check_code_range(chart: &ChartConfig, code: &str, account_type: AccountType) -> Result<()> {
+    if !chart.enforce_code_ranges {
+        return Ok(());
+    }
+
+    let range = chart.code_range(account_type);
+    match code.parse::<u32>() {
+        Ok(number) if range.contains(number) => Ok(()),
+        Ok(_) => Err(Error::Validation(format!(
+            "Account code {} is outside the {} range for {} accounts",
+            code, range, account_type
+        ))),
+        Err(_) => Err(Error::Validation(format!(
+            "Account code {} must be numeric when code ranges are enforced",
+            code
+        ))),
+    }
+}
+
+/// Picks the next free code in `range`, following the highest numeric code in
+/// `siblings`. Without siblings it starts right after the parent's code, or at
+/// the start of the range for a top-level account. Codes in `taken` are
+/// skipped; `None` means the range is full.
+pub fn next_code(
+    range: CodeRange,
+    siblings: &[String],
+    parent_code: Option<&str>,
+    taken: &HashSet<String>,
+) -> Option<String> {
+    let highest_sibling = siblings
+        .iter()
+        .filter_map(|code| code.parse::<u32>().ok())
+        .filter(|code| range.contains(*code))
+        .max();
+
+    let start = match (
+        highest_sibling,
+        parent_code.and_then(|code| code.parse::<u32>().ok()),
+    ) {
+        (Some(highest), _) => highest + 1,
+        (None, Some(parent)) if range.contains(parent) => parent + 1,
+        _ => range.start,
+    };
+
+    (start.max(range.start)..=range.end)
+        .map(|code| code.to_string())
+        .find(|code| !taken.contains(code))
+}
@@ -0,0 +1,97 @@
+// src-tauri/services/seed.rs
+
+use crate::config::ChartConfig;
+use crate::error::{Error, Result};
+use crate::models::account::AccountCategory::{self, *};
+use crate::models::account::AccountType::{self, *};
+use crate::models::account::NewAccount;
+use crate::models::currency::DEFAULT_CURRENCY;
+
+/// An account of the default chart; its code is `offset` past the start of
+/// the range configured for its type
+struct SeedAccount {
+    offset: u32,
+    name: &'static str,
+    account_type: AccountType,
+    category: AccountCategory,
+}
+
+const fn seed(
+    offset: u32,
+    name: &'static str,
+    account_type: AccountType,
+    category: AccountCategory,
+) -> SeedAccount {
+    SeedAccount {
+        offset,
+        name,
+        account_type,
+        category,
+    }
+}
+
+/// A small-business chart of accounts. With the default ranges the codes run
+/// 1000 Cash, 1100 Accounts Receivable, ..., 5900 Interest Expense.
+const DEFAULT_CHART: &[SeedAccount] = &[
+    seed(0, "Cash", Asset, CurrentAsset),
+    seed(100, "Accounts Receivable", Asset, CurrentAsset),
+    seed(200, "Inventory", Asset, CurrentAsset),
+    seed(300, "Prepaid Expenses", Asset, CurrentAsset),
+    seed(500, "Equipment", Asset, FixedAsset),
+    seed(600, "Furniture and Fixtures", Asset, FixedAsset),
+    seed(0, "Accounts Payable", Liability, CurrentLiability),
+    seed(100, "Accrued Liabilities", Liability, CurrentLiability),
+    seed(200, "Sales Tax Payable", Liability, CurrentLiability),
+    seed(300, "Payroll Liabilities", Liability, CurrentLiability),
+    seed(500, "Long-Term Loans", Liability, LongTermLiability),
+    seed(0, "Owner's Capital", Equity, OwnerEquity),
+    seed(100, "Owner's Drawings", Equity, OwnerEquity),
+    seed(900, "Retained Earnings", Equity, RetainedEarnings),
+    seed(0, "Sales Revenue", Revenue, OperatingRevenue),
+    seed(100, "Service Revenue", Revenue, OperatingRevenue),
+    seed(500, "Interest Income", Revenue, NonOperatingRevenue),
+    seed(600, "Other Income", Revenue, NonOperatingRevenue),
+    seed(0, "Cost of Goods Sold", Expense, OperatingExpense),
+    seed(100, "Rent Expense", Expense, OperatingExpense),
+    seed(200, "Utilities Expense", Expense, OperatingExpense),
+    seed(300, "Salaries and Wages", Expense, OperatingExpense),
+    seed(400, "Office Supplies", Expense, OperatingExpense),
+    seed(500, "Advertising", Expense, OperatingExpense),
+    seed(600, "Insurance", Expense, OperatingExpense),
+    seed(700, "Depreciation Expense", Expense, OperatingExpense),
+    seed(800, "Bank Fees", Expense, OperatingExpense),
+    seed(900, "Interest Expense", Expense, NonOperatingExpense),
+];
+
+/// Builds the default chart, numbering each account within the code range
+/// configured for its type. Fails when a range is too small to hold it.
+pub fn default_chart(chart: &ChartConfig) -> Result<Vec<NewAccount>> {
+    DEFAULT_CHART
+        .iter()
+        .map(|account| {
+            let range = chart.code_range(account.account_type);
+            let code = range.start + account.offset;
+            if !range.contains(code) {
+                return Err(Error::Validation(format!(
+                    "The {} code range {} is too small for the default chart",
+                    account.account_type, range
+                )));
+            }
+
+            Ok(NewAccount {
+                code: code.to_string(),
+                name: account.name.to_string(),
+                description: None,
+                account_type: account.account_type,
+                category: account.category,
+                // Accounts named after a subcategory are filed under it
+                subcategory: account
+                    .category
+                    .canonical_subcategory(account.name)
+                    .map(str::to_string),
+                parent_id: None,
+                currency: DEFAULT_CURRENCY.to_string(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,224 @@
+// src-tauri/services/trial_balance.rs
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::account::Account;
+use crate::models::journal::AccountActivity;
+use crate::money;
+
+/// A single account line of the trial balance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialBalanceRow {
+    pub code: String,
+    pub name: String,
+    pub debit: Decimal,
+    pub credit: Decimal,
+}
+
+/// Trial balance built from account balances, current or as of a date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrialBalance {
+    pub rows: Vec<TrialBalanceRow>,
+    pub total_debit: Decimal,
+    pub total_credit: Decimal,
+}
+
+impl TrialBalance {
+    /// Builds the trial balance from the current account balances
+    pub fn from_accounts(accounts: &[Account]) -> Self {
+        Self::from_balances(accounts.iter().map(|account| (account, account.balance)))
+    }
+
+    /// Builds the trial balance from posted activity, each account's balance
+    /// being the net of its debits and credits on its normal side. Accounts
+    /// without activity are left out.
+    pub fn from_activity(accounts: &[Account], activity: &[AccountActivity]) -> Self {
+        let totals_by_account: HashMap<Uuid, (Decimal, Decimal)> = activity
+            .iter()
+            .map(|row| (row.account_id, (row.debit, row.credit)))
+            .collect();
+
+        Self::from_balances(accounts.iter().filter_map(|account| {
+            totals_by_account.get(&account.id).map(|&(debit, credit)| {
+                (account, account.account_type.balance_delta(debit, credit))
+            })
+        }))
+    }
+
+    /// Places each non-zero balance on its account's normal side (or the
+    /// opposite side when the balance is negative)
+    fn from_balances<'a>(balances: impl Iterator<Item = (&'a Account, Decimal)>) -> Self {
+        let rows: Vec<TrialBalanceRow> = balances
+            .filter(|(_, balance)| !balance.is_zero())
+            .map(|(account, balance)| {
+                let on_normal_side = balance.is_sign_positive();
+                let amount = balance.abs();
+                let is_debit = account.is_debit_normal() == on_normal_side;
+
+                TrialBalanceRow {
+                    code: account.code.clone(),
+                    name: account.name.clone(),
+                    debit: if is_debit { amount } else { Decimal::ZERO },
+                    credit: if is_debit { Decimal::ZERO } else { amount },
+                }
+            })
+            .collect();
+
+        let total_debit = rows.iter().map(|row| row.debit).sum();
+        let total_credit = rows.iter().map(|row| row.credit).sum();
+
+        Self {
+            rows,
+            total_debit,
+            total_credit,
+        }
+    }
+
+    /// Renders a self-contained HTML table with inline styles, suitable for email
+    pub fn to_html(&self) -> String {
+        const CELL: &str = "padding:4px 8px;border-bottom:1px solid #ddd;";
+        const AMOUNT: &str = "padding:4px 8px;border-bottom:1px solid #ddd;text-align:right;";
+        const TOTAL: &str =
+            "padding:4px 8px;border-top:2px solid #333;font-weight:bold;text-align:right;";
+
+        let mut html = String::new();
+        html.push_str("<table style=\"border-collapse:collapse;font-family:Arial,sans-serif;font-size:14px;\">");
+        html.push_str("<thead><tr style=\"background:#f3f4f6;\">");
+        html.push_str(&format!("<th style=\"{}text-align:left;\">Code</th>", CELL));
+        html.push_str(&format!(
+            "<th style=\"{}text-align:left;\">Account</th>",
+            CELL
+        ));
+        html.push_str(&format!("<th style=\"{}\">Debit</th>", AMOUNT));
+        html.push_str(&format!("<th style=\"{}\">Credit</th>", AMOUNT));
+        html.push_str("</tr></thead><tbody>");
+
+        for row in &self.rows {
+            html.push_str(&format!(
+                "<tr><td style=\"{cell}\">{}</td><td style=\"{cell}\">{}</td><td style=\"{amount}\">{}</td><td style=\"{amount}\">{}</td></tr>",
+                escape_html(&row.code),
+                escape_html(&row.name),
+                format_amount(row.debit),
+                format_amount(row.credit),
+                cell = CELL,
+                amount = AMOUNT,
+            ));
+        }
+
+        html.push_str(&format!(
+            "<tr><td style=\"{total}text-align:left;\" colspan=\"2\">Total</td><td style=\"{total}\">{}</td><td style=\"{total}\">{}</td></tr>",
+            money::format(self.total_debit),
+            money::format(self.total_credit),
+            total = TOTAL,
+        ));
+        html.push_str("</tbody></table>");
+
+        html
+    }
+}
+
+fn format_amount(amount: Decimal) -> String {
+    if amount.is_zero() {
+        String::new()
+    } else {
+        money::format(amount)
+    }
+}
+
+/// Escapes text for safe inclusion in HTML element content and attributes
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::account::{AccountCategory, AccountType, NewAccount};
+
+    fn account(code: &str, name: &str, balance: Decimal) -> Account {
+        account_of_type(code, name, AccountType::Asset, balance)
+    }
+
+    fn account_of_type(
+        code: &str,
+        name: &str,
+        account_type: AccountType,
+        balance: Decimal,
+    ) -> Account {
+        let mut account = Account::new(NewAccount {
+            code: code.to_string(),
+            name: name.to_string(),
+            description: None,
+            account_type,
+            category: AccountCategory::for_account_type(account_type)[0],
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        });
+        account.balance = balance;
+        account
+    }
+
+    #[test]
+    fn html_export_escapes_account_text() {
+        let accounts = [account(
+            "1000",
+            "<script>alert('x')</script> & \"Co\"",
+            Decimal::new(150, 0),
+        )];
+
+        let html = TrialBalance::from_accounts(&accounts).to_html();
+
+        assert!(!html.contains("<script>"));
+        assert!(
+            html.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; &quot;Co&quot;")
+        );
+    }
+
+    #[test]
+    fn escape_html_leaves_plain_text_alone() {
+        assert_eq!(escape_html("Cash on hand 1000"), "Cash on hand 1000");
+        assert_eq!(escape_html("A&B"), "A&amp;B");
+    }
+
+    #[test]
+    fn activity_balances_replace_the_current_ones() {
+        let cash = account_of_type("1000", "Cash", AccountType::Asset, Decimal::new(900, 0));
+        let sales = account_of_type("4000", "Sales", AccountType::Revenue, Decimal::new(900, 0));
+        let idle = account_of_type("5000", "Rent", AccountType::Expense, Decimal::new(50, 0));
+        let activity = [
+            AccountActivity {
+                account_id: cash.id,
+                debit: Decimal::new(500, 0),
+                credit: Decimal::new(100, 0),
+            },
+            AccountActivity {
+                account_id: sales.id,
+                debit: Decimal::ZERO,
+                credit: Decimal::new(400, 0),
+            },
+        ];
+
+        let trial_balance = TrialBalance::from_activity(&[cash, sales, idle], &activity);
+
+        assert_eq!(trial_balance.rows.len(), 2);
+        assert_eq!(trial_balance.rows[0].debit, Decimal::new(400, 0));
+        assert_eq!(trial_balance.rows[1].credit, Decimal::new(400, 0));
+        assert_eq!(trial_balance.total_debit, trial_balance.total_credit);
+    }
+}
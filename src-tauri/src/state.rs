@@ -1,13 +1,34 @@
 use sqlx::postgres::PgPool;
+use tokio::sync::Mutex;
+
+use crate::config::AppConfig;
+use crate::diagnostics::RecentErrors;
+use crate::error::ErrorResponse;
 
 /// Application state that will be shared across Tauri commands
 #[derive(Debug)]
 pub struct AppState {
     pub db_pool: PgPool,
+    pub config: AppConfig,
+    pub recent_errors: RecentErrors,
+    /// Held while a backup is being written, so only one runs at a time
+    pub backup_lock: Mutex<()>,
 }
 
 impl AppState {
-    pub fn new(pool: PgPool) -> Self {
-        Self { db_pool: pool }
+    pub fn new(pool: PgPool, config: AppConfig) -> Self {
+        Self {
+            db_pool: pool,
+            config,
+            recent_errors: RecentErrors::default(),
+            backup_lock: Mutex::new(()),
+        }
+    }
+
+    /// Converts an error into the client-facing response, recording it for diagnostics
+    pub fn record_error(&self, command: &str, err: impl Into<ErrorResponse>) -> ErrorResponse {
+        let response = err.into();
+        self.recent_errors.record(command, &response);
+        response
     }
 }
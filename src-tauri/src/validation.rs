@@ -0,0 +1,183 @@
+// src/validation.rs
+use crate::error::{Error, FieldError, Result};
+
+/// Invisible formatting characters that commonly sneak in via copy-paste
+const INVISIBLE_CHARS: &[char] = &[
+    '\u{00AD}', // soft hyphen
+    '\u{200B}', // zero-width space
+    '\u{200C}', // zero-width non-joiner
+    '\u{200D}', // zero-width joiner
+    '\u{200E}', // left-to-right mark
+    '\u{200F}', // right-to-left mark
+    '\u{2060}', // word joiner
+    '\u{FEFF}', // zero-width no-break space / BOM
+];
+
+/// Strips control and invisible characters from user-supplied text and trims it.
+/// Returns a validation error naming `field` if nothing but such characters was given.
+pub fn sanitize_text(field: &str, value: &str) -> Result<String> {
+    let sanitized: String = value
+        .chars()
+        .filter(|c| !c.is_control() && !INVISIBLE_CHARS.contains(c))
+        .collect::<String>()
+        .trim()
+        .to_string();
+
+    if sanitized.is_empty() && !value.trim().is_empty() {
+        return Err(Error::Validation(format!(
+            "{} contains only invisible or control characters",
+            field
+        )));
+    }
+
+    Ok(sanitized)
+}
+
+/// Sanitizes an optional text field, treating an empty result as `None`
+pub fn sanitize_optional_text(field: &str, value: Option<String>) -> Result<Option<String>> {
+    match value {
+        Some(value) => {
+            let sanitized = sanitize_text(field, &value)?;
+            Ok(if sanitized.is_empty() {
+                None
+            } else {
+                Some(sanitized)
+            })
+        }
+        None => Ok(None),
+    }
+}
+
+/// Collects the failures of several field checks, so that a form can be told
+/// about every bad field at once rather than one per submission
+#[derive(Debug, Default)]
+pub struct FieldErrors {
+    errors: Vec<FieldError>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `field` is invalid
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Returns the value of a passing check, or records why `field` failed
+    pub fn check<T>(&mut self, field: &str, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(Error::Validation(message)) => {
+                self.add(field, message);
+                None
+            }
+            Err(err) => {
+                self.add(field, err.to_string());
+                None
+            }
+        }
+    }
+
+    /// Returns the value of a passing validation, or records every field it
+    /// reports as bad. Errors that name no field are passed on.
+    pub fn merge<T>(&mut self, result: Result<T>) -> Result<Option<T>> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::InvalidFields(errors)) => {
+                self.errors.extend(errors);
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Whether a failure has been recorded for `field`
+    pub fn contains(&self, field: &str) -> bool {
+        self.errors.iter().any(|error| error.field == field)
+    }
+
+    /// Puts the failures in the order `fields` are listed, e.g. as they appear
+    /// on a form, keeping the order of failures of the same field
+    pub fn sort_by_field(&mut self, fields: &[&str]) {
+        self.errors.sort_by_key(|error| {
+            fields
+                .iter()
+                .position(|field| *field == error.field)
+                .unwrap_or(fields.len())
+        });
+    }
+
+    /// The error listing every failure so far
+    pub fn into_error(self) -> Error {
+        Error::InvalidFields(self.errors)
+    }
+
+    /// `Ok` if every check passed, otherwise the error listing the failures
+    pub fn finish(self) -> Result<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.into_error())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn control_characters_are_removed() {
+        assert_eq!(
+            sanitize_text("Name", "Petty\u{0007} cash\u{0000}").unwrap(),
+            "Petty cash"
+        );
+        assert_eq!(sanitize_text("Name", "Line\none").unwrap(), "Lineone");
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_trimmed() {
+        assert_eq!(sanitize_text("Name", "  Cash \t").unwrap(), "Cash");
+        assert_eq!(sanitize_text("Name", "").unwrap(), "");
+        assert_eq!(sanitize_text("Name", "   ").unwrap(), "");
+    }
+
+    #[test]
+    fn invisible_characters_are_removed_and_other_unicode_kept() {
+        assert_eq!(
+            sanitize_text("Name", "\u{FEFF}Caf\u{00E9}\u{200B} Kasse").unwrap(),
+            "Caf\u{00E9} Kasse"
+        );
+        assert_eq!(sanitize_text("Name", "現金").unwrap(), "現金");
+    }
+
+    #[test]
+    fn text_of_only_control_or_invisible_characters_is_rejected() {
+        for value in ["\u{0007}\u{0008}", "\u{200B}", " \u{200D}\u{0000} "] {
+            let err = sanitize_text("Account name", value).unwrap_err();
+            assert!(
+                matches!(&err, Error::Validation(msg) if msg.starts_with("Account name")),
+                "{:?}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn optional_text_that_cleans_to_nothing_becomes_none() {
+        assert_eq!(
+            sanitize_optional_text("Description", Some("  ".to_string())).unwrap(),
+            None
+        );
+        assert_eq!(
+            sanitize_optional_text("Description", Some(" Notes\u{200B} ".to_string())).unwrap(),
+            Some("Notes".to_string())
+        );
+        assert!(sanitize_optional_text("Description", Some("\u{200B}".to_string())).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+// src-tauri/tests/accounts.rs
+
+mod common;
+
+use erp_lib::models::account::{AccountCategory, AccountType};
+use erp_lib::repositories::accounts::AccountRepository;
+use erp_lib::Error;
+
+use common::{create_account, test_pool};
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn reclassify_with_one_incompatible_account_changes_no_row() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let rent = create_account(&pool, "5000", AccountType::Expense, "USD").await;
+    let repo = AccountRepository::new(&pool);
+
+    // The compatible account comes first, so its update has already run when
+    // the second one is rejected
+    let reclassified: Vec<_> = [cash.clone(), rent.clone()]
+        .into_iter()
+        .map(|mut account| {
+            account.category = AccountCategory::OtherAsset;
+            account
+        })
+        .collect();
+    let err = repo
+        .update_category(&reclassified, None, &[])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)), "{:?}", err);
+
+    for before in [cash, rent] {
+        let after = repo.find_by_id(before.id).await.unwrap().unwrap();
+        assert_eq!(after.category, before.category);
+        assert_eq!(after.version, before.version);
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn second_update_from_the_same_version_is_a_conflict() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let repo = AccountRepository::new(&pool);
+
+    // Two editors load the same version of the account
+    let mut first = cash.clone();
+    first.name = "Operating Cash".to_string();
+    repo.update(&mut first, None).await.unwrap();
+
+    let mut second = cash.clone();
+    second.name = "Petty Cash".to_string();
+    let err = repo.update(&mut second, None).await.unwrap_err();
+    assert!(matches!(err, Error::Conflict(_)), "{:?}", err);
+
+    let stored = repo.find_by_id(cash.id).await.unwrap().unwrap();
+    assert_eq!(stored.name, "Operating Cash");
+    assert_eq!(stored.version, cash.version + 1);
+}
@@ -0,0 +1,117 @@
+// src-tauri/tests/balances.rs
+
+mod common;
+
+use chrono::{NaiveDate, Utc};
+use erp_lib::models::account::{AccountCategory, AccountType};
+use erp_lib::repositories::accounts::AccountRepository;
+use erp_lib::repositories::journal::JournalRepository;
+use rust_decimal::Decimal;
+
+use common::{balance, create_account, entry, test_pool};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn concurrent_balance_updates_are_all_applied() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+
+    let updates: Vec<_> = (0..40)
+        .map(|n| {
+            let amount = if n % 2 == 0 {
+                Decimal::new(1000, 2)
+            } else {
+                Decimal::new(-250, 2)
+            };
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                AccountRepository::new(&pool)
+                    .update_balance(cash.id, amount)
+                    .await
+            })
+        })
+        .collect();
+    for update in updates {
+        update.await.unwrap().unwrap();
+    }
+
+    assert_eq!(balance(&pool, cash.id).await, Decimal::new(15000, 2));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn concurrent_postings_and_updates_of_one_account_are_all_applied() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let sales = create_account(&pool, "4000", AccountType::Revenue, "USD").await;
+
+    let tasks: Vec<_> = (0..20)
+        .map(|n| {
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                if n % 2 == 0 {
+                    return AccountRepository::new(&pool)
+                        .update_balance(cash.id, Decimal::ONE)
+                        .await;
+                }
+
+                let sale = entry(
+                    Utc::now().date_naive(),
+                    cash.id,
+                    sales.id,
+                    Decimal::new(2, 0),
+                    None,
+                );
+                JournalRepository::new(&pool)
+                    .create(sale, true)
+                    .await
+                    .map(|_| ())
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap().unwrap();
+    }
+
+    assert_eq!(balance(&pool, cash.id).await, Decimal::new(30, 0));
+    assert_eq!(balance(&pool, sales.id).await, Decimal::new(20, 0));
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn category_balance_as_of_counts_only_lines_posted_by_then() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let sales = create_account(&pool, "4000", AccountType::Revenue, "USD").await;
+    let journal = JournalRepository::new(&pool);
+
+    let march = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+    let april = NaiveDate::from_ymd_opt(2025, 4, 30).unwrap();
+    journal
+        .create(
+            entry(march, cash.id, sales.id, Decimal::new(300, 0), None),
+            true,
+        )
+        .await
+        .unwrap();
+    journal
+        .create(
+            entry(april, cash.id, sales.id, Decimal::new(200, 0), None),
+            true,
+        )
+        .await
+        .unwrap();
+
+    let repo = AccountRepository::new(&pool);
+    let as_of_march = repo
+        .sum_balance_by_category(AccountCategory::CurrentAsset, None, Some(march))
+        .await
+        .unwrap();
+    let current = repo
+        .sum_balance_by_category(AccountCategory::CurrentAsset, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(as_of_march, Decimal::new(300, 0));
+    assert_eq!(current, Decimal::new(500, 0));
+}
@@ -0,0 +1,111 @@
+// src-tauri/tests/common/mod.rs
+//
+// Helpers shared by the database tests. They need a PostgreSQL server, so they
+// are ignored by default; run them with
+//
+//     DATABASE_URL=postgres://... cargo test -- --ignored
+//
+// Every test migrates a schema of its own, so tests running side by side never
+// see each other's rows.
+
+#![allow(dead_code)]
+
+use std::env;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use erp_lib::models::account::{Account, AccountCategory, AccountType, NewAccount};
+use erp_lib::models::journal::{NewJournalEntry, NewJournalLine};
+use erp_lib::repositories::accounts::AccountRepository;
+use rust_decimal::Decimal;
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use uuid::Uuid;
+
+/// Connects to `DATABASE_URL` with a freshly migrated, empty schema
+pub async fn test_pool() -> PgPool {
+    let url = env::var("DATABASE_URL").expect("DATABASE_URL must be set for database tests");
+    let schema = format!("test_{}", Uuid::new_v4().simple());
+
+    let admin = PgPool::connect(&url).await.unwrap();
+    sqlx::query(&format!("CREATE SCHEMA {}", schema))
+        .execute(&admin)
+        .await
+        .unwrap();
+    admin.close().await;
+
+    let options = PgConnectOptions::from_str(&url)
+        .unwrap()
+        .options([("search_path", schema.as_str())]);
+    let pool = PgPoolOptions::new()
+        .max_connections(10)
+        .connect_with(options)
+        .await
+        .unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+
+    pool
+}
+
+/// Creates an account with the first category of `account_type`
+pub async fn create_account(
+    pool: &PgPool,
+    code: &str,
+    account_type: AccountType,
+    currency: &str,
+) -> Account {
+    let new_account = NewAccount {
+        code: code.to_string(),
+        name: format!("Account {}", code),
+        description: None,
+        account_type,
+        category: AccountCategory::for_account_type(account_type)[0],
+        subcategory: None,
+        parent_id: None,
+        currency: currency.to_string(),
+    };
+
+    AccountRepository::new(pool)
+        .create(new_account, None)
+        .await
+        .unwrap()
+}
+
+/// The stored balance of an account
+pub async fn balance(pool: &PgPool, id: Uuid) -> Decimal {
+    AccountRepository::new(pool)
+        .find_by_id(id)
+        .await
+        .unwrap()
+        .unwrap()
+        .balance
+}
+
+/// A two-line entry debiting `debit_id` and crediting `credit_id` with `amount`
+pub fn entry(
+    entry_date: NaiveDate,
+    debit_id: Uuid,
+    credit_id: Uuid,
+    amount: Decimal,
+    exchange_rate: Option<Decimal>,
+) -> NewJournalEntry {
+    NewJournalEntry {
+        entry_date,
+        description: "Test entry".to_string(),
+        reference: None,
+        exchange_rate,
+        lines: vec![
+            NewJournalLine {
+                account_id: debit_id,
+                description: None,
+                debit: amount,
+                credit: Decimal::ZERO,
+            },
+            NewJournalLine {
+                account_id: credit_id,
+                description: None,
+                debit: Decimal::ZERO,
+                credit: amount,
+            },
+        ],
+    }
+}
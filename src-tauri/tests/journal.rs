@@ -0,0 +1,104 @@
+// src-tauri/tests/journal.rs
+
+mod common;
+
+use chrono::NaiveDate;
+use erp_lib::models::account::AccountType;
+use erp_lib::repositories::journal::JournalRepository;
+use erp_lib::Error;
+use rust_decimal::Decimal;
+
+use common::{balance, create_account, entry, test_pool};
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn entry_mixing_currencies_needs_an_exchange_rate() {
+    let pool = test_pool().await;
+    let dollars = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let euros = create_account(&pool, "1010", AccountType::Asset, "EUR").await;
+    let journal = JournalRepository::new(&pool);
+
+    let err = journal
+        .create(
+            entry(date(), euros.id, dollars.id, Decimal::new(100, 0), None),
+            true,
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, Error::Validation(msg)
+            if msg == "Journal entry mixes currencies (EUR, USD) without an exchange rate"),
+        "{:?}",
+        err
+    );
+    let entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM journal_entries")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(entries, 0);
+    assert_eq!(balance(&pool, dollars.id).await, Decimal::ZERO);
+    assert_eq!(balance(&pool, euros.id).await, Decimal::ZERO);
+
+    let rate = Some(Decimal::new(108, 2));
+    let posted = journal
+        .create(
+            entry(date(), euros.id, dollars.id, Decimal::new(100, 0), rate),
+            true,
+        )
+        .await
+        .unwrap();
+    assert!(posted.is_posted);
+    assert_eq!(balance(&pool, euros.id).await, Decimal::new(100, 0));
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn entry_in_one_currency_needs_no_exchange_rate() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "EUR").await;
+    let sales = create_account(&pool, "4000", AccountType::Revenue, "EUR").await;
+
+    JournalRepository::new(&pool)
+        .create(
+            entry(date(), cash.id, sales.id, Decimal::new(50, 0), None),
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(balance(&pool, cash.id).await, Decimal::new(50, 0));
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn posted_transfer_moves_balance_between_accounts() {
+    let pool = test_pool().await;
+    let checking = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let savings = create_account(&pool, "1010", AccountType::Asset, "USD").await;
+    let capital = create_account(&pool, "3000", AccountType::Equity, "USD").await;
+    let journal = JournalRepository::new(&pool);
+
+    journal
+        .create(
+            entry(date(), checking.id, capital.id, Decimal::new(500, 0), None),
+            true,
+        )
+        .await
+        .unwrap();
+
+    // A transfer debits the destination and credits the source
+    journal
+        .create(
+            entry(date(), savings.id, checking.id, Decimal::new(100, 0), None),
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(balance(&pool, checking.id).await, Decimal::new(400, 0));
+    assert_eq!(balance(&pool, savings.id).await, Decimal::new(100, 0));
+}
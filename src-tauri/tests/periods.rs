@@ -0,0 +1,172 @@
+// src-tauri/tests/periods.rs
+
+mod common;
+
+use chrono::{NaiveDate, Utc};
+use erp_lib::models::account::{Account, AccountCategory, AccountType, NewAccount};
+use erp_lib::models::period::{FiscalPeriod, NewFiscalPeriod};
+use erp_lib::repositories::accounts::AccountRepository;
+use erp_lib::repositories::journal::JournalRepository;
+use erp_lib::repositories::periods::PeriodRepository;
+use erp_lib::Error;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+
+use common::{balance, create_account, entry, test_pool};
+
+async fn closed_period(pool: &PgPool, start_date: NaiveDate, end_date: NaiveDate) -> FiscalPeriod {
+    let periods = PeriodRepository::new(pool);
+    let period = periods
+        .create(NewFiscalPeriod {
+            name: "Closed".to_string(),
+            start_date,
+            end_date,
+        })
+        .await
+        .unwrap();
+
+    periods.close_period(period.id).await.unwrap()
+}
+
+fn assert_period_closed(result: erp_lib::Result<impl std::fmt::Debug>) {
+    match result {
+        Err(Error::Conflict(msg)) => assert_eq!(msg, "Period is closed"),
+        other => panic!("expected a closed-period conflict, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn posting_into_a_closed_period_changes_no_balances() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    let sales = create_account(&pool, "4000", AccountType::Revenue, "USD").await;
+    let journal = JournalRepository::new(&pool);
+
+    let january = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+    let amount = Decimal::new(100, 0);
+    let draft = journal
+        .create(entry(january, cash.id, sales.id, amount, None), false)
+        .await
+        .unwrap();
+    closed_period(
+        &pool,
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+    )
+    .await;
+
+    assert_period_closed(
+        journal
+            .create(entry(january, cash.id, sales.id, amount, None), true)
+            .await,
+    );
+    assert_period_closed(journal.post(draft.id).await);
+
+    assert!(
+        !journal
+            .find_by_id(draft.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_posted
+    );
+    assert_eq!(balance(&pool, cash.id).await, Decimal::ZERO);
+    assert_eq!(balance(&pool, sales.id).await, Decimal::ZERO);
+
+    // The period after the closed one still accepts postings
+    let february = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+    journal
+        .create(entry(february, cash.id, sales.id, amount, None), true)
+        .await
+        .unwrap();
+    assert_eq!(balance(&pool, cash.id).await, amount);
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn balance_update_is_refused_while_today_is_closed() {
+    let pool = test_pool().await;
+    let cash = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+
+    let today = Utc::now().date_naive();
+    closed_period(&pool, today, today).await;
+
+    assert_period_closed(
+        AccountRepository::new(&pool)
+            .update_balance(cash.id, Decimal::ONE)
+            .await,
+    );
+    assert_eq!(balance(&pool, cash.id).await, Decimal::ZERO);
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn inactive_accounts_with_a_balance_are_found_before_closing() {
+    let pool = test_pool().await;
+    let repo = AccountRepository::new(&pool);
+    let dormant = create_account(&pool, "1900", AccountType::Asset, "USD").await;
+    let empty = create_account(&pool, "1910", AccountType::Asset, "USD").await;
+    let active = create_account(&pool, "1000", AccountType::Asset, "USD").await;
+    for account in [&dormant, &active] {
+        repo.update_balance(account.id, Decimal::new(25, 0))
+            .await
+            .unwrap();
+    }
+    repo.set_active(&[dormant.id, empty.id], false, None)
+        .await
+        .unwrap();
+
+    let found: Vec<String> = repo
+        .find_inactive_nonzero()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|account| account.code)
+        .collect();
+    assert_eq!(found, ["1900"]);
+}
+
+#[tokio::test]
+#[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+async fn opening_balance_in_a_closed_period_saves_no_new_equity_account() {
+    let pool = test_pool().await;
+    closed_period(
+        &pool,
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+    )
+    .await;
+    let repo = AccountRepository::new(&pool);
+
+    let new_account =
+        |code: &str, account_type: AccountType, category: AccountCategory| NewAccount {
+            code: code.to_string(),
+            name: format!("Account {}", code),
+            description: None,
+            account_type,
+            category,
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+        };
+    let equity = Account::new(new_account(
+        "3900",
+        AccountType::Equity,
+        AccountCategory::OwnerEquity,
+    ));
+
+    assert_period_closed(
+        repo.create_with_opening_balance(
+            new_account("1000", AccountType::Asset, AccountCategory::CurrentAsset),
+            None,
+            equity.id,
+            Some(&equity),
+            Decimal::new(100, 0),
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+        )
+        .await,
+    );
+    assert!(repo.find_by_code("3900").await.unwrap().is_none());
+    assert!(repo.find_by_code("1000").await.unwrap().is_none());
+}
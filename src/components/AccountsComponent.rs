@@ -1,42 +1,171 @@
 #![allow(non_snake_case)]
+use crate::components::ConfirmDialog;
+use crate::services::accounts::{listen_account_changed, AccountChangeKind, AccountViewModel};
+use crate::services::auth;
+use crate::services::currency::{self, Currency};
+use crate::services::tauri::ErrorResponse;
+use async_std::task::sleep;
 use dioxus::events::{FormData, FormEvent};
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::rc::Rc;
+use std::time::Duration;
 
-// Account model for the frontend
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct AccountViewModel {
-    pub id: String,
+pub struct NewAccountModel {
     pub code: String,
     pub name: String,
     pub description: Option<String>,
     pub account_type: String,
     pub category: String,
     pub subcategory: Option<String>,
-    pub is_active: bool,
     pub parent_id: Option<String>,
-    pub balance: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub currency: String,
+    // Version of the account being edited; None when creating
+    pub version: Option<i32>,
+    // Balance to open a new account with, posted against opening balance equity
+    pub opening_balance: Option<String>,
+    // Date of the opening balance entry (YYYY-MM-DD); today when empty
+    pub opening_balance_date: Option<String>,
 }
 
+// An account type together with the categories it allows
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct NewAccountModel {
-    pub code: String,
-    pub name: String,
-    pub description: Option<String>,
+pub struct AccountTypeMetadata {
     pub account_type: String,
-    pub category: String,
-    pub subcategory: Option<String>,
-    pub parent_id: Option<String>,
+    pub categories: Vec<String>,
+}
+
+// What deleting an account would affect, as reported by the backend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeleteImpact {
+    pub children: i64,
+    pub posted_lines: i64,
+    pub unposted_lines: i64,
+    pub notes: i64,
+    pub can_delete: bool,
+    pub reasons: Vec<String>,
+}
+
+impl Default for NewAccountModel {
+    fn default() -> Self {
+        Self {
+            code: String::new(),
+            name: String::new(),
+            description: None,
+            account_type: "ASSET".to_string(),
+            category: "CURRENT_ASSET".to_string(),
+            subcategory: None,
+            parent_id: None,
+            currency: "USD".to_string(),
+            version: None,
+            opening_balance: None,
+            opening_balance_date: None,
+        }
+    }
+}
+
+impl From<&AccountViewModel> for NewAccountModel {
+    fn from(account: &AccountViewModel) -> Self {
+        Self {
+            code: account.code.clone(),
+            name: account.name.clone(),
+            description: account.description.clone(),
+            account_type: account.account_type.clone(),
+            category: account.category.clone(),
+            subcategory: account.subcategory.clone(),
+            parent_id: account.parent_id.clone(),
+            currency: account.currency.clone(),
+            version: Some(account.version),
+            opening_balance: None,
+            opening_balance_date: None,
+        }
+    }
 }
 
 // API calls
-async fn fetch_accounts() -> Result<Vec<AccountViewModel>, String> {
+// Number of accounts shown per table page
+const PAGE_SIZE: u32 = 50;
+
+// Delay after the last keystroke before searching
+const SEARCH_DEBOUNCE_MS: u64 = 300;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountPage {
+    pub accounts: Vec<AccountViewModel>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_count: i64,
+}
+
+async fn fetch_accounts_page(page: u32) -> Result<AccountPage, String> {
+    #[derive(Serialize)]
+    struct PageArgs {
+        page: u32,
+        page_size: u32,
+    }
+
+    let args = PageArgs {
+        page,
+        page_size: PAGE_SIZE,
+    };
+    let result =
+        crate::services::tauri::invoke::<PageArgs, AccountPage>("get_accounts_paged", &args).await;
+
+    match result {
+        Ok(page) => Ok(page),
+        Err(e) => Err(format!("Failed to fetch accounts: {}", e)),
+    }
+}
+
+async fn fetch_account_metadata() -> Result<Vec<AccountTypeMetadata>, String> {
     let result =
-        crate::services::tauri::invoke::<(), Vec<AccountViewModel>>("get_accounts", &()).await;
+        crate::services::tauri::invoke::<(), Vec<AccountTypeMetadata>>("get_account_metadata", &())
+            .await;
+
+    match result {
+        Ok(metadata) => Ok(metadata),
+        Err(e) => Err(format!("Failed to fetch account metadata: {}", e)),
+    }
+}
+
+async fn search_accounts(query: String) -> Result<Vec<AccountViewModel>, String> {
+    #[derive(Serialize)]
+    struct SearchArgs {
+        query: String,
+    }
+
+    let result = crate::services::tauri::invoke::<SearchArgs, Vec<AccountViewModel>>(
+        "search_accounts",
+        &SearchArgs { query },
+    )
+    .await;
+
+    match result {
+        Ok(accounts) => Ok(accounts),
+        Err(e) => Err(format!("Failed to search accounts: {}", e)),
+    }
+}
+
+async fn fetch_sorted_accounts(
+    sort_by: String,
+    ascending: bool,
+) -> Result<Vec<AccountViewModel>, String> {
+    #[derive(Serialize)]
+    struct SortArgs {
+        sort_by: String,
+        direction: String,
+    }
+
+    let args = SortArgs {
+        sort_by,
+        direction: if ascending { "asc" } else { "desc" }.to_string(),
+    };
+    let result = crate::services::tauri::invoke::<SortArgs, Vec<AccountViewModel>>(
+        "get_accounts_sorted",
+        &args,
+    )
+    .await;
 
     match result {
         Ok(accounts) => Ok(accounts),
@@ -44,16 +173,202 @@ async fn fetch_accounts() -> Result<Vec<AccountViewModel>, String> {
     }
 }
 
-async fn create_account(new_account: NewAccountModel) -> Result<AccountViewModel, String> {
-    let result = crate::services::tauri::invoke::<NewAccountModel, AccountViewModel>(
-        "create_account",
-        &new_account,
+async fn filter_accounts(
+    account_type: String,
+    category: String,
+) -> Result<Vec<AccountViewModel>, String> {
+    #[derive(Serialize)]
+    struct FilterArgs {
+        account_type: Option<String>,
+        category: Option<String>,
+    }
+
+    // An empty selection means "All"
+    let args = FilterArgs {
+        account_type: Some(account_type).filter(|value| !value.is_empty()),
+        category: Some(category).filter(|value| !value.is_empty()),
+    };
+    let result = crate::services::tauri::invoke::<FilterArgs, Vec<AccountViewModel>>(
+        "get_accounts_filtered",
+        &args,
     )
     .await;
 
+    match result {
+        Ok(accounts) => Ok(accounts),
+        Err(e) => Err(format!("Failed to fetch accounts: {}", e)),
+    }
+}
+
+async fn create_account(new_account: NewAccountModel) -> Result<AccountViewModel, ErrorResponse> {
+    #[derive(Serialize)]
+    struct CreateArgs {
+        new_account: NewAccountModel,
+        token: String,
+    }
+
+    let args = CreateArgs {
+        new_account,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+    let result =
+        crate::services::tauri::invoke::<CreateArgs, AccountViewModel>("create_account", &args)
+            .await;
+
     match result {
         Ok(account) => Ok(account),
-        Err(e) => Err(format!("Failed to create account: {}", e)),
+        Err(e) => Err(e.with_context("Failed to create account")),
+    }
+}
+
+async fn update_account(
+    id: String,
+    update_data: NewAccountModel,
+) -> Result<AccountViewModel, ErrorResponse> {
+    #[derive(Serialize)]
+    struct UpdateArgs {
+        id: String,
+        update_data: NewAccountModel,
+        token: String,
+    }
+
+    let args = UpdateArgs {
+        id,
+        update_data,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+    let result =
+        crate::services::tauri::invoke::<UpdateArgs, AccountViewModel>("update_account", &args)
+            .await;
+
+    match result {
+        Ok(account) => Ok(account),
+        Err(e) => Err(e.with_context("Failed to update account")),
+    }
+}
+
+async fn fetch_delete_impact(id: String) -> Result<DeleteImpact, String> {
+    #[derive(Serialize)]
+    struct ImpactArgs {
+        id: String,
+    }
+
+    let result = crate::services::tauri::invoke::<ImpactArgs, DeleteImpact>(
+        "get_account_delete_impact",
+        &ImpactArgs { id },
+    )
+    .await;
+
+    match result {
+        Ok(impact) => Ok(impact),
+        Err(e) => Err(format!("Failed to check account references: {}", e)),
+    }
+}
+
+// Classes for a form control, outlined in red while the backend rejects its field
+fn field_class(invalid: bool) -> &'static str {
+    if invalid {
+        "shadow appearance-none border border-red-500 rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline"
+    } else {
+        "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline"
+    }
+}
+
+// Confirmation text for deleting `account`, warning about what references it
+fn delete_message(account: &AccountViewModel, impact: Option<&DeleteImpact>) -> String {
+    let label = format!("{} - {}", account.code, account.name);
+    let Some(impact) = impact else {
+        return format!("Delete account {}? This cannot be undone.", label);
+    };
+
+    if !impact.can_delete {
+        return format!("{}.", impact.reasons.join(". "));
+    }
+
+    let count = |n: i64, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+    let mut references = Vec::new();
+    if impact.children > 0 {
+        references.push(count(impact.children, "child", "children"));
+    }
+    let transactions = impact.posted_lines + impact.unposted_lines;
+    if transactions > 0 {
+        references.push(count(transactions, "transaction", "transactions"));
+    }
+    if impact.notes > 0 {
+        references.push(count(impact.notes, "note", "notes"));
+    }
+
+    if references.is_empty() {
+        format!("Delete account {}? This cannot be undone.", label)
+    } else {
+        format!(
+            "Delete account {}? This account has {}. This cannot be undone.",
+            label,
+            references.join(" and ")
+        )
+    }
+}
+
+async fn seed_default_chart() -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SeedArgs {
+        token: String,
+    }
+
+    let args = SeedArgs {
+        token: auth::stored_token().unwrap_or_default(),
+    };
+    let result = crate::services::tauri::invoke::<SeedArgs, Vec<AccountViewModel>>(
+        "seed_default_chart",
+        &args,
+    )
+    .await;
+
+    match result {
+        Ok(accounts) => Ok(accounts),
+        Err(e) => Err(e.with_context("Failed to create the default chart")),
+    }
+}
+
+async fn delete_account(id: String) -> Result<(), ErrorResponse> {
+    #[derive(Serialize)]
+    struct DeleteArgs {
+        id: String,
+        token: String,
+    }
+
+    let args = DeleteArgs {
+        id,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+    let result = crate::services::tauri::invoke::<DeleteArgs, ()>("delete_account", &args).await;
+
+    match result {
+        Ok(()) => Ok(()),
+        Err(e) => Err(e.with_context("Failed to delete account")),
+    }
+}
+
+async fn suggest_next_code(
+    account_type: String,
+    parent_id: Option<String>,
+) -> Result<String, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SuggestArgs {
+        account_type: String,
+        parent_id: Option<String>,
+    }
+
+    let args = SuggestArgs {
+        account_type,
+        parent_id,
+    };
+    let result =
+        crate::services::tauri::invoke::<SuggestArgs, String>("suggest_next_code", &args).await;
+
+    match result {
+        Ok(code) => Ok(code),
+        Err(e) => Err(e.with_context("Failed to suggest an account code")),
     }
 }
 
@@ -61,69 +376,130 @@ async fn create_account(new_account: NewAccountModel) -> Result<AccountViewModel
 pub fn AccountsComponent() -> Element {
     let mut accounts = use_signal(Vec::<AccountViewModel>::new);
     let mut error_message = use_signal(|| Option::<String>::None);
+    // Form fields the backend rejected on the last submit
+    let mut invalid_fields = use_signal(Vec::<String>::new);
     let mut is_loading = use_signal(|| true);
     let mut show_form = use_signal(|| false);
+    let mut page = use_signal(|| 1u32);
+    let mut total_count = use_signal(|| 0i64);
+    let mut search_query = use_signal(String::new);
+    let mut type_filter = use_signal(String::new);
+    let mut category_filter = use_signal(String::new);
+    // Column the table is ordered by; the default is code, ascending
+    let mut sort_field = use_signal(|| "code".to_string());
+    let mut sort_ascending = use_signal(|| true);
 
-    let mut new_account = use_signal(|| NewAccountModel {
-        code: String::new(),
-        name: String::new(),
-        description: None,
-        account_type: "ASSET".to_string(),
-        category: "CURRENT_ASSET".to_string(),
-        subcategory: None,
-        parent_id: None,
-    });
+    let mut editing_id = use_signal(|| Option::<String>::None);
+    let mut pending_delete = use_signal(|| Option::<AccountViewModel>::None);
+    let mut delete_impact = use_signal(|| Option::<DeleteImpact>::None);
+
+    let mut new_account = use_signal(NewAccountModel::default);
+    // Last code suggested by the backend; replaced on type change unless edited
+    let mut suggested_code = use_signal(|| Option::<String>::None);
+    let mut account_metadata = use_signal(Vec::<AccountTypeMetadata>::new);
+    let mut currencies = use_signal(Vec::<Currency>::new);
 
-    // Load accounts on component mount
+    // Load the current page on mount and whenever the page changes; with a
+    // filter or a non-default sort selected, load every matching account instead
     use_effect(move || {
+        let current_page = page();
+        let account_type = type_filter();
+        let category = category_filter();
+        let sort_by = sort_field();
+        let ascending = sort_ascending();
         is_loading.set(true);
 
         spawn(async move {
-            match fetch_accounts().await {
-                Ok(fetched_accounts) => {
-                    accounts.set(fetched_accounts);
-                    error_message.set(None);
+            if !account_type.is_empty() || !category.is_empty() {
+                match filter_accounts(account_type, category).await {
+                    Ok(filtered_accounts) => {
+                        accounts.set(filtered_accounts);
+                        error_message.set(None);
+                    }
+                    Err(err) => {
+                        error_message.set(Some(err));
+                    }
                 }
-                Err(err) => {
-                    error_message.set(Some(err.to_string()));
+            } else if sort_by != "code" || !ascending {
+                match fetch_sorted_accounts(sort_by, ascending).await {
+                    Ok(sorted_accounts) => {
+                        accounts.set(sorted_accounts);
+                        error_message.set(None);
+                    }
+                    Err(err) => {
+                        error_message.set(Some(err));
+                    }
+                }
+            } else {
+                match fetch_accounts_page(current_page).await {
+                    Ok(fetched_page) => {
+                        accounts.set(fetched_page.accounts);
+                        total_count.set(fetched_page.total_count);
+                        error_message.set(None);
+                    }
+                    Err(err) => {
+                        error_message.set(Some(err.to_string()));
+                    }
                 }
             }
             is_loading.set(false);
         });
     });
 
-    let account_types = vec!["ASSET", "LIABILITY", "EQUITY", "REVENUE", "EXPENSE"];
-
-    let category_map: Rc<HashMap<&str, Vec<&str>>> = Rc::new({
-        let mut map = HashMap::new();
-        map.insert("ASSET", vec!["CURRENT_ASSET", "FIXED_ASSET", "OTHER_ASSET"]);
-        map.insert(
-            "LIABILITY",
-            vec![
-                "CURRENT_LIABILITY",
-                "LONG_TERM_LIABILITY",
-                "OTHER_LIABILITY",
-            ],
-        );
-        map.insert("EQUITY", vec!["OWNER_EQUITY", "RETAINED_EARNINGS"]);
-        map.insert(
-            "REVENUE",
-            vec!["OPERATING_REVENUE", "NON_OPERATING_REVENUE"],
-        );
-        map.insert(
-            "EXPENSE",
-            vec!["OPERATING_EXPENSE", "NON_OPERATING_EXPENSE"],
-        );
-        map
+    // Patch the table in place when an account changes in this or another
+    // window. The listener is removed when the component unmounts.
+    use_hook(|| {
+        listen_account_changed(move |change| {
+            let mut rows = accounts.write();
+            match change.account {
+                Some(account) => match rows.iter_mut().find(|row| row.id == account.id) {
+                    Some(row) => *row = account,
+                    None if change.kind == AccountChangeKind::Created => {
+                        rows.push(account);
+                        total_count.set(total_count() + 1);
+                    }
+                    None => {}
+                },
+                None => {
+                    let before = rows.len();
+                    rows.retain(|row| row.id != change.account_id);
+                    if rows.len() < before {
+                        total_count.set(total_count() - 1);
+                    }
+                }
+            }
+        })
+        .ok()
+        .map(Rc::new)
     });
 
-    let categories = {
-        let category_map = Rc::clone(&category_map);
-        match category_map.get(new_account.read().account_type.as_str()) {
-            Some(cats) => cats.clone(),
-            None => vec![],
-        }
-    };
+    // Load account types and their categories once on mount
+    use_effect(move || {
+        spawn(async move {
+            match fetch_account_metadata().await {
+                Ok(fetched_metadata) => account_metadata.set(fetched_metadata),
+                Err(err) => error_message.set(Some(err)),
+            }
+            match currency::get_supported().await {
+                Ok(fetched_currencies) => currencies.set(fetched_currencies),
+                Err(err) => error_message.set(Some(err.to_string())),
+            }
+        });
+    });
+
+    // Opening balances are entered in the smallest unit of the chosen currency
+    let opening_balance_step = currencies
+        .read()
+        .iter()
+        .find(|currency| currency.code == new_account.read().currency)
+        .map_or(0.01, |currency| 10f64.powi(-(currency.decimal_places as i32)));
+
+    let categories: Vec<String> = account_metadata
+        .read()
+        .iter()
+        .find(|metadata| metadata.account_type == new_account.read().account_type)
+        .map(|metadata| metadata.categories.clone())
+        .unwrap_or_default();
 
     let handle_submit = move |event: FormEvent| {
         event.prevent_default();
@@ -131,28 +507,44 @@ pub fn AccountsComponent() -> Element {
         is_loading.set(true);
 
         let new_account_clone = new_account.read().clone();
+        let editing = editing_id();
 
         spawn(async move {
-            match create_account(new_account_clone).await {
-                Ok(created_account) => {
+            let result = match editing {
+                Some(id) => update_account(id, new_account_clone).await,
+                None => create_account(new_account_clone).await,
+            };
+
+            match result {
+                Ok(saved_account) => {
                     accounts.set({
                         let mut updated_accounts = accounts().clone();
-                        updated_accounts.push(created_account);
+                        match updated_accounts
+                            .iter_mut()
+                            .find(|account| account.id == saved_account.id)
+                        {
+                            Some(existing) => *existing = saved_account,
+                            None => {
+                                updated_accounts.push(saved_account);
+                                total_count.set(total_count() + 1);
+                            }
+                        }
                         updated_accounts
                     });
                     show_form.set(false);
-                    new_account.set(NewAccountModel {
-                        code: String::new(),
-                        name: String::new(),
-                        description: None,
-                        account_type: "ASSET".to_string(),
-                        category: "CURRENT_ASSET".to_string(),
-                        subcategory: None,
-                        parent_id: None,
-                    });
+                    editing_id.set(None);
+                    new_account.set(NewAccountModel::default());
+                    suggested_code.set(None);
                     error_message.set(None);
+                    invalid_fields.set(Vec::new());
+                }
+                // The user can fix these, so show just what is wrong
+                Err(err) if err.is_validation() || err.is_conflict() => {
+                    invalid_fields.set(err.field_errors().into_iter().map(|error| error.field).collect());
+                    error_message.set(Some(err.user_message()));
                 }
                 Err(err) => {
+                    invalid_fields.set(Vec::new());
                     error_message.set(Some(err.to_string()));
                 }
             }
@@ -160,14 +552,171 @@ pub fn AccountsComponent() -> Element {
         });
     };
 
+    // Look up what references the account whenever a deletion is requested
+    use_effect(move || {
+        let target = pending_delete();
+        delete_impact.set(None);
+
+        if let Some(account) = target {
+            spawn(async move {
+                match fetch_delete_impact(account.id).await {
+                    Ok(impact) => delete_impact.set(Some(impact)),
+                    Err(err) => error_message.set(Some(err)),
+                }
+            });
+        }
+    });
+
+    let confirm_delete = move |_| {
+        let Some(account) = pending_delete() else {
+            return;
+        };
+        pending_delete.set(None);
+
+        spawn(async move {
+            match delete_account(account.id.clone()).await {
+                Ok(()) => {
+                    // The account-changed event may have removed the row already
+                    let before = accounts.read().len();
+                    accounts.write().retain(|existing| existing.id != account.id);
+                    if accounts.read().len() < before {
+                        total_count.set(total_count() - 1);
+                    }
+                    error_message.set(None);
+                }
+                Err(err) => error_message.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    // Fill an empty chart with the backend's default small-business accounts
+    let seed_chart = move |_| {
+        is_loading.set(true);
+
+        spawn(async move {
+            match seed_default_chart().await {
+                // The seeded chart is ordered by code and fits on the first page
+                Ok(seeded) => {
+                    total_count.set(seeded.len() as i64);
+                    accounts.set(seeded);
+                    error_message.set(None);
+                }
+                Err(err) => error_message.set(Some(err.to_string())),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    // Prefill the code field with the next free code unless the user typed one
+    let prefill_code = move |account_type: String, parent_id: Option<String>| {
+        spawn(async move {
+            let Ok(code) = suggest_next_code(account_type, parent_id).await else {
+                return;
+            };
+            let mut account = new_account();
+            if account.code.is_empty() || suggested_code.read().as_deref() == Some(account.code.as_str()) {
+                account.code = code.clone();
+                new_account.set(account);
+            }
+            suggested_code.set(Some(code));
+        });
+    };
+
     let toggle_form = move |_| {
+        if show_form() {
+            editing_id.set(None);
+            new_account.set(NewAccountModel::default());
+            suggested_code.set(None);
+            invalid_fields.set(Vec::new());
+        } else if editing_id.read().is_none() {
+            let account = new_account();
+            prefill_code(account.account_type, account.parent_id);
+        }
         show_form.set(!show_form());
     };
 
-    let category_map_clone = Rc::clone(&category_map);
-    let account_type_options = account_types.iter().map(|acct_type| {
+    let is_editing = editing_id.read().is_some();
+    let is_invalid = move |field: &str| invalid_fields.read().iter().any(|invalid| invalid == field);
+    let is_searching = !search_query.read().trim().is_empty();
+    let is_filtering = !type_filter.read().is_empty() || !category_filter.read().is_empty();
+    let is_sorted = *sort_field.read() != "code" || !sort_ascending();
+
+    // Clicking a header sorts by it; clicking it again reverses the order
+    let mut toggle_sort = move |field: &str| {
+        if *sort_field.read() == field {
+            sort_ascending.set(!sort_ascending());
+        } else {
+            sort_field.set(field.to_string());
+            sort_ascending.set(true);
+        }
+    };
+    let sort_arrow = |field: &str| {
+        if *sort_field.read() != field {
+            ""
+        } else if sort_ascending() {
+            " ▲"
+        } else {
+            " ▼"
+        }
+    };
+    let code_arrow = sort_arrow("code");
+    let name_arrow = sort_arrow("name");
+    let type_arrow = sort_arrow("type");
+    let balance_arrow = sort_arrow("balance");
+
+    let handle_search = move |event: Event<FormData>| {
+        let query = event.value();
+        search_query.set(query.clone());
+
+        spawn(async move {
+            sleep(Duration::from_millis(SEARCH_DEBOUNCE_MS)).await;
+
+            // A newer keystroke has superseded this search
+            if *search_query.peek() != query {
+                return;
+            }
+
+            is_loading.set(true);
+            let result = if query.trim().is_empty() {
+                fetch_accounts_page(page())
+                    .await
+                    .map(|fetched_page| fetched_page.accounts)
+            } else {
+                search_accounts(query.clone()).await
+            };
+
+            // Ignore results for a query that changed while the request was in flight
+            if *search_query.peek() == query {
+                match result {
+                    Ok(found_accounts) => {
+                        accounts.set(found_accounts);
+                        error_message.set(None);
+                    }
+                    Err(err) => {
+                        error_message.set(Some(err));
+                    }
+                }
+            }
+            is_loading.set(false);
+        });
+    };
+
+    let total_pages = ((total_count() as u32).div_ceil(PAGE_SIZE)).max(1);
+    let has_previous = page() > 1;
+    let has_next = page() < total_pages;
+
+    // The category filter offers the categories of the selected type, or all
+    let filter_categories: Vec<String> = account_metadata
+        .read()
+        .iter()
+        .filter(|metadata| type_filter.read().is_empty() || metadata.account_type == *type_filter.read())
+        .flat_map(|metadata| metadata.categories.clone())
+        .collect();
+
+    let account_type_read = account_metadata.read();
+    let account_type_options = account_type_read.iter().map(|metadata| {
         rsx! {
-            option { value: "{acct_type}", "{acct_type}" }
+            option { value: "{metadata.account_type}", "{metadata.account_type}" }
         }
     });
 
@@ -177,15 +726,29 @@ pub fn AccountsComponent() -> Element {
         }
     });
 
+    let currency_read = currencies.read();
     let account_row_read = accounts.read();
     let account_rows = account_row_read.iter().map(|account| {
+        let edit_target = account.clone();
+        let delete_target = account.clone();
+        let balance = currency::format_balance(&account.balance, &account.currency, &currency_read);
+
         rsx! {
             tr { key: "{account.id}",
                 td { class: "py-2 px-4 border-b", "{account.code}" }
-                td { class: "py-2 px-4 border-b", "{account.name}" }
+                td { class: "py-2 px-4 border-b",
+                    "{account.name}"
+                    if account.note_count > 0 {
+                        span {
+                            class: "ml-2 inline-block px-2 text-xs font-semibold text-gray-600 bg-gray-200 rounded-full",
+                            title: "Notes on this account",
+                            "{account.note_count}"
+                        }
+                    }
+                }
                 td { class: "py-2 px-4 border-b", "{account.account_type}" }
                 td { class: "py-2 px-4 border-b", "{account.category}" }
-                td { class: "py-2 px-4 border-b text-right", "{account.balance}" }
+                td { class: "py-2 px-4 border-b text-right", "{balance}" }
                 td { class: "py-2 px-4 border-b text-center",
                     span {
                         class: if account.is_active {
@@ -204,9 +767,19 @@ pub fn AccountsComponent() -> Element {
                     }
                     button {
                         class: "text-green-500 hover:text-green-700",
-                        // onclick: move |_| edit_account(account.id.clone()),
+                        onclick: move |_| {
+                            new_account.set(NewAccountModel::from(&edit_target));
+                            editing_id.set(Some(edit_target.id.clone()));
+                            invalid_fields.set(Vec::new());
+                            show_form.set(true);
+                        },
                         "Edit"
                     }
+                    button {
+                        class: "text-red-500 hover:text-red-700 ml-2",
+                        onclick: move |_| pending_delete.set(Some(delete_target.clone())),
+                        "Delete"
+                    }
                 }
             }
         }
@@ -216,6 +789,16 @@ pub fn AccountsComponent() -> Element {
         div { class: "container mx-auto p-4",
             h1 { class: "text-2xl font-bold mb-4", "Chart of Accounts" }
 
+            if let Some(account) = pending_delete.read().as_ref() {
+                ConfirmDialog {
+                    title: "Delete account",
+                    message: delete_message(account, delete_impact.read().as_ref()),
+                    confirm_label: "Delete",
+                    on_confirm: confirm_delete,
+                    on_cancel: move |_| pending_delete.set(None),
+                }
+            }
+
             {match &*error_message.read() {
 
                 Some(error) => rsx! {
@@ -237,12 +820,15 @@ pub fn AccountsComponent() -> Element {
             {if *show_form.read() {
                 rsx! {
                     form { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4", onsubmit: handle_submit,
+                        h2 { class: "text-xl font-semibold mb-4",
+                            {if is_editing { "Update Account" } else { "New Account" }}
+                        }
                         div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
                             div { class: "mb-4",
                                 label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "code", "Account Code" }
                                 input {
                                     id: "code",
-                                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                    class: field_class(is_invalid("code")),
                                     r#type: "text",
                                     placeholder: "e.g., 1000",
                                     required: "true",
@@ -258,7 +844,7 @@ pub fn AccountsComponent() -> Element {
                                 label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "name", "Account Name" }
                                 input {
                                     id: "name",
-                                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                    class: field_class(is_invalid("name")),
                                     r#type: "text",
                                     placeholder: "e.g., Cash",
                                     required: "true",
@@ -274,7 +860,7 @@ pub fn AccountsComponent() -> Element {
                                 label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "description", "Description" }
                                 input {
                                     id: "description",
-                                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                    class: field_class(is_invalid("description")),
                                     r#type: "text",
                                     placeholder: "Optional description",
                                     value: "{new_account.read().description.clone().unwrap_or_default()}",
@@ -293,20 +879,27 @@ pub fn AccountsComponent() -> Element {
                                 label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "accountType", "Account Type" }
                                 select {
                                     id: "accountType",
-                                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                    class: field_class(is_invalid("account_type")),
                                     required: "true",
                                     value: "{new_account.read().account_type}",
                                     onchange: move |event| {
                                          let account_type = event.value().clone();
-                                         let default_category = match category_map_clone.get(account_type.as_str()) {
-                                             Some(cats) if !cats.is_empty() => cats[0].to_string(),
-                                             _ => String::new(),
-                                         };
+                                         let default_category = account_metadata
+                                             .read()
+                                             .iter()
+                                             .find(|metadata| metadata.account_type == account_type)
+                                             .and_then(|metadata| metadata.categories.first().cloned())
+                                             .unwrap_or_default();
 
                                          let mut account_model = new_account().clone();
-                                         account_model.account_type = account_type;
+                                         account_model.account_type = account_type.clone();
                                          account_model.category = default_category;
+                                         let parent_id = account_model.parent_id.clone();
                                          new_account.set(account_model);
+
+                                         if editing_id.read().is_none() {
+                                             prefill_code(account_type, parent_id);
+                                         }
                                      },
                                     {account_type_options}
                                 }
@@ -315,7 +908,7 @@ pub fn AccountsComponent() -> Element {
                                 label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "category", "Category" }
                                 select {
                                     id: "category",
-                                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                    class: field_class(is_invalid("category")),
                                     required: "true",
                                     value: "{new_account.read().category}",
                                     onchange: move |event: Event<FormData>| {
@@ -326,13 +919,80 @@ pub fn AccountsComponent() -> Element {
                                     {category_options}
                                 }
                             }
+                            div { class: "mb-4",
+                                label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "currency", "Currency" }
+                                select {
+                                    id: "currency",
+                                    class: field_class(is_invalid("currency")),
+                                    required: "true",
+                                    value: "{new_account.read().currency}",
+                                    onchange: move |event: Event<FormData>| {
+                                        let mut account = new_account().clone();
+                                        account.currency = event.value().clone();
+                                        new_account.set(account);
+                                    },
+                                    for currency in currencies.read().iter() {
+                                        option { value: "{currency.code}", "{currency.code} - {currency.name} ({currency.symbol})" }
+                                    }
+                                }
+                            }
+                            {if !is_editing {
+                                rsx! {
+                                    div { class: "mb-4",
+                                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "openingBalance", "Opening Balance" }
+                                        input {
+                                            id: "openingBalance",
+                                            class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                            r#type: "number",
+                                            step: "{opening_balance_step}",
+                                            placeholder: "0.00",
+                                            value: "{new_account.read().opening_balance.clone().unwrap_or_default()}",
+                                            oninput: move |event: Event<FormData>| {
+                                                let mut account = new_account().clone();
+                                                account.opening_balance = if event.value().is_empty() {
+                                                    None
+                                                } else {
+                                                    Some(event.value().clone())
+                                                };
+                                                new_account.set(account);
+                                            }
+                                        }
+                                    }
+                                    div { class: "mb-4",
+                                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "openingBalanceDate", "Opening Balance Date" }
+                                        input {
+                                            id: "openingBalanceDate",
+                                            class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                                            r#type: "date",
+                                            value: "{new_account.read().opening_balance_date.clone().unwrap_or_default()}",
+                                            oninput: move |event: Event<FormData>| {
+                                                let mut account = new_account().clone();
+                                                account.opening_balance_date = if event.value().is_empty() {
+                                                    None
+                                                } else {
+                                                    Some(event.value().clone())
+                                                };
+                                                new_account.set(account);
+                                            }
+                                        }
+                                    }
+                                }
+                            } else {
+                                rsx! {}
+                            }}
                         }
                         div { class: "flex items-center justify-between mt-4",
                             button {
                                 class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline",
                                 r#type: "submit",
                                 disabled: *is_loading.read(),
-                                {if *is_loading.read() { "Saving..." } else { "Create Account" }}
+                                {if *is_loading.read() {
+                                    "Saving..."
+                                } else if is_editing {
+                                    "Update Account"
+                                } else {
+                                    "Create Account"
+                                }}
                             }
                         }
                     }
@@ -341,16 +1001,77 @@ pub fn AccountsComponent() -> Element {
                 rsx! {}
             }}
 
+            div { class: "mb-4",
+                input {
+                    class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                    r#type: "search",
+                    placeholder: "Search by code or name",
+                    value: "{search_query}",
+                    oninput: handle_search,
+                }
+            }
+
+            div { class: "flex mb-4",
+                select {
+                    class: "shadow border rounded py-2 px-3 text-gray-700 mr-2",
+                    value: "{type_filter}",
+                    onchange: move |event: Event<FormData>| {
+                        let account_type = event.value();
+                        // Keep the category only if it still belongs to the new type
+                        let category_still_valid = account_type.is_empty()
+                            || account_metadata
+                                .read()
+                                .iter()
+                                .find(|metadata| metadata.account_type == account_type)
+                                .is_some_and(|metadata| metadata.categories.contains(&category_filter.read()));
+                        if !category_still_valid {
+                            category_filter.set(String::new());
+                        }
+                        type_filter.set(account_type);
+                    },
+                    option { value: "", "All types" }
+                    for metadata in account_metadata.read().iter() {
+                        option { value: "{metadata.account_type}", "{metadata.account_type}" }
+                    }
+                }
+                select {
+                    class: "shadow border rounded py-2 px-3 text-gray-700",
+                    value: "{category_filter}",
+                    onchange: move |event: Event<FormData>| category_filter.set(event.value()),
+                    option { value: "", "All categories" }
+                    for category in filter_categories.iter() {
+                        option { value: "{category}", "{category}" }
+                    }
+                }
+            }
+
             {if *is_loading.read() && accounts.read().is_empty() {
                 rsx! {
                     div { class: "text-center p-4",
                         "Loading accounts..."
                     }
                 }
+            } else if accounts.read().is_empty() && is_searching {
+                rsx! {
+                    div { class: "text-center p-4 bg-gray-100 rounded",
+                        "No accounts match your search."
+                    }
+                }
+            } else if accounts.read().is_empty() && is_filtering {
+                rsx! {
+                    div { class: "text-center p-4 bg-gray-100 rounded",
+                        "No accounts match the selected filters."
+                    }
+                }
             } else if accounts.read().is_empty() {
                 rsx! {
                     div { class: "text-center p-4 bg-gray-100 rounded",
-                        "No accounts found. Create your first account to get started."
+                        p { "No accounts found. Create your first account to get started." }
+                        button {
+                            class: "mt-3 bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                            onclick: seed_chart,
+                            "Start with a standard chart of accounts"
+                        }
                     }
                 }
             } else {
@@ -359,11 +1080,27 @@ pub fn AccountsComponent() -> Element {
                         table { class: "min-w-full bg-white",
                             thead { class: "bg-gray-100",
                                 tr {
-                                    th { class: "py-2 px-4 border-b text-left", "Code" }
-                                    th { class: "py-2 px-4 border-b text-left", "Name" }
-                                    th { class: "py-2 px-4 border-b text-left", "Type" }
+                                    th {
+                                        class: "py-2 px-4 border-b text-left cursor-pointer",
+                                        onclick: move |_| toggle_sort("code"),
+                                        "Code{code_arrow}"
+                                    }
+                                    th {
+                                        class: "py-2 px-4 border-b text-left cursor-pointer",
+                                        onclick: move |_| toggle_sort("name"),
+                                        "Name{name_arrow}"
+                                    }
+                                    th {
+                                        class: "py-2 px-4 border-b text-left cursor-pointer",
+                                        onclick: move |_| toggle_sort("type"),
+                                        "Type{type_arrow}"
+                                    }
                                     th { class: "py-2 px-4 border-b text-left", "Category" }
-                                    th { class: "py-2 px-4 border-b text-right", "Balance" }
+                                    th {
+                                        class: "py-2 px-4 border-b text-right cursor-pointer",
+                                        onclick: move |_| toggle_sort("balance"),
+                                        "Balance{balance_arrow}"
+                                    }
                                     th { class: "py-2 px-4 border-b text-center", "Status" }
                                     th { class: "py-2 px-4 border-b text-center", "Actions" }
                                 }
@@ -372,6 +1109,25 @@ pub fn AccountsComponent() -> Element {
                                 {account_rows}
                             }
                         }
+                        div {
+                            class: "flex justify-between items-center mt-4",
+                            hidden: is_searching || is_filtering || is_sorted,
+                            button {
+                                class: "bg-gray-200 hover:bg-gray-300 text-gray-700 font-bold py-2 px-4 rounded disabled:opacity-50",
+                                disabled: !has_previous || *is_loading.read(),
+                                onclick: move |_| page.set(page() - 1),
+                                "Previous"
+                            }
+                            span { class: "text-sm text-gray-600",
+                                "Page {page} of {total_pages} ({total_count} accounts)"
+                            }
+                            button {
+                                class: "bg-gray-200 hover:bg-gray-300 text-gray-700 font-bold py-2 px-4 rounded disabled:opacity-50",
+                                disabled: !has_next || *is_loading.read(),
+                                onclick: move |_| page.set(page() + 1),
+                                "Next"
+                            }
+                        }
                     }
                 }
             }}
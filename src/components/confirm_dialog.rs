@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+
+/// Modal that asks the user to confirm an action before it runs
+#[component]
+pub fn ConfirmDialog(
+    message: String,
+    confirm_label: String,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+    #[props(default = "Are you sure?".to_string())] title: String,
+) -> Element {
+    rsx! {
+        div { class: "fixed inset-0 bg-gray-600 bg-opacity-50 flex items-center justify-center z-50",
+            div { class: "bg-white rounded shadow-lg px-8 pt-6 pb-6 max-w-md w-full",
+                role: "dialog",
+                h2 { class: "text-xl font-semibold mb-4", "{title}" }
+                p { class: "text-gray-700 mb-6", "{message}" }
+                div { class: "flex justify-end",
+                    button {
+                        class: "bg-gray-300 hover:bg-gray-400 text-gray-800 font-bold py-2 px-4 rounded mr-2",
+                        r#type: "button",
+                        onclick: move |_| on_cancel.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        class: "bg-red-500 hover:bg-red-700 text-white font-bold py-2 px-4 rounded",
+                        r#type: "button",
+                        onclick: move |_| on_confirm.call(()),
+                        "{confirm_label}"
+                    }
+                }
+            }
+        }
+    }
+}
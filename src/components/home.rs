@@ -1,62 +1,35 @@
+use crate::services::dashboard::{
+    self, DbHealth, FinancialMetric, FinancialSummary, RecentActivity, SystemStatus,
+};
 use crate::Route;
-use async_std::task::sleep;
-use dioxus::html::input::list;
 use dioxus::prelude::*;
-use serde::{Deserialize, Serialize};
-use std::time::Duration;
 
-// Define types for our dynamic data
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-struct SystemStatus {
-    database_connected: bool,
-    version: String,
-    last_backup: Option<String>,
-    fiscal_year: String,
-}
+// Number of entries loaded into the recent activity panel
+const RECENT_ACTIVITY_LIMIT: u32 = 10;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-struct RecentActivity {
-    id: String,
-    action: String,
-    description: String,
-    timestamp: String,
-    user: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-struct FinancialMetric {
-    name: String,
-    value: String,
-    change: f64, // percentage change
-    period: String,
-}
+// Period the headline figures cover and compare against its predecessor
+const METRICS_PERIOD: &str = "month";
 
 #[component]
 pub fn Home() -> Element {
     // State for our dynamic components
     let mut system_status = use_signal(|| Option::<SystemStatus>::None);
+    let mut db_health = use_signal(|| Option::<DbHealth>::None);
     let mut recent_activities = use_signal(Vec::<RecentActivity>::new);
     let mut financial_metrics = use_signal(Vec::<FinancialMetric>::new);
+    let mut financial_summary = use_signal(|| Option::<FinancialSummary>::None);
 
     // Individual loading states
     let mut status_loading = use_signal(|| true);
     let mut activities_loading = use_signal(|| true);
     let mut metrics_loading = use_signal(|| true);
+    let mut summary_refreshing = use_signal(|| false);
 
     // Fetch system status
     use_effect(move || {
         spawn(async move {
-            // For now, we'll simulate with a delay
-            sleep(Duration::from_millis(800)).await;
-
-            // Mock data
-            system_status.set(Some(SystemStatus {
-                database_connected: true,
-                version: "1.0.0".to_string(),
-                last_backup: Some("2025-03-04T06:00:00Z".to_string()),
-                fiscal_year: "2025".to_string(),
-            }));
-
+            system_status.set(dashboard::get_system_status().await.ok());
+            db_health.set(dashboard::get_db_health().await.ok());
             status_loading.set(false);
         });
     });
@@ -64,34 +37,11 @@ pub fn Home() -> Element {
     // Fetch recent activities
     use_effect(move || {
         spawn(async move {
-            // Simulate API call
-            sleep(Duration::from_millis(1200)).await;
-
-            // Mock data
-            recent_activities.set(vec![
-                RecentActivity {
-                    id: "act1".to_string(),
-                    action: "Journal Entry".to_string(),
-                    description: "Created invoice payment #INV-2025-042".to_string(),
-                    timestamp: "10 minutes ago".to_string(),
-                    user: "John Doe".to_string(),
-                },
-                RecentActivity {
-                    id: "act2".to_string(),
-                    action: "Account Created".to_string(),
-                    description: "Added new expense account 'Office Supplies'".to_string(),
-                    timestamp: "2 hours ago".to_string(),
-                    user: "Jane Smith".to_string(),
-                },
-                RecentActivity {
-                    id: "act3".to_string(),
-                    action: "Report Generated".to_string(),
-                    description: "Monthly P&L statement for February 2025".to_string(),
-                    timestamp: "Yesterday".to_string(),
-                    user: "John Doe".to_string(),
-                },
-            ]);
-
+            recent_activities.set(
+                dashboard::get_recent_activities(Some(RECENT_ACTIVITY_LIMIT))
+                    .await
+                    .unwrap_or_default(),
+            );
             activities_loading.set(false);
         });
     });
@@ -99,41 +49,32 @@ pub fn Home() -> Element {
     // Fetch financial metrics
     use_effect(move || {
         spawn(async move {
-            // Simulate API call
-            sleep(Duration::from_millis(1500)).await;
-
-            // Mock data
-            financial_metrics.set(vec![
-                FinancialMetric {
-                    name: "Revenue".to_string(),
-                    value: "$125,430.00".to_string(),
-                    change: 5.2,
-                    period: "This Month".to_string(),
-                },
-                FinancialMetric {
-                    name: "Expenses".to_string(),
-                    value: "$78,230.00".to_string(),
-                    change: -2.1,
-                    period: "This Month".to_string(),
-                },
-                FinancialMetric {
-                    name: "Net Profit".to_string(),
-                    value: "$47,200.00".to_string(),
-                    change: 12.5,
-                    period: "This Month".to_string(),
-                },
-                FinancialMetric {
-                    name: "Cash Balance".to_string(),
-                    value: "$253,890.00".to_string(),
-                    change: 3.7,
-                    period: "Current".to_string(),
-                },
-            ]);
-
+            financial_metrics.set(
+                dashboard::get_metrics_with_change(METRICS_PERIOD)
+                    .await
+                    .unwrap_or_default(),
+            );
             metrics_loading.set(false);
         });
     });
 
+    // Fetch the cached balance summary
+    use_effect(move || {
+        spawn(async move {
+            financial_summary.set(dashboard::get_financial_summary().await.ok());
+        });
+    });
+
+    let refresh_summary = move |_| {
+        summary_refreshing.set(true);
+        spawn(async move {
+            if let Ok(summary) = dashboard::refresh_financial_summary().await {
+                financial_summary.set(Some(summary));
+            }
+            summary_refreshing.set(false);
+        });
+    };
+
     let mut show_all_activities = use_signal(|| false);
 
     // needs to live long enough to be used alter
@@ -157,6 +98,8 @@ pub fn Home() -> Element {
                 let first_char = activity.action.chars().next()
                     .map(|c| c.to_string())
                     .unwrap_or_else(|| "A".to_string());
+                // Show "YYYY-MM-DD HH:MM" from the RFC 3339 timestamp
+                let when = activity.timestamp.get(..16).unwrap_or(&activity.timestamp).replace('T', " ");
                 
                 rsx! {
                     div { class: "py-3 flex items-start",
@@ -174,8 +117,13 @@ pub fn Home() -> Element {
                             p { class: "text-sm text-gray-500",
                                 "{activity.description}"
                             }
+                            for change in activity.details.iter() {
+                                p { class: "text-xs text-gray-500",
+                                    "{change.summary()}"
+                                }
+                            }
                             div { class: "mt-1 flex items-center text-xs text-gray-500",
-                                span { "{activity.user} • {activity.timestamp}" }
+                                span { "{activity.user} • {when}" }
                             }
                         }
                     }
@@ -215,8 +163,26 @@ pub fn Home() -> Element {
                     rsx! {
                         div { class: "grid grid-cols-1 md:grid-cols-4 gap-4",
                             {financial_metrics.read().iter().map(|metric| {
-                                let change_color = if metric.change >= 0.0 { "text-green-600" } else { "text-red-600" };
-                                let change_icon = if metric.change >= 0.0 { "↑" } else { "↓" };
+                                // Rising expenses are bad news, everything else is good news
+                                let rise_is_good = metric.name != "Expenses";
+                                let change_color = match metric.change {
+                                    Some(change) if change > 0.0 && rise_is_good => "text-green-600",
+                                    Some(change) if change < 0.0 && !rise_is_good => "text-green-600",
+                                    Some(change) if change != 0.0 => "text-red-600",
+                                    Some(_) => "text-gray-500",
+                                    None => "text-gray-400",
+                                };
+                                let change_icon = match metric.change {
+                                    Some(change) if change > 0.0 => "↑",
+                                    Some(change) if change < 0.0 => "↓",
+                                    Some(_) => "→",
+                                    None => "",
+                                };
+                                // No change is given when the prior period was zero
+                                let change_text = match metric.change {
+                                    Some(change) => format!("{} {:.1}%", change_icon, change.abs()),
+                                    None => "N/A".to_string(),
+                                };
 
                                 rsx! {
                                     div { class: "border rounded-md p-4",
@@ -224,7 +190,7 @@ pub fn Home() -> Element {
                                         p { class: "text-xl font-semibold", "{metric.value}" }
                                         div { class: "flex items-center mt-1",
                                             span { class: "{change_color} text-sm font-medium",
-                                                "{change_icon} {metric.change.abs()}%"
+                                                "{change_text}"
                                             }
                                             span { class: "text-xs text-gray-500 ml-2", "{metric.period}" }
                                         }
@@ -236,6 +202,48 @@ pub fn Home() -> Element {
                 }}
             }
 
+            // Cached balance summary
+            div { class: "bg-white p-6 rounded-lg shadow-md",
+                div { class: "flex justify-between items-center mb-4",
+                    h2 { class: "text-lg font-medium text-gray-900", "Balance Summary" }
+                    button {
+                        class: "text-sm font-medium text-indigo-600 hover:text-indigo-500",
+                        disabled: *summary_refreshing.read(),
+                        onclick: refresh_summary,
+                        if *summary_refreshing.read() { "Refreshing..." } else { "Refresh" }
+                    }
+                }
+
+                {if let Some(summary) = financial_summary.read().as_ref() {
+                    // Show "YYYY-MM-DD HH:MM" from the RFC 3339 timestamp
+                    let refreshed = summary.refreshed_at.get(..16).unwrap_or(&summary.refreshed_at).replace('T', " ");
+
+                    rsx! {
+                        div { class: "grid grid-cols-1 md:grid-cols-5 gap-4",
+                            for (name, value) in [
+                                ("Assets", &summary.total_assets),
+                                ("Liabilities", &summary.total_liabilities),
+                                ("Equity", &summary.total_equity),
+                                ("Revenue", &summary.total_revenue),
+                                ("Expenses", &summary.total_expenses),
+                            ] {
+                                div { class: "border rounded-md p-4",
+                                    p { class: "text-sm text-gray-500", "{name}" }
+                                    p { class: "text-xl font-semibold", "{value}" }
+                                }
+                            }
+                        }
+                        p { class: "text-xs text-gray-500 mt-2", "As of {refreshed}" }
+                    }
+                } else {
+                    rsx! {
+                        div { class: "text-center py-4 text-gray-500",
+                            "Unable to retrieve the balance summary"
+                        }
+                    }
+                }}
+            }
+
             // Quick access cards
             div { class: "grid grid-cols-1 md:grid-cols-3 gap-6",
                 // Accounting card
@@ -341,14 +349,33 @@ pub fn Home() -> Element {
                         }
                     }
                 } else if let Some(status) = system_status.read().as_ref() {
-                    let db_status_color = if status.database_connected { "text-green-600" } else { "text-red-600" };
-                    let db_status_text = if status.database_connected { "Connected" } else { "Disconnected" };
+                    // A failed health probe counts as disconnected
+                    let (db_status_color, db_status_text, db_status_detail) = match db_health.read().as_ref() {
+                        Some(health) if health.connected => (
+                            "text-green-600",
+                            "Connected",
+                            format!(
+                                "{:.0} ms, {}/{} idle, migration {}",
+                                health.latency_ms.unwrap_or_default(),
+                                health.idle_connections,
+                                health.pool_size,
+                                health
+                                    .latest_migration
+                                    .map(|version| version.to_string())
+                                    .unwrap_or_else(|| "none".to_string()),
+                            ),
+                        ),
+                        Some(health) => (
+                            "text-red-600",
+                            "Disconnected",
+                            health.error.clone().unwrap_or_default(),
+                        ),
+                        None => ("text-red-600", "Disconnected", String::new()),
+                    };
 
+                    // Show "YYYY-MM-DD HH:MM" from the RFC 3339 timestamp
                     let formatted_backup = status.last_backup.as_ref()
-                        .map(|date| {
-                            // In a real app, you would parse and format this properly
-                            "Today 06:00 AM".to_string()
-                        })
+                        .map(|date| date.get(..16).unwrap_or(date).replace('T', " "))
                         .unwrap_or_else(|| "Never".to_string());
 
                     rsx! {
@@ -356,6 +383,7 @@ pub fn Home() -> Element {
                             div { class: "border rounded-md p-4 text-center",
                                 p { class: "text-sm text-gray-500", "Database" }
                                 p { class: "{db_status_color} text-lg font-semibold", "{db_status_text}" }
+                                p { class: "text-xs text-gray-400 truncate", title: "{db_status_detail}", "{db_status_detail}" }
                             }
                             div { class: "border rounded-md p-4 text-center",
                                 p { class: "text-sm text-gray-500", "Version" }
@@ -1,3 +1,4 @@
+use crate::components::QuickSwitcher;
 use crate::Route;
 use dioxus::prelude::*;
 
@@ -13,6 +14,9 @@ pub fn AppLayout() -> Element {
             main { class: "container mx-auto py-6 sm:px-6 lg:px-8",
                 Outlet::<Route> {}
             }
+
+            // Ctrl+K account search, available on every page
+            QuickSwitcher {}
         }
     }
 }
@@ -0,0 +1,210 @@
+use crate::services::accounts::{self, AccountViewModel};
+use crate::services::journal;
+use dioxus::prelude::*;
+
+const INPUT_CLASS: &str = "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline";
+
+#[component]
+pub fn Ledger() -> Element {
+    rsx! {
+        LedgerView { account_id: None }
+    }
+}
+
+/// The ledger page opened on one account, e.g. from the quick switcher
+#[component]
+pub fn AccountLedger(account_id: String) -> Element {
+    rsx! {
+        LedgerView { account_id: Some(account_id) }
+    }
+}
+
+#[component]
+fn LedgerView(account_id: Option<String>) -> Element {
+    let mut accounts = use_signal(Vec::<AccountViewModel>::new);
+    let mut selected_account = use_signal(String::new);
+    let mut start_date = use_signal(String::new);
+    let mut end_date = use_signal(String::new);
+    let mut ledger = use_signal(|| Option::<journal::AccountLedger>::None);
+    let mut breadcrumb = use_signal(String::new);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut is_loading = use_signal(|| false);
+
+    // Load the account list for the selector on component mount
+    use_effect(move || {
+        spawn(async move {
+            match accounts::get_all().await {
+                Ok(list) => accounts.set(list),
+                Err(err) => error_message.set(Some(err.to_string())),
+            }
+        });
+    });
+
+    // Loads the ledger of `account_id` over the chosen dates
+    let mut show_ledger = move |account_id: String| {
+        is_loading.set(true);
+
+        spawn(async move {
+            let start = start_date();
+            let end = end_date();
+            let start = (!start.is_empty()).then_some(start.as_str());
+            let end = (!end.is_empty()).then_some(end.as_str());
+
+            // The breadcrumb is a nicety; without it the heading still names the account
+            breadcrumb.set(
+                accounts::get_path(&account_id)
+                    .await
+                    .map(|path| {
+                        path.accounts
+                            .iter()
+                            .map(|account| account.name.as_str())
+                            .collect::<Vec<_>>()
+                            .join(" > ")
+                    })
+                    .unwrap_or_default(),
+            );
+
+            match journal::get_account_ledger(&account_id, start, end).await {
+                Ok(result) => {
+                    ledger.set(Some(result));
+                    error_message.set(None);
+                }
+                Err(err) => {
+                    ledger.set(None);
+                    error_message.set(Some(err));
+                }
+            }
+            is_loading.set(false);
+        });
+    };
+
+    // Open the account named in the route, and follow it to another one
+    use_effect(use_reactive!(|account_id| {
+        if let Some(account_id) = account_id {
+            selected_account.set(account_id.clone());
+            show_ledger(account_id);
+        }
+    }));
+
+    let load_ledger = move |_| {
+        let account_id = selected_account();
+        if account_id.is_empty() {
+            error_message.set(Some("Select an account first".to_string()));
+            return;
+        }
+        show_ledger(account_id);
+    };
+
+    rsx! {
+        div { class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "General Ledger" }
+
+            div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4",
+                div { class: "grid grid-cols-1 md:grid-cols-4 gap-4 items-end",
+                    div { class: "md:col-span-2",
+                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "ledgerAccount", "Account" }
+                        select {
+                            id: "ledgerAccount",
+                            class: INPUT_CLASS,
+                            value: "{selected_account}",
+                            onchange: move |event: Event<FormData>| selected_account.set(event.value()),
+                            option { value: "", "Select an account" }
+                            for account in accounts.read().iter() {
+                                option { value: "{account.id}", "{account.code} - {account.name}" }
+                            }
+                        }
+                    }
+                    div {
+                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "ledgerStart", "From" }
+                        input {
+                            id: "ledgerStart",
+                            class: INPUT_CLASS,
+                            r#type: "date",
+                            value: "{start_date}",
+                            oninput: move |event: Event<FormData>| start_date.set(event.value()),
+                        }
+                    }
+                    div {
+                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "ledgerEnd", "To" }
+                        input {
+                            id: "ledgerEnd",
+                            class: INPUT_CLASS,
+                            r#type: "date",
+                            value: "{end_date}",
+                            oninput: move |event: Event<FormData>| end_date.set(event.value()),
+                        }
+                    }
+                }
+                div { class: "mt-4",
+                    button {
+                        class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                        disabled: *is_loading.read(),
+                        onclick: load_ledger,
+                        "Show Ledger"
+                    }
+                }
+            }
+
+            {match &*error_message.read() {
+                Some(error) => rsx! {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                        span { class: "block sm:inline", "{error}" }
+                    }
+                },
+                None => rsx! {}
+            }}
+
+            {if *is_loading.read() {
+                rsx! {
+                    div { class: "text-center p-4", "Loading ledger..." }
+                }
+            } else if let Some(ledger) = ledger.read().as_ref() {
+                rsx! {
+                    div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8",
+                        if !breadcrumb.read().is_empty() {
+                            p { class: "text-sm text-gray-500 mb-1", "{breadcrumb}" }
+                        }
+                        h2 { class: "text-xl font-semibold mb-4", "{ledger.code} - {ledger.name}" }
+                        table { class: "min-w-full bg-white",
+                            thead {
+                                tr {
+                                    th { class: "py-2 px-4 border-b text-left", "Date" }
+                                    th { class: "py-2 px-4 border-b text-left", "Description" }
+                                    th { class: "py-2 px-4 border-b text-left", "Reference" }
+                                    th { class: "py-2 px-4 border-b text-right", "Debit" }
+                                    th { class: "py-2 px-4 border-b text-right", "Credit" }
+                                    th { class: "py-2 px-4 border-b text-right", "Balance" }
+                                }
+                            }
+                            tbody {
+                                tr { class: "bg-gray-50",
+                                    td { class: "py-2 px-4 border-b", colspan: "5", "Opening balance" }
+                                    td { class: "py-2 px-4 border-b text-right", "{ledger.opening_balance}" }
+                                }
+                                for row in ledger.rows.iter() {
+                                    tr {
+                                        td { class: "py-2 px-4 border-b", "{row.entry_date}" }
+                                        td { class: "py-2 px-4 border-b", "{row.description}" }
+                                        td { class: "py-2 px-4 border-b", "{row.reference.clone().unwrap_or_default()}" }
+                                        td { class: "py-2 px-4 border-b text-right", "{row.debit}" }
+                                        td { class: "py-2 px-4 border-b text-right", "{row.credit}" }
+                                        td { class: "py-2 px-4 border-b text-right", "{row.running_balance}" }
+                                    }
+                                }
+                                tr { class: "bg-gray-50 font-semibold",
+                                    td { class: "py-2 px-4 border-b", colspan: "5", "Closing balance" }
+                                    td { class: "py-2 px-4 border-b text-right", "{ledger.closing_balance}" }
+                                }
+                            }
+                        }
+                        if ledger.rows.is_empty() {
+                            div { class: "text-center p-4 text-gray-500", "No posted entries in this period." }
+                        }
+                    }
+                }
+            } else {
+                rsx! {}
+            }}
+        }
+    }
+}
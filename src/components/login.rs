@@ -0,0 +1,87 @@
+use crate::services::auth;
+use crate::Route;
+use dioxus::events::{FormData, FormEvent};
+use dioxus::prelude::*;
+
+#[component]
+pub fn Login() -> Element {
+    let mut username = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut is_loading = use_signal(|| false);
+    let navigator = use_navigator();
+
+    let handle_submit = move |event: FormEvent| {
+        event.prevent_default();
+
+        is_loading.set(true);
+
+        let username_value = username();
+        let password_value = password();
+
+        spawn(async move {
+            match auth::login(&username_value, &password_value).await {
+                Ok(response) => match auth::store_token(&response.token) {
+                    Ok(()) => {
+                        error_message.set(None);
+                        password.set(String::new());
+                        navigator.push(Route::Home {});
+                    }
+                    Err(err) => error_message.set(Some(err)),
+                },
+                Err(err) => {
+                    error_message.set(Some(err));
+                }
+            }
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "container mx-auto p-4 max-w-md",
+            h1 { class: "text-2xl font-bold mb-4", "Sign In" }
+
+            {match &*error_message.read() {
+                Some(error) => rsx! {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                        span { class: "block sm:inline", "{error}" }
+                    }
+                },
+                None => rsx! {}
+            }}
+
+            form { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4", onsubmit: handle_submit,
+                div { class: "mb-4",
+                    label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "username", "Username" }
+                    input {
+                        id: "username",
+                        class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                        r#type: "text",
+                        autocomplete: "username",
+                        required: "true",
+                        value: "{username}",
+                        oninput: move |event: Event<FormData>| username.set(event.value()),
+                    }
+                }
+                div { class: "mb-6",
+                    label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "password", "Password" }
+                    input {
+                        id: "password",
+                        class: "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline",
+                        r#type: "password",
+                        autocomplete: "current-password",
+                        required: "true",
+                        value: "{password}",
+                        oninput: move |event: Event<FormData>| password.set(event.value()),
+                    }
+                }
+                button {
+                    class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded focus:outline-none focus:shadow-outline",
+                    r#type: "submit",
+                    disabled: *is_loading.read(),
+                    {if *is_loading.read() { "Signing in..." } else { "Sign In" }}
+                }
+            }
+        }
+    }
+}
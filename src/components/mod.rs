@@ -1,6 +1,16 @@
 pub mod AccountsComponent;
+pub mod confirm_dialog;
 pub mod home;
 pub mod layout;
+pub mod ledger;
+pub mod login;
+pub mod quick_switcher;
+pub mod settings;
 
+pub use confirm_dialog::ConfirmDialog;
 pub use home::Home;
 pub use layout::AppLayout;
+pub use ledger::{AccountLedger, Ledger};
+pub use login::Login;
+pub use quick_switcher::QuickSwitcher;
+pub use settings::Settings;
@@ -0,0 +1,197 @@
+use crate::services::accounts::{self, AccountSearchResult};
+use crate::Route;
+use async_std::task::sleep;
+use dioxus::events::FormData;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+const INPUT_CLASS: &str = "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline";
+
+// Delay after the last keystroke before searching
+const SEARCH_DEBOUNCE_MS: u64 = 200;
+
+// Most matches listed; the search already puts the best ones first
+const MAX_RESULTS: usize = 8;
+
+// Reports Ctrl+K (Cmd+K on macOS) pressed anywhere on the page. Browsers bind
+// the combination to their own search box, so it is claimed here.
+const SHORTCUT_LISTENER: &str = r#"
+document.addEventListener("keydown", (event) => {
+    if ((event.ctrlKey || event.metaKey) && event.key.toLowerCase() === "k") {
+        event.preventDefault();
+        dioxus.send(true);
+    }
+});
+"#;
+
+/// Overlay for jumping to an account's ledger by typing its code or name.
+/// Opens on Ctrl+K; arrow keys move through the matches, Enter opens the
+/// highlighted one and Escape closes it.
+#[component]
+pub fn QuickSwitcher() -> Element {
+    let mut is_open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut results = use_signal(Vec::<AccountSearchResult>::new);
+    let mut highlighted = use_signal(|| 0usize);
+    let mut is_searching = use_signal(|| false);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let navigator = use_navigator();
+
+    // Listen for the shortcut for as long as the layout is shown
+    use_effect(move || {
+        spawn(async move {
+            let mut listener = document::eval(SHORTCUT_LISTENER);
+            while listener.recv::<bool>().await.is_ok() {
+                is_open.set(true);
+            }
+        });
+    });
+
+    let mut close = move || {
+        is_open.set(false);
+        query.set(String::new());
+        results.set(Vec::new());
+        highlighted.set(0);
+        is_searching.set(false);
+        error_message.set(None);
+    };
+
+    let mut select = move |index: usize| {
+        let Some(result) = results.read().get(index).cloned() else {
+            return;
+        };
+        close();
+        navigator.push(Route::AccountLedger {
+            account_id: result.account.id,
+        });
+    };
+
+    let handle_input = move |event: Event<FormData>| {
+        let value = event.value();
+        query.set(value.clone());
+
+        if value.trim().is_empty() {
+            results.set(Vec::new());
+            highlighted.set(0);
+            is_searching.set(false);
+            return;
+        }
+        is_searching.set(true);
+
+        spawn(async move {
+            sleep(Duration::from_millis(SEARCH_DEBOUNCE_MS)).await;
+
+            // A newer keystroke has superseded this search
+            if *query.peek() != value {
+                return;
+            }
+
+            let result = accounts::search(&value).await;
+
+            // The query may have changed while the search was in flight
+            if *query.peek() != value {
+                return;
+            }
+
+            match result {
+                Ok(found) => {
+                    results.set(found.into_iter().take(MAX_RESULTS).collect());
+                    error_message.set(None);
+                }
+                Err(err) => {
+                    results.set(Vec::new());
+                    error_message.set(Some(err.to_string()));
+                }
+            }
+            highlighted.set(0);
+            is_searching.set(false);
+        });
+    };
+
+    let handle_keydown = move |event: KeyboardEvent| {
+        let count = results.read().len();
+        match event.key() {
+            Key::ArrowDown => {
+                event.prevent_default();
+                if count > 0 {
+                    highlighted.set((highlighted() + 1) % count);
+                }
+            }
+            Key::ArrowUp => {
+                event.prevent_default();
+                if count > 0 {
+                    highlighted.set((highlighted() + count - 1) % count);
+                }
+            }
+            Key::Enter => {
+                event.prevent_default();
+                select(highlighted());
+            }
+            Key::Escape => close(),
+            _ => {}
+        }
+    };
+
+    if !is_open() {
+        return rsx! {};
+    }
+
+    let show_no_matches = !*is_searching.read()
+        && error_message.read().is_none()
+        && results.read().is_empty()
+        && !query.read().trim().is_empty();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-start justify-center pt-24 bg-black bg-opacity-30",
+            onclick: move |_| close(),
+            div {
+                class: "bg-white shadow-md rounded w-full max-w-lg p-4",
+                onclick: move |event| event.stop_propagation(),
+                input {
+                    class: INPUT_CLASS,
+                    r#type: "text",
+                    role: "combobox",
+                    aria_expanded: "{!results.read().is_empty()}",
+                    aria_controls: "quickSwitcherResults",
+                    placeholder: "Go to account by code or name",
+                    value: "{query}",
+                    oninput: handle_input,
+                    onkeydown: handle_keydown,
+                    onmounted: move |event| async move {
+                        let _ = event.set_focus(true).await;
+                    },
+                }
+
+                {match &*error_message.read() {
+                    Some(error) => rsx! {
+                        div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mt-2",
+                            span { class: "block sm:inline", "{error}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                ul { id: "quickSwitcherResults", role: "listbox", class: "mt-2",
+                    for (index, result) in results.read().iter().enumerate() {
+                        li {
+                            key: "{result.account.id}",
+                            role: "option",
+                            aria_selected: "{index == highlighted()}",
+                            class: if index == highlighted() { "px-3 py-2 rounded cursor-pointer bg-blue-100" } else { "px-3 py-2 rounded cursor-pointer" },
+                            onmouseenter: move |_| highlighted.set(index),
+                            onclick: move |_| select(index),
+                            span { class: "font-mono mr-3", "{result.account.code}" }
+                            span { "{result.account.name}" }
+                            span { class: "float-right text-sm text-gray-500", "{result.account.account_type}" }
+                        }
+                    }
+                }
+
+                if show_no_matches {
+                    p { class: "text-center text-gray-500 p-2", "No matching accounts." }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,463 @@
+use crate::components::ConfirmDialog;
+use crate::services::dashboard;
+use crate::services::diagnostics::{self, BooksBalanceCheck, RecordedError};
+use crate::services::settings::{self, AppConfigView, BackupInfo};
+use dioxus::prelude::*;
+
+// Log levels accepted by the backend configuration
+const LOG_LEVELS: &[&str] = &["error", "warn", "info", "debug", "trace"];
+
+const MONTH_NAMES: &[&str] = &[
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const INPUT_CLASS: &str = "shadow appearance-none border rounded w-full py-2 px-3 text-gray-700 leading-tight focus:outline-none focus:shadow-outline";
+
+#[component]
+pub fn Settings() -> Element {
+    let mut app_config = use_signal(|| Option::<AppConfigView>::None);
+    let mut fiscal_year = use_signal(String::new);
+    let mut log_level = use_signal(String::new);
+    let mut data_dir = use_signal(String::new);
+    let mut config_error = use_signal(|| Option::<String>::None);
+    let mut config_saved = use_signal(|| false);
+    let mut is_saving = use_signal(|| false);
+
+    let mut last_backup = use_signal(|| Option::<String>::None);
+    let mut backup_result = use_signal(|| Option::<BackupInfo>::None);
+    let mut backup_error = use_signal(|| Option::<String>::None);
+    let mut is_backing_up = use_signal(|| false);
+    let mut restore_path = use_signal(String::new);
+    let mut restore_message = use_signal(|| Option::<String>::None);
+    let mut confirm_restore = use_signal(|| false);
+
+    let mut recent_errors = use_signal(Vec::<RecordedError>::new);
+    let mut error_message = use_signal(|| Option::<String>::None);
+    let mut is_loading = use_signal(|| true);
+
+    let mut books_check = use_signal(|| Option::<BooksBalanceCheck>::None);
+    let mut books_error = use_signal(|| Option::<String>::None);
+    let mut is_checking = use_signal(|| false);
+
+    let mut load_errors = move || {
+        is_loading.set(true);
+
+        spawn(async move {
+            match diagnostics::get_recent_errors().await {
+                Ok(errors) => {
+                    recent_errors.set(errors);
+                    error_message.set(None);
+                }
+                Err(err) => {
+                    error_message.set(Some(err));
+                }
+            }
+            is_loading.set(false);
+        });
+    };
+
+    // Load the settings on component mount
+    use_effect(move || {
+        spawn(async move {
+            match settings::get_app_config().await {
+                Ok(config) => {
+                    log_level.set(config.log_level.clone());
+                    data_dir.set(config.data_dir.clone());
+                    app_config.set(Some(config));
+                }
+                Err(err) => config_error.set(Some(err)),
+            }
+
+            if let Ok(status) = dashboard::get_system_status().await {
+                fiscal_year.set(status.fiscal_year);
+                last_backup.set(status.last_backup);
+            }
+        });
+    });
+
+    let save_config = move |event: FormEvent| {
+        event.prevent_default();
+        is_saving.set(true);
+        config_saved.set(false);
+
+        spawn(async move {
+            match settings::update_app_config(&log_level(), &data_dir()).await {
+                Ok(_) => {
+                    config_error.set(None);
+                    config_saved.set(true);
+                }
+                Err(err) => config_error.set(Some(err)),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let create_backup = move |_| {
+        is_backing_up.set(true);
+
+        spawn(async move {
+            match settings::create_backup().await {
+                Ok(backup) => {
+                    last_backup.set(Some(backup.created_at.clone()));
+                    restore_path.set(backup.file_path.clone());
+                    backup_result.set(Some(backup));
+                    backup_error.set(None);
+                }
+                Err(err) => backup_error.set(Some(err)),
+            }
+            is_backing_up.set(false);
+        });
+    };
+
+    let restore_backup = move |_| {
+        confirm_restore.set(false);
+        is_backing_up.set(true);
+        restore_message.set(None);
+
+        spawn(async move {
+            match settings::restore_backup(&restore_path(), true).await {
+                Ok(result) => {
+                    restore_message.set(Some(format!(
+                        "Restored {} accounts from {} ({} before the restore).",
+                        result.accounts_after, result.file_path, result.accounts_before
+                    )));
+                    backup_error.set(None);
+                }
+                Err(err) => backup_error.set(Some(err)),
+            }
+            is_backing_up.set(false);
+        });
+    };
+
+    let run_books_check = move |_| {
+        is_checking.set(true);
+
+        spawn(async move {
+            match diagnostics::verify_books_balanced().await {
+                Ok(check) => {
+                    books_check.set(Some(check));
+                    books_error.set(None);
+                }
+                Err(err) => books_error.set(Some(err)),
+            }
+            is_checking.set(false);
+        });
+    };
+
+    // Show "YYYY-MM-DD HH:MM" from the RFC 3339 timestamp
+    let last_backup_text = last_backup
+        .read()
+        .as_ref()
+        .map(|date| date.get(..16).unwrap_or(date).replace('T', " "))
+        .unwrap_or_else(|| "Never".to_string());
+
+    // Load recent errors on component mount
+    use_effect(load_errors);
+
+    // Plain-text rendering for pasting into bug reports
+    let report = recent_errors
+        .read()
+        .iter()
+        .map(|error| match &error.details {
+            Some(details) => format!(
+                "{} [{}] {}: {} ({})",
+                error.timestamp, error.command, error.code, error.message, details
+            ),
+            None => format!(
+                "{} [{}] {}: {}",
+                error.timestamp, error.command, error.code, error.message
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    rsx! {
+        div { class: "container mx-auto p-4",
+            h1 { class: "text-2xl font-bold mb-4", "Settings" }
+
+            div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4",
+                h2 { class: "text-xl font-semibold mb-4", "Application" }
+
+                {match &*config_error.read() {
+                    Some(error) => rsx! {
+                        div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline", "{error}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                if *config_saved.read() {
+                    div { class: "bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded mb-4",
+                        span { class: "block sm:inline", "Settings saved. They take effect after the application restarts." }
+                    }
+                }
+
+                {match &*app_config.read() {
+                    Some(config) => rsx! {
+                        dl { class: "grid grid-cols-2 gap-2 mb-6 text-sm",
+                            dt { class: "text-gray-500", "Application" }
+                            dd { "{config.app_name}" }
+                            dt { class: "text-gray-500", "Version" }
+                            dd { "v{config.version}" }
+                            dt { class: "text-gray-500", "Fiscal Year" }
+                            dd { "{fiscal_year}" }
+                            dt { class: "text-gray-500", "Fiscal Year Starts" }
+                            dd {
+                                {MONTH_NAMES
+                                    .get(config.fiscal_year_start_month.saturating_sub(1) as usize)
+                                    .copied()
+                                    .unwrap_or("January")}
+                            }
+                            dt { class: "text-gray-500", "Database Connections" }
+                            dd { "{config.db_max_connections} (timeout {config.db_timeout_seconds}s)" }
+                            dt { class: "text-gray-500", "Session Length" }
+                            dd { "{config.token_expiry_hours} hours" }
+                        }
+                        form { onsubmit: save_config,
+                            div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
+                                div {
+                                    label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "logLevel", "Log Level" }
+                                    select {
+                                        id: "logLevel",
+                                        class: INPUT_CLASS,
+                                        value: "{log_level}",
+                                        onchange: move |event: Event<FormData>| log_level.set(event.value()),
+                                        for level in LOG_LEVELS.iter() {
+                                            option { value: "{level}", "{level}" }
+                                        }
+                                    }
+                                }
+                                div {
+                                    label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "dataDir", "Data Directory" }
+                                    input {
+                                        id: "dataDir",
+                                        class: INPUT_CLASS,
+                                        r#type: "text",
+                                        required: "true",
+                                        value: "{data_dir}",
+                                        oninput: move |event: Event<FormData>| data_dir.set(event.value()),
+                                    }
+                                }
+                            }
+                            div { class: "mt-4",
+                                button {
+                                    class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                                    r#type: "submit",
+                                    disabled: *is_saving.read(),
+                                    if *is_saving.read() { "Saving..." } else { "Save Settings" }
+                                }
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div { class: "text-center p-4", "Loading settings..." }
+                    }
+                }}
+            }
+
+            div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4",
+                div { class: "flex justify-between items-center mb-4",
+                    h2 { class: "text-xl font-semibold", "Backups" }
+                    button {
+                        class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                        disabled: *is_backing_up.read(),
+                        onclick: create_backup,
+                        if *is_backing_up.read() { "Backing Up..." } else { "Back Up Now" }
+                    }
+                }
+
+                {match &*backup_error.read() {
+                    Some(error) => rsx! {
+                        div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline", "{error}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                {match &*backup_result.read() {
+                    Some(backup) => rsx! {
+                        div { class: "bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline",
+                                "Backed up {backup.account_count} accounts to {backup.file_path} ({backup.size_bytes} bytes)."
+                            }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                {match &*restore_message.read() {
+                    Some(message) => rsx! {
+                        div { class: "bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline", "{message}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                p { class: "text-sm text-gray-600 mb-4", "Last backup: {last_backup_text}" }
+
+                div { class: "flex items-end gap-2",
+                    div { class: "flex-grow",
+                        label { class: "block text-gray-700 text-sm font-bold mb-2", r#for: "restorePath", "Restore From File" }
+                        input {
+                            id: "restorePath",
+                            class: INPUT_CLASS,
+                            r#type: "text",
+                            placeholder: "Path to a backup file",
+                            value: "{restore_path}",
+                            oninput: move |event: Event<FormData>| restore_path.set(event.value()),
+                        }
+                    }
+                    button {
+                        class: "bg-red-500 hover:bg-red-700 text-white font-bold py-2 px-4 rounded",
+                        disabled: *is_backing_up.read() || restore_path.read().trim().is_empty(),
+                        onclick: move |_| confirm_restore.set(true),
+                        "Restore"
+                    }
+                }
+            }
+
+            if *confirm_restore.read() {
+                ConfirmDialog {
+                    title: "Restore backup",
+                    message: format!(
+                        "Every account will be replaced with those in {}. This cannot be undone.",
+                        restore_path()
+                    ),
+                    confirm_label: "Restore",
+                    on_confirm: restore_backup,
+                    on_cancel: move |_| confirm_restore.set(false),
+                }
+            }
+
+            div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4",
+                div { class: "flex justify-between items-center mb-4",
+                    h2 { class: "text-xl font-semibold", "Books Check" }
+                    button {
+                        class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                        disabled: *is_checking.read(),
+                        onclick: run_books_check,
+                        if *is_checking.read() { "Checking..." } else { "Run Check" }
+                    }
+                }
+
+                {match &*books_error.read() {
+                    Some(error) => rsx! {
+                        div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline", "{error}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                {match &*books_check.read() {
+                    Some(check) => rsx! {
+                        div { class: "grid grid-cols-3 gap-4 mb-4",
+                            div {
+                                p { class: "text-sm text-gray-600", "Debit-normal total" }
+                                p { class: "font-mono", "{check.debit_normal_total}" }
+                            }
+                            div {
+                                p { class: "text-sm text-gray-600", "Credit-normal total" }
+                                p { class: "font-mono", "{check.credit_normal_total}" }
+                            }
+                            div {
+                                p { class: "text-sm text-gray-600", "Difference" }
+                                p { class: "font-mono", "{check.difference}" }
+                            }
+                        }
+                        if check.is_balanced {
+                            div { class: "bg-green-100 border border-green-400 text-green-700 px-4 py-3 rounded",
+                                "The books balance (checked {check.checked_at})."
+                            }
+                        } else {
+                            div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                                "The books do not balance (checked {check.checked_at})."
+                            }
+                            table { class: "min-w-full bg-white",
+                                thead {
+                                    tr {
+                                        th { class: "py-2 px-4 border-b text-left", "Code" }
+                                        th { class: "py-2 px-4 border-b text-left", "Name" }
+                                        th { class: "py-2 px-4 border-b text-left", "Type" }
+                                        th { class: "py-2 px-4 border-b text-right", "Balance" }
+                                    }
+                                }
+                                tbody {
+                                    for account in check.accounts.iter() {
+                                        tr { key: "{account.id}",
+                                            td { class: "py-2 px-4 border-b", "{account.code}" }
+                                            td { class: "py-2 px-4 border-b", "{account.name}" }
+                                            td { class: "py-2 px-4 border-b", "{account.account_type}" }
+                                            td { class: "py-2 px-4 border-b text-right font-mono", "{account.balance}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        p { class: "text-sm text-gray-600",
+                            "Confirms that debit-normal and credit-normal account balances agree."
+                        }
+                    },
+                }}
+            }
+
+            div { class: "bg-white shadow-md rounded px-8 pt-6 pb-8 mb-4",
+                div { class: "flex justify-between items-center mb-4",
+                    h2 { class: "text-xl font-semibold", "Recent Errors" }
+                    button {
+                        class: "bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded",
+                        disabled: *is_loading.read(),
+                        onclick: move |_| load_errors(),
+                        "Refresh"
+                    }
+                }
+
+                {match &*error_message.read() {
+                    Some(error) => rsx! {
+                        div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded mb-4",
+                            span { class: "block sm:inline", "{error}" }
+                        }
+                    },
+                    None => rsx! {}
+                }}
+
+                {if *is_loading.read() {
+                    rsx! {
+                        div { class: "text-center p-4", "Loading recent errors..." }
+                    }
+                } else if recent_errors.read().is_empty() {
+                    rsx! {
+                        div { class: "text-center p-4 bg-gray-100 rounded", "No errors recorded." }
+                    }
+                } else {
+                    rsx! {
+                        p { class: "text-sm text-gray-600 mb-2",
+                            "Copy the entries below into your bug report."
+                        }
+                        textarea {
+                            class: "w-full h-64 font-mono text-xs border rounded p-2",
+                            readonly: true,
+                            value: "{report}",
+                        }
+                    }
+                }}
+            }
+        }
+    }
+}
@@ -2,8 +2,12 @@ mod app;
 mod components;
 mod services;
 
+use crate::components::AccountLedger;
 use crate::components::AppLayout;
 use crate::components::Home;
+use crate::components::Ledger;
+use crate::components::Login;
+use crate::components::Settings;
 use app::App;
 
 use dioxus::prelude::*;
@@ -22,6 +26,8 @@ enum Route {
     Accounting {},
     #[route("/accounting/ledger")]
     Ledger {},
+    #[route("/accounting/ledger/:account_id")]
+    AccountLedger { account_id: String },
     #[route("/accounting/journal")]
     Journal {},
     #[route("/settings")]
@@ -36,11 +42,6 @@ fn main() {
     launch(App);
 }
 
-#[component]
-fn Login() -> Element {
-    todo!()
-}
-
 #[component]
 fn Dashboard() -> Element {
     todo!()
@@ -51,21 +52,11 @@ fn Accounting() -> Element {
     todo!()
 }
 
-#[component]
-fn Ledger() -> Element {
-    todo!()
-}
-
 #[component]
 fn Journal() -> Element {
     todo!()
 }
 
-#[component]
-fn Settings() -> Element {
-    todo!()
-}
-
 #[component]
 fn PageNotFound(route: Vec<String>) -> Element {
     rsx! {
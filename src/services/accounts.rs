@@ -1,5 +1,7 @@
-use crate::services::tauri;
+use crate::services::auth;
+use crate::services::tauri::{self, ErrorResponse};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 // Account view model for the frontend
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -14,8 +16,89 @@ pub struct AccountViewModel {
     pub is_active: bool,
     pub parent_id: Option<String>,
     pub balance: String,
+    pub currency: String,
+    pub version: i32,
+    pub note_count: i32,
     pub created_at: String,
     pub updated_at: String,
+    pub created_by: Option<String>,
+    pub updated_by: Option<String>,
+}
+
+// An account changed since a sync point; deleted ones are to be evicted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ModifiedAccount {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    pub is_deleted: bool,
+}
+
+// An account found by search; `match_kind` is "exact_code", "code_prefix",
+// "name_prefix" or "substring", and absent when the query was empty
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountSearchResult {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    pub match_kind: Option<String>,
+}
+
+// A node of the chart-of-accounts tree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountTreeNode {
+    #[serde(flatten)]
+    pub account: AccountViewModel,
+    // Number of ancestors; root accounts are at depth 0
+    pub depth: u32,
+    pub children: Vec<AccountTreeNode>,
+}
+
+// Posted line count and last activity date (YYYY-MM-DD) of an account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountActivityStats {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub is_active: bool,
+    pub balance: String,
+    pub line_count: i64,
+    pub last_activity: Option<String>,
+    pub archive_candidate: bool,
+}
+
+// What deleting an account would affect; `reasons` explains a refusal
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeleteImpact {
+    pub children: i64,
+    pub posted_lines: i64,
+    pub unposted_lines: i64,
+    pub notes: i64,
+    pub can_delete: bool,
+    pub reasons: Vec<String>,
+}
+
+// A code an account was renumbered from and what it became
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CodeChange {
+    pub account_id: String,
+    pub old_code: String,
+    pub new_code: String,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+// The ancestors of an account from the root down, ending with the account itself
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountPath {
+    pub accounts: Vec<AccountViewModel>,
+    pub depth: u32,
+}
+
+// An account with the summed balance of itself and everything below it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SubtreeBalance {
+    pub account: AccountViewModel,
+    pub subtree_balance: String,
+    pub descendant_count: usize,
 }
 
 // Data transfer object for creating/updating accounts
@@ -28,6 +111,214 @@ pub struct AccountDto {
     pub category: String,
     pub subcategory: Option<String>,
     pub parent_id: Option<String>,
+    pub currency: String,
+    // Version of the account being edited; None when creating
+    pub version: Option<i32>,
+    // Balance to open a new account with, posted against opening balance equity
+    pub opening_balance: Option<String>,
+    // Date of the opening balance entry (YYYY-MM-DD); today when empty
+    pub opening_balance_date: Option<String>,
+}
+
+// A chunk of accounts plus the cursor to request the next one
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountStreamChunk {
+    pub accounts: Vec<AccountViewModel>,
+    pub next_cursor: Option<String>,
+}
+
+// An account type together with the categories it allows
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountTypeMetadata {
+    pub account_type: String,
+    pub categories: Vec<String>,
+}
+
+// One page of accounts plus the total number of accounts
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountPage {
+    pub accounts: Vec<AccountViewModel>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total_count: i64,
+}
+
+// Working-capital metrics; ratios are None when there are no current liabilities
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkingCapitalMetrics {
+    pub current_assets: String,
+    pub inventory: String,
+    pub current_liabilities: String,
+    pub net_working_capital: String,
+    pub current_ratio: Option<String>,
+    pub quick_ratio: Option<String>,
+}
+
+// An IIF account whose QuickBooks type has no mapping
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnmappedIifAccount {
+    pub name: String,
+    pub foreign_type: String,
+}
+
+// Result of a batch status change; `missing` lists ids that matched no account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountStatusBatch {
+    pub updated: Vec<AccountViewModel>,
+    pub missing: Vec<String>,
+}
+
+// Result of importing a QuickBooks IIF chart of accounts
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IifImportReport {
+    pub imported: Vec<AccountViewModel>,
+    pub unmapped: Vec<UnmappedIifAccount>,
+    pub skipped: Vec<String>,
+}
+
+// A single chart-of-accounts checklist item
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompletenessItem {
+    pub key: String,
+    pub label: String,
+    pub satisfied: bool,
+    pub suggestion: Option<String>,
+}
+
+// Chart-of-accounts completeness score (0-100) and checklist
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChartCompleteness {
+    pub score: u32,
+    pub checklist: Vec<CompletenessItem>,
+}
+
+// One account line of the balance sheet
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceSheetLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub balance: String,
+}
+
+// Accounts of one category with their subtotal
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceSheetCategory {
+    pub category: String,
+    pub accounts: Vec<BalanceSheetLine>,
+    pub total: String,
+}
+
+// Assets, liabilities or equity grouped by category
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceSheetSection {
+    pub account_type: String,
+    pub categories: Vec<BalanceSheetCategory>,
+    pub total: String,
+}
+
+// Balance sheet with totals and the accounting equation check
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceSheet {
+    pub assets: BalanceSheetSection,
+    pub liabilities: BalanceSheetSection,
+    pub equity: BalanceSheetSection,
+    pub current_earnings: String,
+    pub total_equity: String,
+    pub total_liabilities_and_equity: String,
+    pub is_balanced: bool,
+}
+
+// One revenue or expense account with its activity over the period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IncomeStatementLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub amount: String,
+}
+
+// Revenue or expense accounts of one category with their subtotal
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IncomeStatementGroup {
+    pub category: String,
+    pub accounts: Vec<IncomeStatementLine>,
+    pub total: String,
+}
+
+// Profit and loss over a date range
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IncomeStatement {
+    pub period_start: String,
+    pub period_end: String,
+    pub operating_revenue: IncomeStatementGroup,
+    pub operating_expenses: IncomeStatementGroup,
+    pub gross_profit: String,
+    pub non_operating_revenue: IncomeStatementGroup,
+    pub non_operating_expenses: IncomeStatementGroup,
+    pub total_revenue: String,
+    pub total_expenses: String,
+    pub net_profit: String,
+}
+
+// The budget of an account for a fiscal period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BudgetViewModel {
+    pub id: String,
+    pub account_id: String,
+    pub period_id: String,
+    pub amount: String,
+    pub updated_at: String,
+}
+
+// Budgeted against actual amounts of one revenue or expense account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BudgetVarianceLine {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub budgeted: String,
+    pub actual: String,
+    pub variance: String,
+    pub favorable: bool,
+}
+
+// Budget variance of every budgeted or active revenue and expense account in a period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BudgetVarianceReport {
+    pub period_id: String,
+    pub period_name: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub lines: Vec<BudgetVarianceLine>,
+}
+
+// The accounts of one type with the sum of their balances
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountGroup {
+    pub account_type: String,
+    pub accounts: Vec<AccountViewModel>,
+    pub subtotal: String,
+}
+
+// What happened to the account named in an `account-changed` event
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountChangeKind {
+    Created,
+    Updated,
+    Deleted,
+    StatusChanged,
+}
+
+// Sent by the backend to every window after an account changes; `account` is
+// the row as saved and is absent for deletions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountChangedEvent {
+    pub account_id: String,
+    pub kind: AccountChangeKind,
+    pub account: Option<AccountViewModel>,
 }
 
 impl Default for AccountDto {
@@ -40,94 +331,628 @@ impl Default for AccountDto {
             category: "CURRENT_ASSET".to_string(),
             subcategory: None,
             parent_id: None,
+            currency: "USD".to_string(),
+            version: None,
+            opening_balance: None,
+            opening_balance_date: None,
+        }
+    }
+}
+
+// Arguments for mutations that address a single account by ID
+#[derive(Serialize)]
+struct IdArgs<'a> {
+    id: &'a str,
+    token: String,
+}
+
+impl<'a> IdArgs<'a> {
+    fn new(id: &'a str) -> Self {
+        Self {
+            id,
+            token: session_token(),
         }
     }
 }
 
+/// How long imports and reports may run before the UI reports them as slow
+const SLOW_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The stored session token; mutations are rejected by the backend without one
+fn session_token() -> String {
+    auth::stored_token().unwrap_or_default()
+}
+
 /// Fetches all accounts from the backend
-pub async fn get_all() -> Result<Vec<AccountViewModel>, String> {
+pub async fn get_all() -> Result<Vec<AccountViewModel>, ErrorResponse> {
     tauri::invoke::<(), Vec<AccountViewModel>>("get_accounts", &())
         .await
-        .map_err(|e| format!("Failed to fetch accounts: {}", e))
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Fetches one page of accounts ordered by code; pages start at 1
+pub async fn get_page(page: u32, page_size: u32) -> Result<AccountPage, ErrorResponse> {
+    #[derive(Serialize)]
+    struct PageArgs {
+        page: u32,
+        page_size: u32,
+    }
+
+    tauri::invoke::<_, AccountPage>("get_accounts_paged", &PageArgs { page, page_size })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Fetches up to `limit` accounts ordered by code, starting after
+/// `cursor_code` (None for the first page); `next_cursor` is None at the end
+pub async fn get_after(
+    cursor_code: Option<&str>,
+    limit: u32,
+) -> Result<AccountStreamChunk, ErrorResponse> {
+    #[derive(Serialize)]
+    struct AfterArgs<'a> {
+        cursor_code: Option<&'a str>,
+        limit: u32,
+    }
+
+    tauri::invoke::<_, AccountStreamChunk>("get_accounts_after", &AfterArgs { cursor_code, limit })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Fetches all accounts ordered by `sort_by` (code, name, type, balance or
+/// created_at), ascending unless `descending`
+pub async fn get_sorted(
+    sort_by: &str,
+    descending: bool,
+) -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SortArgs<'a> {
+        sort_by: &'a str,
+        direction: &'a str,
+    }
+
+    let args = SortArgs {
+        sort_by,
+        direction: if descending { "desc" } else { "asc" },
+    };
+    tauri::invoke::<_, Vec<AccountViewModel>>("get_accounts_sorted", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Fetches how much each account is used, ordered by `sort_by` (code,
+/// line_count or last_activity); accounts never posted to come first when
+/// sorting by last_activity ascending
+pub async fn get_activity_stats(
+    sort_by: &str,
+    descending: bool,
+) -> Result<Vec<AccountActivityStats>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SortArgs<'a> {
+        sort_by: &'a str,
+        direction: &'a str,
+    }
+
+    let args = SortArgs {
+        sort_by,
+        direction: if descending { "desc" } else { "asc" },
+    };
+    tauri::invoke::<_, Vec<AccountActivityStats>>("get_account_activity_stats", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to fetch account activity"))
+}
+
+/// Fetches accounts of a type and/or category; `None` leaves a filter off
+pub async fn get_filtered(
+    account_type: Option<&str>,
+    category: Option<&str>,
+) -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct FilterArgs<'a> {
+        account_type: Option<&'a str>,
+        category: Option<&'a str>,
+    }
+
+    let args = FilterArgs {
+        account_type,
+        category,
+    };
+    tauri::invoke::<_, Vec<AccountViewModel>>("get_accounts_filtered", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Searches accounts by code or name, most relevant first; an empty query
+/// returns all accounts
+pub async fn search(query: &str) -> Result<Vec<AccountSearchResult>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SearchArgs<'a> {
+        query: &'a str,
+    }
+
+    tauri::invoke::<_, Vec<AccountSearchResult>>("search_accounts", &SearchArgs { query })
+        .await
+        .map_err(|e| e.with_context("Failed to search accounts"))
+}
+
+/// Fetches the accounts changed after `timestamp` (RFC 3339), oldest change
+/// first, so a cached list can be brought up to date
+pub async fn get_modified_since(timestamp: &str) -> Result<Vec<ModifiedAccount>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct ModifiedSinceArgs<'a> {
+        timestamp: &'a str,
+    }
+
+    tauri::invoke::<_, Vec<ModifiedAccount>>(
+        "get_accounts_modified_since",
+        &ModifiedSinceArgs { timestamp },
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to fetch changed accounts"))
 }
 
 /// Fetches a single account by ID
-pub async fn get_by_id(id: &str) -> Result<Option<AccountViewModel>, String> {
+pub async fn get_by_id(id: &str) -> Result<Option<AccountViewModel>, ErrorResponse> {
     tauri::invoke::<_, Option<AccountViewModel>>("get_account", &id)
         .await
-        .map_err(|e| format!("Failed to fetch account: {}", e))
+        .map_err(|e| e.with_context("Failed to fetch account"))
 }
 
 /// Creates a new account
-pub async fn create(account: &AccountDto) -> Result<AccountViewModel, String> {
-    tauri::invoke::<_, AccountViewModel>("create_account", account)
+pub async fn create(account: &AccountDto) -> Result<AccountViewModel, ErrorResponse> {
+    #[derive(Serialize)]
+    struct CreateArgs<'a> {
+        new_account: &'a AccountDto,
+        token: String,
+    }
+
+    let args = CreateArgs {
+        new_account: account,
+        token: session_token(),
+    };
+
+    tauri::invoke::<_, AccountViewModel>("create_account", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to create account"))
+}
+
+/// Fetches the next free code for a new account of `account_type`
+pub async fn suggest_next_code(
+    account_type: &str,
+    parent_id: Option<&str>,
+) -> Result<String, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SuggestArgs<'a> {
+        account_type: &'a str,
+        parent_id: Option<&'a str>,
+    }
+
+    let args = SuggestArgs {
+        account_type,
+        parent_id,
+    };
+    tauri::invoke::<_, String>("suggest_next_code", &args)
         .await
-        .map_err(|e| format!("Failed to create account: {}", e))
+        .map_err(|e| e.with_context("Failed to suggest an account code"))
 }
 
 /// Updates an existing account
-pub async fn update(id: &str, account: &AccountDto) -> Result<AccountViewModel, String> {
+pub async fn update(id: &str, account: &AccountDto) -> Result<AccountViewModel, ErrorResponse> {
     #[derive(Serialize)]
     struct UpdateArgs<'a> {
         id: &'a str,
         update_data: &'a AccountDto,
+        token: String,
     }
 
     let args = UpdateArgs {
         id,
         update_data: account,
+        token: session_token(),
     };
 
     tauri::invoke::<_, AccountViewModel>("update_account", &args)
         .await
-        .map_err(|e| format!("Failed to update account: {}", e))
+        .map_err(|e| e.with_context("Failed to update account"))
+}
+
+/// Moves an account under `new_parent_id`, or to the top level with `None`.
+/// With `inherit_category` the account adopts the parent's category.
+pub async fn reparent(
+    id: &str,
+    new_parent_id: Option<&str>,
+    inherit_category: bool,
+) -> Result<AccountViewModel, ErrorResponse> {
+    #[derive(Serialize)]
+    struct ReparentArgs<'a> {
+        id: &'a str,
+        new_parent_id: Option<&'a str>,
+        inherit_category: bool,
+        token: String,
+    }
+
+    let args = ReparentArgs {
+        id,
+        new_parent_id,
+        inherit_category,
+        token: session_token(),
+    };
+    tauri::invoke::<_, AccountViewModel>("reparent_account", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to move account"))
+}
+
+/// Fills an empty chart with the default small-business accounts; fails once
+/// any account exists
+pub async fn seed_default_chart() -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SeedArgs {
+        token: String,
+    }
+
+    tauri::invoke::<_, Vec<AccountViewModel>>(
+        "seed_default_chart",
+        &SeedArgs {
+            token: session_token(),
+        },
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to create the default chart"))
 }
 
 // Deletes an account
-pub async fn delete(id: &str) -> Result<(), String> {
-    tauri::invoke::<_, ()>("delete_account", &id)
+pub async fn delete(id: &str) -> Result<(), ErrorResponse> {
+    tauri::invoke::<_, ()>("delete_account", &IdArgs::new(id))
         .await
-        .map_err(|e| format!("Failed to delete account: {}", e))
+        .map_err(|e| e.with_context("Failed to delete account"))
+}
+
+/// Reports the children, journal lines and notes that reference an account,
+/// and whether it can be deleted
+pub async fn get_delete_impact(id: &str) -> Result<DeleteImpact, ErrorResponse> {
+    #[derive(Serialize)]
+    struct ImpactArgs<'a> {
+        id: &'a str,
+    }
+
+    tauri::invoke::<_, DeleteImpact>("get_account_delete_impact", &ImpactArgs { id })
+        .await
+        .map_err(|e| e.with_context("Failed to check account references"))
+}
+
+/// Restores a deleted account
+pub async fn restore(id: &str) -> Result<AccountViewModel, ErrorResponse> {
+    tauri::invoke::<_, AccountViewModel>("restore_account", &IdArgs::new(id))
+        .await
+        .map_err(|e| e.with_context("Failed to restore account"))
 }
 
 /// Toggles the active status of an account
-pub async fn toggle_status(id: &str) -> Result<AccountViewModel, String> {
-    tauri::invoke::<_, AccountViewModel>("toggle_account_status", &id)
+pub async fn toggle_status(id: &str) -> Result<AccountViewModel, ErrorResponse> {
+    tauri::invoke::<_, AccountViewModel>("toggle_account_status", &IdArgs::new(id))
+        .await
+        .map_err(|e| e.with_context("Failed to toggle account status"))
+}
+
+/// Activates or deactivates a batch of accounts; ids without an account come
+/// back in `missing`
+pub async fn set_status(ids: &[String], active: bool) -> Result<AccountStatusBatch, ErrorResponse> {
+    #[derive(Serialize)]
+    struct StatusArgs<'a> {
+        ids: &'a [String],
+        active: bool,
+        token: String,
+    }
+
+    let args = StatusArgs {
+        ids,
+        active,
+        token: session_token(),
+    };
+
+    tauri::invoke::<_, AccountStatusBatch>("toggle_accounts_status", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to change account status"))
+}
+
+/// Moves a batch of accounts to a new category
+pub async fn reclassify(
+    ids: &[String],
+    new_category: &str,
+) -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct ReclassifyArgs<'a> {
+        ids: &'a [String],
+        new_category: &'a str,
+        token: String,
+    }
+
+    let args = ReclassifyArgs {
+        ids,
+        new_category,
+        token: session_token(),
+    };
+
+    tauri::invoke::<_, Vec<AccountViewModel>>("reclassify_accounts", &args)
         .await
-        .map_err(|e| format!("Failed to toggle account status: {}", e))
+        .map_err(|e| e.with_context("Failed to reclassify accounts"))
+}
+
+/// Imports a chart of accounts from QuickBooks IIF content
+pub async fn import_iif(content: &str) -> Result<IifImportReport, ErrorResponse> {
+    #[derive(Serialize)]
+    struct ImportArgs<'a> {
+        content: &'a str,
+        token: String,
+    }
+
+    let args = ImportArgs {
+        content,
+        token: session_token(),
+    };
+
+    tauri::invoke_with_timeout::<_, IifImportReport>(
+        "import_chart_iif",
+        &args,
+        SLOW_COMMAND_TIMEOUT,
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to import chart of accounts"))
 }
 
 /// Fetches root (top-level) accounts
-pub async fn get_roots() -> Result<Vec<AccountViewModel>, String> {
+pub async fn get_roots() -> Result<Vec<AccountViewModel>, ErrorResponse> {
     tauri::invoke::<(), Vec<AccountViewModel>>("get_root_accounts", &())
         .await
-        .map_err(|e| format!("Failed to fetch root accounts: {}", e))
+        .map_err(|e| e.with_context("Failed to fetch root accounts"))
 }
 
 /// Fetches child accounts for a parent account
-pub async fn get_children(parent_id: &str) -> Result<Vec<AccountViewModel>, String> {
+pub async fn get_children(parent_id: &str) -> Result<Vec<AccountViewModel>, ErrorResponse> {
     tauri::invoke::<_, Vec<AccountViewModel>>("get_child_accounts", parent_id)
         .await
-        .map_err(|e| format!("Failed to fetch child accounts: {}", e))
+        .map_err(|e| e.with_context("Failed to fetch child accounts"))
 }
 
-/// Gets the available account types
-pub fn get_account_types() -> Vec<&'static str> {
-    vec!["ASSET", "LIABILITY", "EQUITY", "REVENUE", "EXPENSE"]
+/// Fetches the trial balance rendered as self-contained HTML, as of a
+/// YYYY-MM-DD date or from current balances
+pub async fn export_trial_balance_html(as_of: Option<&str>) -> Result<String, ErrorResponse> {
+    #[derive(Serialize)]
+    struct TrialBalanceArgs<'a> {
+        as_of: Option<&'a str>,
+    }
+
+    tauri::invoke::<_, String>("export_trial_balance_html", &TrialBalanceArgs { as_of })
+        .await
+        .map_err(|e| e.with_context("Failed to export trial balance"))
 }
 
-/// Gets available categories for a given account type
-pub fn get_categories_for_type(account_type: &str) -> Vec<&'static str> {
-    match account_type {
-        "ASSET" => vec!["CURRENT_ASSET", "FIXED_ASSET", "OTHER_ASSET"],
-        "LIABILITY" => vec![
-            "CURRENT_LIABILITY",
-            "LONG_TERM_LIABILITY",
-            "OTHER_LIABILITY",
-        ],
-        "EQUITY" => vec!["OWNER_EQUITY", "RETAINED_EARNINGS"],
-        "REVENUE" => vec!["OPERATING_REVENUE", "NON_OPERATING_REVENUE"],
-        "EXPENSE" => vec!["OPERATING_EXPENSE", "NON_OPERATING_EXPENSE"],
-        _ => vec![],
+/// Fetches the chart-of-accounts completeness score and checklist
+pub async fn get_chart_completeness() -> Result<ChartCompleteness, ErrorResponse> {
+    tauri::invoke::<(), ChartCompleteness>("get_chart_completeness", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch chart completeness"))
+}
+
+/// Fetches the balance sheet grouped by type and category
+pub async fn get_balance_sheet() -> Result<BalanceSheet, ErrorResponse> {
+    tauri::invoke_with_timeout::<(), BalanceSheet>("get_balance_sheet", &(), SLOW_COMMAND_TIMEOUT)
+        .await
+        .map_err(|e| e.with_context("Failed to fetch balance sheet"))
+}
+
+/// Fetches the income statement for an inclusive YYYY-MM-DD date range. A
+/// missing start or end defaults to the current fiscal year to date.
+pub async fn get_income_statement(
+    period_start: Option<&str>,
+    period_end: Option<&str>,
+) -> Result<IncomeStatement, ErrorResponse> {
+    #[derive(Serialize)]
+    struct IncomeStatementArgs<'a> {
+        period_start: Option<&'a str>,
+        period_end: Option<&'a str>,
     }
+
+    let args = IncomeStatementArgs {
+        period_start,
+        period_end,
+    };
+    tauri::invoke_with_timeout::<_, IncomeStatement>(
+        "get_income_statement",
+        &args,
+        SLOW_COMMAND_TIMEOUT,
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to fetch income statement"))
+}
+
+/// Calls `handler` whenever an account is created, updated, deleted or
+/// (de)activated, in this window or any other, until the listener is dropped
+pub fn listen_account_changed(
+    handler: impl FnMut(AccountChangedEvent) + 'static,
+) -> Result<tauri::EventListener, ErrorResponse> {
+    tauri::listen("account-changed", handler)
+}
+
+/// Sets the budget of a revenue or expense account for a fiscal period
+pub async fn set_budget(
+    account_id: &str,
+    period_id: &str,
+    amount: &str,
+) -> Result<BudgetViewModel, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SetBudgetArgs<'a> {
+        account_id: &'a str,
+        period_id: &'a str,
+        amount: &'a str,
+        token: String,
+    }
+
+    let args = SetBudgetArgs {
+        account_id,
+        period_id,
+        amount,
+        token: session_token(),
+    };
+    tauri::invoke::<_, BudgetViewModel>("set_account_budget", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to set budget"))
+}
+
+/// Fetches budgeted against actual amounts for a fiscal period
+pub async fn get_budget_variance(period_id: &str) -> Result<BudgetVarianceReport, ErrorResponse> {
+    #[derive(Serialize)]
+    struct BudgetVarianceArgs<'a> {
+        period_id: &'a str,
+    }
+
+    tauri::invoke::<_, BudgetVarianceReport>(
+        "get_budget_variance",
+        &BudgetVarianceArgs { period_id },
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to fetch budget variance"))
+}
+
+/// Fetches current ratio, quick ratio and net working capital, as of a
+/// YYYY-MM-DD date or from current balances
+pub async fn get_working_capital_metrics(
+    as_of: Option<&str>,
+) -> Result<WorkingCapitalMetrics, ErrorResponse> {
+    #[derive(Serialize)]
+    struct WorkingCapitalArgs<'a> {
+        as_of: Option<&'a str>,
+    }
+
+    tauri::invoke::<_, WorkingCapitalMetrics>(
+        "get_working_capital_metrics",
+        &WorkingCapitalArgs { as_of },
+    )
+    .await
+    .map_err(|e| e.with_context("Failed to fetch working capital metrics"))
+}
+
+/// Fetches inactive accounts that still carry a non-zero balance
+pub async fn get_inactive_nonzero() -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    tauri::invoke::<(), Vec<AccountViewModel>>("find_inactive_nonzero_accounts", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch inactive accounts with balances"))
+}
+
+/// Fetches the next chunk of accounts; pass `None` to start from the beginning
+pub async fn get_stream_chunk(cursor: Option<&str>) -> Result<AccountStreamChunk, ErrorResponse> {
+    #[derive(Serialize)]
+    struct StreamArgs<'a> {
+        cursor: Option<&'a str>,
+    }
+
+    tauri::invoke::<_, AccountStreamChunk>("get_accounts_stream", &StreamArgs { cursor })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch accounts"))
+}
+
+/// Fetches accounts that can be chosen as parent for an account of the given type
+pub async fn get_eligible_parents(
+    account_id: Option<&str>,
+    account_type: &str,
+) -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct EligibleParentsArgs<'a> {
+        account_id: Option<&'a str>,
+        account_type: &'a str,
+    }
+
+    let args = EligibleParentsArgs {
+        account_id,
+        account_type,
+    };
+
+    tauri::invoke::<_, Vec<AccountViewModel>>("get_eligible_parents", &args)
+        .await
+        .map_err(|e| e.with_context("Failed to fetch eligible parent accounts"))
+}
+
+/// Fetches the checksum over all account balances
+pub async fn get_balance_checksum() -> Result<String, ErrorResponse> {
+    tauri::invoke::<(), String>("get_balance_checksum", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch balance checksum"))
+}
+
+/// Fetches all accounts with parent balances including their descendants
+pub async fn get_all_rolled_up() -> Result<Vec<AccountViewModel>, ErrorResponse> {
+    tauri::invoke::<(), Vec<AccountViewModel>>("get_accounts_rolled_up", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch rolled-up accounts"))
+}
+
+/// Fetches all accounts grouped by type, in chart-of-accounts order, with a
+/// balance subtotal per group
+pub async fn get_grouped() -> Result<Vec<AccountGroup>, ErrorResponse> {
+    tauri::invoke::<(), Vec<AccountGroup>>("get_accounts_grouped", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch grouped accounts"))
+}
+
+/// Fetches the full chart of accounts as a nested tree
+pub async fn get_tree() -> Result<Vec<AccountTreeNode>, ErrorResponse> {
+    tauri::invoke::<(), Vec<AccountTreeNode>>("get_account_tree", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch account tree"))
+}
+
+/// Fetches the chain of accounts from the root down to `id`, for breadcrumbs
+pub async fn get_path(id: &str) -> Result<AccountPath, ErrorResponse> {
+    #[derive(Serialize)]
+    struct PathArgs<'a> {
+        id: &'a str,
+    }
+
+    tauri::invoke::<_, AccountPath>("get_account_path", &PathArgs { id })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch account path"))
+}
+
+/// Fetches an account with the total balance of the accounts below it
+pub async fn get_subtree_balance(id: &str) -> Result<SubtreeBalance, ErrorResponse> {
+    #[derive(Serialize)]
+    struct SubtreeArgs<'a> {
+        id: &'a str,
+    }
+
+    tauri::invoke::<_, SubtreeBalance>("get_subtree_balance", &SubtreeArgs { id })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch subtree balance"))
+}
+
+/// Fetches the codes an account has had, most recent change first
+pub async fn get_code_history(account_id: &str) -> Result<Vec<CodeChange>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct CodeHistoryArgs<'a> {
+        account_id: &'a str,
+    }
+
+    tauri::invoke::<_, Vec<CodeChange>>("get_code_history", &CodeHistoryArgs { account_id })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch code history"))
+}
+
+/// Fetches the account types and the categories valid for each
+pub async fn get_metadata() -> Result<Vec<AccountTypeMetadata>, ErrorResponse> {
+    tauri::invoke::<(), Vec<AccountTypeMetadata>>("get_account_metadata", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch account metadata"))
+}
+
+/// Fetches the subcategories allowed for a category
+pub async fn get_subcategories(category: &str) -> Result<Vec<String>, ErrorResponse> {
+    #[derive(Serialize)]
+    struct CategoryArgs<'a> {
+        category: &'a str,
+    }
+
+    tauri::invoke::<_, Vec<String>>("get_subcategories_for_category", &CategoryArgs { category })
+        .await
+        .map_err(|e| e.with_context("Failed to fetch subcategories"))
 }
@@ -0,0 +1,96 @@
+use crate::services::tauri;
+use js_sys::{Function, Reflect};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::window;
+
+// localStorage key holding the current session token
+const TOKEN_STORAGE_KEY: &str = "erp.session_token";
+
+// User model for the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UserViewModel {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub role: String,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// A signed session token and the user it was issued to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoginResponse {
+    pub token: String,
+    pub user: UserViewModel,
+}
+
+// The user a valid session token belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionInfo {
+    pub user_id: String,
+    pub expires_at: String,
+}
+
+/// Checks a session token with the backend
+pub async fn verify_session(token: &str) -> Result<SessionInfo, String> {
+    #[derive(Serialize)]
+    struct SessionArgs<'a> {
+        token: &'a str,
+    }
+
+    tauri::invoke::<_, SessionInfo>("verify_session", &SessionArgs { token })
+        .await
+        .map_err(|e| format!("Failed to verify session: {}", e))
+}
+
+/// Exchanges credentials for a session token
+pub async fn login(username: &str, password: &str) -> Result<LoginResponse, String> {
+    #[derive(Serialize)]
+    struct LoginArgs<'a> {
+        username: &'a str,
+        password: &'a str,
+    }
+
+    tauri::invoke::<_, LoginResponse>("login", &LoginArgs { username, password })
+        .await
+        .map_err(|e| format!("Failed to log in: {}", e))
+}
+
+/// Calls a method on `window.localStorage` with the given arguments
+fn call_local_storage(method: &str, args: &js_sys::Array) -> Result<JsValue, String> {
+    let window = window().ok_or_else(|| "Failed to get window object".to_string())?;
+    let storage = Reflect::get(&window, &JsValue::from_str("localStorage"))
+        .map_err(|_| "Failed to access localStorage".to_string())?;
+    let function = Reflect::get(&storage, &JsValue::from_str(method))
+        .map_err(|_| format!("Failed to access localStorage.{}", method))?
+        .dyn_into::<Function>()
+        .map_err(|_| format!("localStorage.{} is not a function", method))?;
+
+    Reflect::apply(&function, &storage, args)
+        .map_err(|e| format!("localStorage.{} failed: {:?}", method, e))
+}
+
+/// Persists the session token across reloads
+pub fn store_token(token: &str) -> Result<(), String> {
+    let args = js_sys::Array::of2(
+        &JsValue::from_str(TOKEN_STORAGE_KEY),
+        &JsValue::from_str(token),
+    );
+    call_local_storage("setItem", &args).map(|_| ())
+}
+
+/// Returns the stored session token, if any
+pub fn stored_token() -> Option<String> {
+    let args = js_sys::Array::of1(&JsValue::from_str(TOKEN_STORAGE_KEY));
+    call_local_storage("getItem", &args)
+        .ok()
+        .and_then(|value| value.as_string())
+}
+
+/// Forgets the stored session token
+pub fn clear_token() -> Result<(), String> {
+    let args = js_sys::Array::of1(&JsValue::from_str(TOKEN_STORAGE_KEY));
+    call_local_storage("removeItem", &args).map(|_| ())
+}
@@ -0,0 +1,74 @@
+use crate::services::tauri::{self, ErrorResponse};
+use serde::{Deserialize, Serialize};
+
+// A currency accounts may be kept in, as listed by the backend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Currency {
+    pub code: String,
+    pub symbol: String,
+    pub decimal_places: u32,
+    pub name: String,
+}
+
+/// Fetches the currencies accounts may be kept in
+pub async fn get_supported() -> Result<Vec<Currency>, ErrorResponse> {
+    tauri::invoke::<(), Vec<Currency>>("get_supported_currencies", &())
+        .await
+        .map_err(|e| e.with_context("Failed to fetch currencies"))
+}
+
+/// Shows an amount such as "1234.50" in `code`'s currency, e.g. "1235 JPY".
+/// Amounts in an unlisted currency, or that do not parse, are shown as given.
+pub fn format_balance(amount: &str, code: &str, currencies: &[Currency]) -> String {
+    let amount = currencies
+        .iter()
+        .find(|currency| currency.code == code)
+        .and_then(|currency| round_amount(amount, currency.decimal_places))
+        .unwrap_or_else(|| amount.to_string());
+    format!("{} {}", amount, code)
+}
+
+/// Rounds a plain decimal string to `decimal_places` digits, halves away from
+/// zero, padding with zeros when it has fewer
+fn round_amount(amount: &str, decimal_places: u32) -> Option<String> {
+    let amount = amount.trim();
+    let (negative, digits) = match amount.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, amount),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty()
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let scale = fraction.len() as u32;
+    let mut units: i128 = format!("{}{}", whole, fraction).parse().ok()?;
+    if decimal_places < scale {
+        let divisor = 10i128.checked_pow(scale - decimal_places)?;
+        let remainder = units % divisor;
+        units /= divisor;
+        if remainder * 2 >= divisor {
+            units += 1;
+        }
+    } else {
+        units = units.checked_mul(10i128.checked_pow(decimal_places - scale)?)?;
+    }
+
+    let mut text = units.to_string();
+    if decimal_places > 0 {
+        let places = decimal_places as usize;
+        if text.len() <= places {
+            text = format!("{}{}", "0".repeat(places + 1 - text.len()), text);
+        }
+        text.insert(text.len() - places, '.');
+    }
+    if negative && units != 0 {
+        text.insert(0, '-');
+    }
+    Some(text)
+}
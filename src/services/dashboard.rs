@@ -0,0 +1,141 @@
+use crate::services::tauri;
+use serde::{Deserialize, Serialize};
+
+// Database health and version shown on the home page
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SystemStatus {
+    pub database_connected: bool,
+    pub version: String,
+    pub last_backup: Option<String>,
+    pub fiscal_year: String,
+}
+
+// Database connectivity, pool usage and schema version
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DbHealth {
+    pub connected: bool,
+    pub latency_ms: Option<f64>,
+    pub pool_size: u32,
+    pub idle_connections: u32,
+    pub latest_migration: Option<i64>,
+    pub error: Option<String>,
+}
+
+// Activity log entry for the recent activity feed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecentActivity {
+    pub id: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: Option<String>,
+    pub description: String,
+    pub user: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub details: Vec<FieldChange>,
+}
+
+// One field an update changed; values are None when empty
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl FieldChange {
+    /// "field: old → new", with "(none)" standing in for an empty value
+    pub fn summary(&self) -> String {
+        format!(
+            "{}: {} → {}",
+            self.field,
+            self.old_value.as_deref().unwrap_or("(none)"),
+            self.new_value.as_deref().unwrap_or("(none)")
+        )
+    }
+}
+
+// Cached balance totals per account type and when they were computed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FinancialSummary {
+    pub total_assets: String,
+    pub total_liabilities: String,
+    pub total_equity: String,
+    pub total_revenue: String,
+    pub total_expenses: String,
+    pub refreshed_at: String,
+}
+
+// Headline figure; change is a percentage when the backend can compute one
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FinancialMetric {
+    pub name: String,
+    pub value: String,
+    pub change: Option<f64>,
+    pub period: String,
+}
+
+#[derive(Serialize)]
+struct MetricsArgs<'a> {
+    period: &'a str,
+}
+
+#[derive(Serialize)]
+struct RecentActivitiesArgs {
+    limit: Option<u32>,
+}
+
+/// Fetches database connectivity and the application version
+pub async fn get_system_status() -> Result<SystemStatus, String> {
+    tauri::invoke::<(), SystemStatus>("get_system_status", &())
+        .await
+        .map_err(|e| format!("Failed to fetch system status: {}", e))
+}
+
+/// Probes the database: connectivity, round-trip latency, pool usage and the
+/// latest applied migration
+pub async fn get_db_health() -> Result<DbHealth, String> {
+    tauri::invoke::<(), DbHealth>("get_db_health", &())
+        .await
+        .map_err(|e| format!("Failed to fetch database health: {}", e))
+}
+
+/// Fetches revenue, expense, net profit and cash figures
+pub async fn get_financial_metrics() -> Result<Vec<FinancialMetric>, String> {
+    tauri::invoke::<(), Vec<FinancialMetric>>("get_financial_metrics", &())
+        .await
+        .map_err(|e| format!("Failed to fetch financial metrics: {}", e))
+}
+
+/// Fetches the figures for the current "month", "quarter" or "year" to date,
+/// each with its percentage change against the prior period; `change` is
+/// `None` when the prior figure was zero
+pub async fn get_metrics_with_change(period: &str) -> Result<Vec<FinancialMetric>, String> {
+    tauri::invoke::<_, Vec<FinancialMetric>>("get_metrics_with_change", &MetricsArgs { period })
+        .await
+        .map_err(|e| format!("Failed to fetch financial metrics: {}", e))
+}
+
+/// Fetches the cached balance totals; they are as old as `refreshed_at`
+pub async fn get_financial_summary() -> Result<FinancialSummary, String> {
+    tauri::invoke::<(), FinancialSummary>("get_financial_summary", &())
+        .await
+        .map_err(|e| format!("Failed to fetch financial summary: {}", e))
+}
+
+/// Recomputes the cached balance totals and returns them
+pub async fn refresh_financial_summary() -> Result<FinancialSummary, String> {
+    tauri::invoke::<(), FinancialSummary>("refresh_financial_summary", &())
+        .await
+        .map_err(|e| format!("Failed to refresh financial summary: {}", e))
+}
+
+/// Fetches the latest activity log entries, newest first
+pub async fn get_recent_activities(limit: Option<u32>) -> Result<Vec<RecentActivity>, String> {
+    tauri::invoke::<_, Vec<RecentActivity>>(
+        "get_recent_activities",
+        &RecentActivitiesArgs { limit },
+    )
+    .await
+    .map_err(|e| format!("Failed to fetch recent activities: {}", e))
+}
@@ -0,0 +1,74 @@
+use crate::services::accounts::AccountViewModel;
+use crate::services::{auth, tauri};
+use serde::{Deserialize, Serialize};
+
+// Command error captured by the backend for bug reports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RecordedError {
+    pub command: String,
+    pub code: String,
+    pub message: String,
+    pub details: Option<String>,
+    pub timestamp: String,
+}
+
+/// Fetches the most recent command errors, newest first
+pub async fn get_recent_errors() -> Result<Vec<RecordedError>, String> {
+    tauri::invoke::<(), Vec<RecordedError>>("get_recent_errors", &())
+        .await
+        .map_err(|e| format!("Failed to fetch recent errors: {}", e))
+}
+
+// Debit-normal against credit-normal totals; `accounts` is only filled when
+// they disagree
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BooksBalanceCheck {
+    pub debit_normal_total: String,
+    pub credit_normal_total: String,
+    pub difference: String,
+    pub is_balanced: bool,
+    pub checked_at: String,
+    pub accounts: Vec<AccountViewModel>,
+}
+
+/// Checks that the balances of debit-normal and credit-normal accounts agree
+pub async fn verify_books_balanced() -> Result<BooksBalanceCheck, String> {
+    tauri::invoke::<(), BooksBalanceCheck>("verify_books_balanced", &())
+        .await
+        .map_err(|e| format!("Failed to verify the books: {}", e))
+}
+
+// An account whose stored balance was corrected to match its journal lines
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceCorrection {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub old_balance: String,
+    pub new_balance: String,
+    pub difference: String,
+}
+
+// Accounts corrected by a balance recomputation; empty when none had drifted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceRecomputeReport {
+    pub corrections: Vec<BalanceCorrection>,
+    pub recomputed_at: String,
+}
+
+/// Recalculates every account balance from its posted journal lines and
+/// corrects those that have drifted. Admins only.
+pub async fn recompute_balances() -> Result<BalanceRecomputeReport, String> {
+    #[derive(Serialize)]
+    struct RecomputeBalancesArgs {
+        token: String,
+    }
+
+    let args = RecomputeBalancesArgs {
+        token: auth::stored_token().unwrap_or_default(),
+    };
+
+    tauri::invoke::<_, BalanceRecomputeReport>("recompute_balances", &args)
+        .await
+        .map_err(|e| format!("Failed to recompute balances: {}", e))
+}
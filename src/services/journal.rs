@@ -0,0 +1,169 @@
+use crate::services::auth;
+use crate::services::tauri;
+use serde::{Deserialize, Serialize};
+
+// Journal line view model for the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalLineViewModel {
+    pub id: String,
+    pub account_id: String,
+    pub line_number: i32,
+    pub description: Option<String>,
+    pub debit: String,
+    pub credit: String,
+}
+
+// Journal entry view model for the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalEntryViewModel {
+    pub id: String,
+    pub entry_date: String,
+    pub description: String,
+    pub reference: Option<String>,
+    pub exchange_rate: Option<String>,
+    pub is_posted: bool,
+    pub posted_at: Option<String>,
+    pub reverses_entry_id: Option<String>,
+    pub lines: Vec<JournalLineViewModel>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Posts an entry reversing a posted one; without a description the backend
+/// uses "Reversal of <reference>"
+pub async fn reverse_entry(
+    entry_id: &str,
+    reversal_date: &str,
+    description: Option<&str>,
+) -> Result<JournalEntryViewModel, String> {
+    #[derive(Serialize)]
+    struct ReverseArgs<'a> {
+        entry_id: &'a str,
+        reversal_date: &'a str,
+        description: Option<&'a str>,
+        token: String,
+    }
+
+    let args = ReverseArgs {
+        entry_id,
+        reversal_date,
+        description,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+
+    tauri::invoke::<_, JournalEntryViewModel>("reverse_journal_entry", &args)
+        .await
+        .map_err(|e| format!("Failed to reverse journal entry: {}", e))
+}
+
+// Ledger row with the account balance after the line
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerRow {
+    pub entry_id: String,
+    pub entry_date: String,
+    pub description: String,
+    pub reference: Option<String>,
+    pub debit: String,
+    pub credit: String,
+    pub running_balance: String,
+}
+
+// Posted activity of a single account over a date range
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountLedger {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub opening_balance: String,
+    pub closing_balance: String,
+    pub rows: Vec<LedgerRow>,
+}
+
+// Account statement for printing; dates are YYYY-MM-DD, `generated_at` is RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LedgerExport {
+    pub account_id: String,
+    pub code: String,
+    pub name: String,
+    pub account_type: String,
+    pub currency: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub opening_balance: String,
+    pub closing_balance: String,
+    pub total_debits: String,
+    pub total_credits: String,
+    pub rows: Vec<LedgerRow>,
+    pub generated_at: String,
+}
+
+// An account balance as it stood at the end of a date
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BalanceAsOf {
+    pub account_id: String,
+    pub as_of: String,
+    pub balance: Option<String>,
+    pub recorded_at: Option<String>,
+}
+
+/// Fetches the ledger of an account; dates are YYYY-MM-DD and inclusive
+pub async fn get_account_ledger(
+    account_id: &str,
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> Result<AccountLedger, String> {
+    #[derive(Serialize)]
+    struct LedgerArgs<'a> {
+        account_id: &'a str,
+        start_date: Option<&'a str>,
+        end_date: Option<&'a str>,
+    }
+
+    let args = LedgerArgs {
+        account_id,
+        start_date,
+        end_date,
+    };
+
+    tauri::invoke::<_, AccountLedger>("get_account_ledger", &args)
+        .await
+        .map_err(|e| format!("Failed to fetch account ledger: {}", e))
+}
+
+/// Fetches a printable statement of an account over an inclusive YYYY-MM-DD range
+pub async fn get_ledger_export(
+    account_id: &str,
+    start_date: &str,
+    end_date: &str,
+) -> Result<LedgerExport, String> {
+    #[derive(Serialize)]
+    struct ExportArgs<'a> {
+        account_id: &'a str,
+        start_date: &'a str,
+        end_date: &'a str,
+    }
+
+    let args = ExportArgs {
+        account_id,
+        start_date,
+        end_date,
+    };
+
+    tauri::invoke::<_, LedgerExport>("get_ledger_export", &args)
+        .await
+        .map_err(|e| format!("Failed to export account statement: {}", e))
+}
+
+/// Fetches an account's balance at the end of a YYYY-MM-DD date
+pub async fn get_balance_as_of(account_id: &str, date: &str) -> Result<BalanceAsOf, String> {
+    #[derive(Serialize)]
+    struct BalanceArgs<'a> {
+        account_id: &'a str,
+        date: &'a str,
+    }
+
+    tauri::invoke::<_, BalanceAsOf>("get_balance_as_of", &BalanceArgs { account_id, date })
+        .await
+        .map_err(|e| format!("Failed to fetch balance: {}", e))
+}
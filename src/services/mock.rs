@@ -0,0 +1,53 @@
+use serde_json::{json, Value};
+
+/// Canned responses for read-only commands, used by `tauri::invoke` when the
+/// page runs outside Tauri with the `mock-backend` feature. Commands without
+/// a canned response still fail with the outside-Tauri error.
+pub fn respond(cmd: &str) -> Option<Value> {
+    let value = match cmd {
+        "get_system_status" => json!({
+            "database_connected": false,
+            "version": env!("CARGO_PKG_VERSION"),
+            "last_backup": null,
+            "fiscal_year": "Mock",
+        }),
+        "get_db_health" => json!({
+            "connected": false,
+            "latency_ms": null,
+            "pool_size": 0,
+            "idle_connections": 0,
+            "latest_migration": null,
+            "error": "Running outside Tauri runtime",
+        }),
+        "get_financial_metrics" | "get_metrics_with_change" => json!([
+            metric("Revenue", "12500.00"),
+            metric("Expenses", "8300.00"),
+            metric("Net Profit", "4200.00"),
+            metric("Cash Balance", "15750.00"),
+        ]),
+        "get_financial_summary" | "refresh_financial_summary" => json!({
+            "total_assets": "15750.00",
+            "total_liabilities": "4200.00",
+            "total_equity": "7350.00",
+            "total_revenue": "12500.00",
+            "total_expenses": "8300.00",
+            "refreshed_at": "1970-01-01T00:00:00+00:00",
+        }),
+        "get_recent_activities" | "get_recent_errors" => json!([]),
+        "get_accounts" | "get_root_accounts" | "get_accounts_rolled_up" | "get_account_tree" => {
+            json!([])
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn metric(name: &str, value: &str) -> Value {
+    json!({
+        "name": name,
+        "value": value,
+        "change": null,
+        "period": "Mock",
+    })
+}
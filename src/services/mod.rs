@@ -1,2 +1,10 @@
 pub mod accounts;
+pub mod auth;
+pub mod currency;
+pub mod dashboard;
+pub mod diagnostics;
+pub mod journal;
+#[cfg(feature = "mock-backend")]
+pub mod mock;
+pub mod settings;
 pub mod tauri;
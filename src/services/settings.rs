@@ -0,0 +1,98 @@
+use crate::services::{auth, tauri};
+use serde::{Deserialize, Serialize};
+
+// Application settings as exposed by the backend (no secrets)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppConfigView {
+    pub app_name: String,
+    pub version: String,
+    pub log_level: String,
+    pub data_dir: String,
+    pub fiscal_year_start_month: u32,
+    pub db_max_connections: u32,
+    pub db_timeout_seconds: u64,
+    pub token_expiry_hours: u64,
+}
+
+// A backup file written to the data directory
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupInfo {
+    pub id: String,
+    pub file_path: String,
+    pub size_bytes: i64,
+    pub account_count: i32,
+    pub created_at: String,
+}
+
+// Account counts before and after restoring a backup
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BackupRestoreResult {
+    pub file_path: String,
+    pub backup_created_at: String,
+    pub accounts_before: i64,
+    pub accounts_after: i64,
+}
+
+/// Fetches the current application settings
+pub async fn get_app_config() -> Result<AppConfigView, String> {
+    tauri::invoke::<(), AppConfigView>("get_app_config", &())
+        .await
+        .map_err(|e| format!("Failed to fetch settings: {}", e))
+}
+
+/// Saves the log level and data directory to the config file
+pub async fn update_app_config(log_level: &str, data_dir: &str) -> Result<AppConfigView, String> {
+    #[derive(Serialize)]
+    struct UpdateConfigArgs<'a> {
+        log_level: &'a str,
+        data_dir: &'a str,
+        token: String,
+    }
+
+    let args = UpdateConfigArgs {
+        log_level,
+        data_dir,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+
+    tauri::invoke::<_, AppConfigView>("update_app_config", &args)
+        .await
+        .map_err(|e| format!("Failed to save settings: {}", e))
+}
+
+/// Writes a backup of the accounts to a timestamped file in the data directory
+pub async fn create_backup() -> Result<BackupInfo, String> {
+    #[derive(Serialize)]
+    struct CreateBackupArgs {
+        token: String,
+    }
+
+    let args = CreateBackupArgs {
+        token: auth::stored_token().unwrap_or_default(),
+    };
+
+    tauri::invoke::<_, BackupInfo>("create_backup", &args)
+        .await
+        .map_err(|e| format!("Failed to create backup: {}", e))
+}
+
+/// Replaces every account with those in a backup file. `confirm` must be set,
+/// since the current accounts are overwritten.
+pub async fn restore_backup(path: &str, confirm: bool) -> Result<BackupRestoreResult, String> {
+    #[derive(Serialize)]
+    struct RestoreBackupArgs<'a> {
+        path: &'a str,
+        confirm: bool,
+        token: String,
+    }
+
+    let args = RestoreBackupArgs {
+        path,
+        confirm,
+        token: auth::stored_token().unwrap_or_default(),
+    };
+
+    tauri::invoke::<_, BackupRestoreResult>("restore_backup", &args)
+        .await
+        .map_err(|e| format!("Failed to restore backup: {}", e))
+}
@@ -1,50 +1,300 @@
 use js_sys::{Promise, Reflect};
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::window;
 
-/// Generic function to invoke Tauri commands and handle the response
-pub async fn invoke<A, R>(cmd: &str, args: &A) -> Result<R, String>
+// Error code for invocations made without the Tauri runtime
+const OUTSIDE_TAURI: &str = "OUTSIDE_TAURI";
+
+// Error code for invocations that did not answer within their time limit
+const TIMEOUT: &str = "TIMEOUT";
+
+// Error returned by backend commands; `code` identifies the kind of failure
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub details: Option<String>,
+}
+
+// A submitted field the backend rejected, named as in the command's arguments
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    /// An error raised on the frontend before or while talking to the backend
+    pub fn client(message: impl Into<String>) -> Self {
+        Self {
+            code: "CLIENT_ERROR".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Prefixes the message with what was being attempted, keeping the code
+    pub fn with_context(mut self, context: &str) -> Self {
+        self.message = format!("{}: {}", context, self.message);
+        self
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.code == "CONFLICT_ERROR"
+    }
+
+    pub fn is_validation(&self) -> bool {
+        self.code == "VALIDATION_ERROR"
+    }
+
+    /// The fields a validation error names, each with what is wrong with it.
+    /// Empty for other errors and for validation errors about no one field.
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        if !self.is_validation() {
+            return Vec::new();
+        }
+        self.details
+            .as_deref()
+            .and_then(|details| js_sys::JSON::parse(details).ok())
+            .and_then(|value| serde_wasm_bindgen::from_value(value).ok())
+            .unwrap_or_default()
+    }
+
+    /// What the user can act on: the details, with field errors joined into
+    /// one line, or the message when there are none
+    pub fn user_message(&self) -> String {
+        let field_errors = self.field_errors();
+        if field_errors.is_empty() {
+            return self.details.clone().unwrap_or_else(|| self.message.clone());
+        }
+        field_errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.code == "NOT_FOUND"
+    }
+
+    pub fn is_auth(&self) -> bool {
+        self.code == "AUTH_ERROR"
+    }
+
+    /// The page is not running inside the Tauri shell, e.g. under `dx serve`
+    pub fn outside_tauri() -> Self {
+        Self {
+            code: OUTSIDE_TAURI.to_string(),
+            message: "Running outside Tauri runtime".to_string(),
+            details: Some("The desktop backend is only reachable from the Tauri app".to_string()),
+        }
+    }
+
+    pub fn is_outside_tauri(&self) -> bool {
+        self.code == OUTSIDE_TAURI
+    }
+
+    /// A command did not answer within `limit`
+    pub fn timeout(cmd: &str, limit: Duration) -> Self {
+        Self {
+            code: TIMEOUT.to_string(),
+            message: "The request is taking longer than expected".to_string(),
+            details: Some(format!(
+                "{} did not answer within {}s",
+                cmd,
+                limit.as_secs_f32()
+            )),
+        }
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        self.code == TIMEOUT
+    }
+}
+
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.details {
+            Some(details) => write!(f, "{}: {}", self.message, details),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Returns the global `__TAURI__` object, or `None` when the page runs in a
+/// plain browser
+fn tauri_global() -> Option<JsValue> {
+    let window = window()?;
+    let tauri = Reflect::get(&window, &JsValue::from_str("__TAURI__")).ok()?;
+    if tauri.is_undefined() || tauri.is_null() {
+        None
+    } else {
+        Some(tauri)
+    }
+}
+
+/// Checks whether the frontend runs inside the Tauri shell
+pub fn is_tauri_available() -> bool {
+    tauri_global().is_some()
+}
+
+/// Generic function to invoke Tauri commands and handle the response.
+///
+/// Outside the Tauri runtime (a plain browser, e.g. `dx serve`) this returns an
+/// error for which `is_outside_tauri()` holds instead of failing on a missing
+/// global. With the `mock-backend` feature, commands known to `services::mock`
+/// are answered with canned data there instead.
+pub async fn invoke<A, R>(cmd: &str, args: &A) -> Result<R, ErrorResponse>
 where
     A: Serialize + ?Sized,
     R: DeserializeOwned,
 {
-    // Get the window object
-    let window = window().ok_or_else(|| "Failed to get window object".to_string())?;
-
     // Access the __TAURI__ object
-    let tauri = Reflect::get(&window, &JsValue::from_str("__TAURI__"))
-        .map_err(|_| "Failed to access __TAURI__ object".to_string())?;
+    let Some(tauri) = tauri_global() else {
+        return invoke_outside_tauri(cmd);
+    };
 
     // Access the invoke function
     let invoke_fn = Reflect::get(&tauri, &JsValue::from_str("invoke"))
-        .map_err(|_| "Failed to access invoke function".to_string())?;
+        .map_err(|_| ErrorResponse::client("Failed to access invoke function"))?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| ErrorResponse::client("__TAURI__.invoke is not a function"))?;
 
     // Convert args to JsValue
     let js_args = match serde_wasm_bindgen::to_value(args) {
         Ok(val) => val,
-        Err(e) => return Err(format!("Failed to serialize arguments: {}", e)),
+        Err(e) => {
+            return Err(ErrorResponse::client(format!(
+                "Failed to serialize arguments: {}",
+                e
+            )))
+        }
     };
 
     // Call the invoke function
     let promise = Reflect::apply(
-        &invoke_fn.dyn_into::<js_sys::Function>().unwrap(),
+        &invoke_fn,
         &tauri,
         &js_sys::Array::of3(&JsValue::from_str(cmd), &js_args, &JsValue::undefined()),
     )
-    .map_err(|e| format!("Failed to invoke Tauri command: {:?}", e))?
+    .map_err(|e| ErrorResponse::client(format!("Failed to invoke Tauri command: {:?}", e)))?
     .dyn_into::<Promise>()
-    .map_err(|_| "Expected Promise from Tauri invoke".to_string())?;
+    .map_err(|_| ErrorResponse::client("Expected Promise from Tauri invoke"))?;
 
-    // Wait for the promise to resolve
-    let result = JsFuture::from(promise)
-        .await
-        .map_err(|e| format!("Tauri command failed: {:?}", e))?;
+    // Wait for the promise to resolve; commands reject with a structured error
+    let result = JsFuture::from(promise).await.map_err(|e| {
+        serde_wasm_bindgen::from_value::<ErrorResponse>(e.clone())
+            .unwrap_or_else(|_| ErrorResponse::client(format!("Tauri command failed: {:?}", e)))
+    })?;
 
     // Deserialize the result
     let ret: R = serde_wasm_bindgen::from_value(result)
-        .map_err(|e| format!("Failed to deserialize response: {}", e))?;
+        .map_err(|e| ErrorResponse::client(format!("Failed to deserialize response: {}", e)))?;
 
     Ok(ret)
 }
+
+/// Like `invoke`, but gives up after `limit` with an error for which
+/// `is_timeout()` holds. The backend command is not cancelled and may still
+/// complete; only the wait is abandoned.
+pub async fn invoke_with_timeout<A, R>(
+    cmd: &str,
+    args: &A,
+    limit: Duration,
+) -> Result<R, ErrorResponse>
+where
+    A: Serialize + ?Sized,
+    R: DeserializeOwned,
+{
+    async_std::future::timeout(limit, invoke(cmd, args))
+        .await
+        .unwrap_or_else(|_| Err(ErrorResponse::timeout(cmd, limit)))
+}
+
+/// A subscription to a backend event made with `listen`; dropping it stops
+/// the handler
+pub struct EventListener {
+    // Resolves to the function that removes the listener again
+    unlisten: Promise,
+    handler: Option<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl Drop for EventListener {
+    fn drop(&mut self) {
+        // The handler must outlive the listener, so it is only released once
+        // the unlisten function has run
+        let unlisten = JsFuture::from(self.unlisten.clone());
+        let handler = self.handler.take();
+        spawn_local(async move {
+            if let Ok(unlisten) = unlisten.await {
+                if let Ok(unlisten) = unlisten.dyn_into::<js_sys::Function>() {
+                    let _ = unlisten.call0(&JsValue::NULL);
+                }
+            }
+            drop(handler);
+        });
+    }
+}
+
+/// Calls `handler` with the payload of every `event` the backend emits until
+/// the returned listener is dropped. Payloads that do not deserialize into `T`
+/// are logged and skipped. Outside the Tauri runtime this returns an error for
+/// which `is_outside_tauri()` holds.
+pub fn listen<T, F>(event: &str, mut handler: F) -> Result<EventListener, ErrorResponse>
+where
+    T: DeserializeOwned + 'static,
+    F: FnMut(T) + 'static,
+{
+    let tauri = tauri_global().ok_or_else(ErrorResponse::outside_tauri)?;
+
+    // Access the event.listen function
+    let events = Reflect::get(&tauri, &JsValue::from_str("event"))
+        .map_err(|_| ErrorResponse::client("Failed to access event module"))?;
+    let listen_fn = Reflect::get(&events, &JsValue::from_str("listen"))
+        .map_err(|_| ErrorResponse::client("Failed to access listen function"))?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| ErrorResponse::client("__TAURI__.event.listen is not a function"))?;
+
+    // Tauri passes an event object; only its payload is handed on
+    let event_name = event.to_string();
+    let handler = Closure::<dyn FnMut(JsValue)>::new(move |js_event: JsValue| {
+        let payload =
+            Reflect::get(&js_event, &JsValue::from_str("payload")).unwrap_or(JsValue::UNDEFINED);
+        match serde_wasm_bindgen::from_value::<T>(payload) {
+            Ok(payload) => handler(payload),
+            Err(e) => {
+                dioxus_logger::tracing::warn!("Ignoring malformed {} event: {}", event_name, e)
+            }
+        }
+    });
+
+    let unlisten = listen_fn
+        .call2(&events, &JsValue::from_str(event), handler.as_ref())
+        .map_err(|e| ErrorResponse::client(format!("Failed to listen for {}: {:?}", event, e)))?
+        .dyn_into::<Promise>()
+        .map_err(|_| ErrorResponse::client("Expected Promise from Tauri listen"))?;
+
+    Ok(EventListener {
+        unlisten,
+        handler: Some(handler),
+    })
+}
+
+#[cfg(not(feature = "mock-backend"))]
+fn invoke_outside_tauri<R>(_cmd: &str) -> Result<R, ErrorResponse> {
+    Err(ErrorResponse::outside_tauri())
+}
+
+#[cfg(feature = "mock-backend")]
+fn invoke_outside_tauri<R: DeserializeOwned>(cmd: &str) -> Result<R, ErrorResponse> {
+    let value = crate::services::mock::respond(cmd).ok_or_else(ErrorResponse::outside_tauri)?;
+
+    serde_json::from_value(value)
+        .map_err(|e| ErrorResponse::client(format!("Failed to deserialize mock response: {}", e)))
+}